@@ -0,0 +1,398 @@
+//! Derive macros for [`slack-blocks`](https://docs.rs/slack-blocks).
+//!
+//! These are re-exported by `slack-blocks` when its `derive` feature is enabled;
+//! see `slack_blocks::form` for usage.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input,
+          spanned::Spanned,
+          Attribute,
+          Data,
+          DeriveInput,
+          Fields,
+          Lit,
+          Meta,
+          NestedMeta};
+
+/// Derive `slack_blocks::form::SlackForm` for a struct with named fields.
+///
+/// Each field becomes an Input block whose `block_id` and `action_id`
+/// are the field's name.
+///
+/// Fields accept `#[slack(label = "..", hint = "..", placeholder = "..")]`.
+#[proc_macro_derive(SlackForm, attributes(slack))]
+pub fn derive_slack_form(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  slack_form(input).unwrap_or_else(|e| e.to_compile_error())
+                   .into()
+}
+
+/// Derive `slack_blocks::form::Choice` for an enum with only unit variants,
+/// so that it can be used as a static select field in a `SlackForm`.
+///
+/// Variants accept `#[slack(label = "..", value = "..")]`.
+#[proc_macro_derive(SlackChoice, attributes(slack))]
+pub fn derive_slack_choice(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  slack_choice(input).unwrap_or_else(|e| e.to_compile_error())
+                     .into()
+}
+
+/// Derive `slack_blocks::compose::action_id::ActionId` for an enum,
+/// and conversions into the `Cow<str>` accepted by `action_id` builder methods.
+///
+/// Variants accept `#[slack(id = "..")]`.
+#[proc_macro_derive(ActionId, attributes(slack))]
+pub fn derive_action_id(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  action_id(input).unwrap_or_else(|e| e.to_compile_error())
+                  .into()
+}
+
+fn slack_form(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  let fields = match &input.data {
+    | Data::Struct(data) => match &data.fields {
+      | Fields::Named(fields) => &fields.named,
+      | _ => {
+        return Err(syn::Error::new(input.span(),
+                                   "SlackForm requires named fields"))
+      },
+    },
+    | _ => {
+      return Err(syn::Error::new(input.span(),
+                                 "SlackForm can only be derived for structs"))
+    },
+  };
+
+  let mut specs = Vec::new();
+  let mut parses = Vec::new();
+  let mut inits = Vec::new();
+
+  for field in fields {
+    let ident = field.ident.as_ref().unwrap();
+    let ty = &field.ty;
+    let id = ident.to_string();
+    let attrs = SlackAttrs::parse(&field.attrs)?;
+
+    let label = attrs.label.unwrap_or_else(|| humanize(&id));
+    let hint = option_tokens(attrs.hint);
+    let placeholder = option_tokens(attrs.placeholder);
+
+    let spec = quote! {
+      ::slack_blocks::form::FieldSpec { id: #id,
+                                        label: #label,
+                                        hint: #hint,
+                                        placeholder: #placeholder }
+    };
+
+    specs.push(quote! {
+                 ::slack_blocks::form::input::<#ty>(&#spec).into()
+               });
+
+    parses.push(quote! {
+      let #ident =
+        match <#ty as ::slack_blocks::form::FormField>::parse(state, &#spec) {
+          | Ok(value) => Some(value),
+          | Err(error) => {
+            errors.insert(#id, error);
+            None
+          },
+        };
+    });
+
+    inits.push(quote! { #ident: #ident.unwrap() });
+  }
+
+  Ok(quote! {
+       impl #impl_generics ::slack_blocks::form::SlackForm for #name #ty_generics #where_clause {
+         fn blocks() -> ::std::vec::Vec<::slack_blocks::blocks::Block<'static>> {
+           vec![#(#specs),*]
+         }
+
+         fn from_view_state(state: &::slack_blocks::interaction::ViewState)
+                            -> ::std::result::Result<Self, ::slack_blocks::form::FormErrors> {
+           let mut errors = ::slack_blocks::form::FormErrors::new();
+
+           #(#parses)*
+
+           if !errors.is_empty() {
+             return Err(errors);
+           }
+
+           Ok(Self { #(#inits),* })
+         }
+       }
+     })
+}
+
+fn slack_choice(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let name = &input.ident;
+
+  let variants = match &input.data {
+    | Data::Enum(data) => &data.variants,
+    | _ => {
+      return Err(syn::Error::new(input.span(),
+                                 "SlackChoice can only be derived for enums"))
+    },
+  };
+
+  let mut choices = Vec::new();
+  let mut matches = Vec::new();
+
+  for variant in variants {
+    if !matches!(variant.fields, Fields::Unit) {
+      return Err(syn::Error::new(variant.span(),
+                                 "SlackChoice variants must not have fields"));
+    }
+
+    let ident = &variant.ident;
+    let attrs = SlackAttrs::parse(&variant.attrs)?;
+    let value = attrs.value.unwrap_or_else(|| ident.to_string());
+    let label = attrs.label.unwrap_or_else(|| humanize(&ident.to_string()));
+
+    choices.push(quote! { (#value, #label) });
+    matches.push(quote! { #value => Some(#name::#ident) });
+  }
+
+  Ok(quote! {
+       impl ::slack_blocks::form::Choice for #name {
+         fn choices() -> ::std::vec::Vec<(&'static str, &'static str)> {
+           vec![#(#choices),*]
+         }
+
+         fn from_choice(value: &str) -> ::std::option::Option<Self> {
+           match value {
+             #(#matches,)*
+             _ => None,
+           }
+         }
+       }
+     })
+}
+
+fn action_id(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let name = &input.ident;
+
+  if !input.generics.params.is_empty() {
+    return Err(syn::Error::new(input.generics.span(),
+                               "ActionId can't be derived for generic enums"));
+  }
+
+  let variants = match &input.data {
+    | Data::Enum(data) => &data.variants,
+    | _ => {
+      return Err(syn::Error::new(input.span(),
+                                 "ActionId can only be derived for enums"))
+    },
+  };
+
+  let mut encodes = Vec::new();
+  let mut parses = Vec::new();
+
+  for variant in variants {
+    let ident = &variant.ident;
+    let attrs = SlackAttrs::parse(&variant.attrs)?;
+    let id = attrs.id.unwrap_or_else(|| snake_case(&ident.to_string()));
+
+    if id.contains(':') || id.chars().count() > 255 {
+      return Err(syn::Error::new(variant.span(),
+                                 "ids must not contain `:`, \
+                                  and must be at most 255 characters"));
+    }
+
+    let bindings = (0..variant.fields.len()).map(|ix| format_ident!("f{}", ix))
+                                            .collect::<Vec<_>>();
+    let count = bindings.len();
+
+    let pattern = match &variant.fields {
+      | Fields::Unit => quote! { #name::#ident },
+      | Fields::Unnamed(_) => quote! { #name::#ident(#(#bindings),*) },
+      | Fields::Named(fields) => {
+        let names = fields.named.iter().map(|f| f.ident.as_ref().unwrap());
+        quote! { #name::#ident { #(#names: #bindings),* } }
+      },
+    };
+
+    if count == 0 {
+      encodes.push(quote! { #pattern => ::std::string::String::from(#id), });
+      parses.push(quote! { (#id, None) => Ok(#name::#ident) });
+      continue;
+    }
+
+    encodes.push(quote! {
+            #pattern => {
+              let mut id = ::std::string::String::from(#id);
+              #(
+                id.push(':');
+                id.push_str(&::std::string::ToString::to_string(#bindings));
+              )*
+              id
+            }
+          });
+
+    parses.push(quote! {
+                  (#id, Some(fields)) => {
+                    let mut fields = fields.splitn(#count, ':');
+                    #(
+                      let #bindings = fields.next()
+                                            .and_then(|f| f.parse().ok())
+                                            .ok_or_else(unrecognized)?;
+                    )*
+                    Ok(#pattern)
+                  }
+                });
+  }
+
+  Ok(quote! {
+       impl ::slack_blocks::compose::action_id::ActionId for #name {
+         fn to_action_id(&self) -> ::std::string::String {
+           match self {
+             #(#encodes)*
+           }
+         }
+
+         fn from_action_id(id: &str)
+                           -> ::std::result::Result<Self, ::slack_blocks::compose::action_id::ActionIdError> {
+           let unrecognized = || {
+             ::slack_blocks::compose::action_id::ActionIdError::Unrecognized { id: id.to_string() }
+           };
+
+           let (variant, fields) = match id.find(':') {
+             | Some(ix) => (&id[..ix], Some(&id[ix + 1..])),
+             | None => (id, None),
+           };
+
+           match (variant, fields) {
+             #(#parses,)*
+             _ => Err(unrecognized()),
+           }
+         }
+       }
+
+       impl<'a> ::std::convert::From<#name> for ::std::borrow::Cow<'a, str> {
+         fn from(id: #name) -> Self {
+           ::std::borrow::Cow::Owned(::slack_blocks::compose::action_id::ActionId::to_action_id(&id))
+         }
+       }
+
+       impl<'a, 'b> ::std::convert::From<&'b #name> for ::std::borrow::Cow<'a, str> {
+         fn from(id: &'b #name) -> Self {
+           ::std::borrow::Cow::Owned(::slack_blocks::compose::action_id::ActionId::to_action_id(id))
+         }
+       }
+     })
+}
+
+#[derive(Default)]
+struct SlackAttrs {
+  label: Option<String>,
+  hint: Option<String>,
+  placeholder: Option<String>,
+  value: Option<String>,
+  id: Option<String>,
+}
+
+impl SlackAttrs {
+  fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+    let mut out = Self::default();
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident("slack")) {
+      let list = match attr.parse_meta()? {
+        | Meta::List(list) => list,
+        | other => {
+          return Err(syn::Error::new(other.span(),
+                                     "expected #[slack(key = \"value\", ..)]"))
+        },
+      };
+
+      for nested in list.nested {
+        let pair = match nested {
+          | NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+          | other => {
+            return Err(syn::Error::new(other.span(),
+                                       "expected key = \"value\""))
+          },
+        };
+
+        let value = match &pair.lit {
+          | Lit::Str(s) => s.value(),
+          | other => {
+            return Err(syn::Error::new(other.span(), "expected a string"))
+          },
+        };
+
+        let slot = match pair.path.get_ident().map(|i| i.to_string()) {
+          | Some(key) if key == "label" => &mut out.label,
+          | Some(key) if key == "hint" => &mut out.hint,
+          | Some(key) if key == "placeholder" => &mut out.placeholder,
+          | Some(key) if key == "value" => &mut out.value,
+          | Some(key) if key == "id" => &mut out.id,
+          | _ => {
+            return Err(syn::Error::new(pair.path.span(),
+                                       "unknown slack attribute"))
+          },
+        };
+
+        *slot = Some(value);
+      }
+    }
+
+    Ok(out)
+  }
+}
+
+fn option_tokens(value: Option<String>) -> TokenStream2 {
+  match value {
+    | Some(v) => quote! { Some(#v) },
+    | None => quote! { None },
+  }
+}
+
+/// `due_date` -> `Due date`, `HighPriority` -> `High priority`
+fn humanize(ident: &str) -> String {
+  let mut out = String::new();
+
+  for (ix, c) in ident.chars().enumerate() {
+    if c == '_' {
+      out.push(' ');
+    } else if ix == 0 {
+      out.extend(c.to_uppercase());
+    } else if c.is_uppercase() {
+      out.push(' ');
+      out.extend(c.to_lowercase());
+    } else {
+      out.push(c);
+    }
+  }
+
+  out
+}
+
+/// `HighPriority` -> `high_priority`
+fn snake_case(ident: &str) -> String {
+  let mut out = String::new();
+
+  for (ix, c) in ident.chars().enumerate() {
+    if c.is_uppercase() {
+      if ix > 0 {
+        out.push('_');
+      }
+      out.extend(c.to_lowercase());
+    } else {
+      out.push(c);
+    }
+  }
+
+  out
+}
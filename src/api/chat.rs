@@ -0,0 +1,1253 @@
+//! # `chat.*` request bodies
+//!
+//! Request bodies for the Web API methods that post and edit [`Message`]s:
+//!  - [`chat.postMessage` 🔗] - [`PostMessage`]
+//!  - [`chat.postEphemeral` 🔗] - [`PostEphemeral`]
+//!  - [`chat.update` 🔗] - [`Update`]
+//!  - [`chat.scheduleMessage` 🔗] - [`ScheduleMessage`]
+//!  - [`chat.unfurl` 🔗] - [`Unfurl`]
+//!
+//! [`chat.postMessage` 🔗]: https://api.slack.com/methods/chat.postMessage
+//! [`chat.postEphemeral` 🔗]: https://api.slack.com/methods/chat.postEphemeral
+//! [`chat.update` 🔗]: https://api.slack.com/methods/chat.update
+//! [`chat.scheduleMessage` 🔗]: https://api.slack.com/methods/chat.scheduleMessage
+//! [`chat.unfurl` 🔗]: https://api.slack.com/methods/chat.unfurl
+
+use std::{borrow::Cow, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+use crate::{blocks::Block,
+            surfaces::{Attachment, Message}};
+
+/// # `chat.postMessage`
+///
+/// [slack api docs 🔗]
+///
+/// Sends a message to a channel.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/chat.postMessage
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct PostMessage<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::channel"))]
+  channel: Cow<'a, str>,
+
+  #[serde(flatten)]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Message<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  username: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  icon_emoji: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  icon_url: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  link_names: Option<bool>,
+}
+
+impl<'a> PostMessage<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "chat.postMessage";
+
+  /// Build a new `chat.postMessage` request body.
+  ///
+  /// For example, see docs for [`build::PostMessageBuilder`].
+  pub fn builder() -> build::PostMessageBuilderInit<'a> {
+    build::PostMessageBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `channel` is empty
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::PostMessage, surfaces::Message};
+  ///
+  /// let req =
+  ///   PostMessage::builder().channel("")
+  ///                         .message(Message::builder().text("hi").build())
+  ///                         .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `chat.postEphemeral`
+///
+/// [slack api docs 🔗]
+///
+/// Sends an ephemeral message to a user in a channel,
+/// visible only to that user.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/chat.postEphemeral
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct PostEphemeral<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::channel"))]
+  channel: Cow<'a, str>,
+
+  #[cfg_attr(feature = "validation", validate(custom = "validate::user"))]
+  user: Cow<'a, str>,
+
+  #[serde(flatten)]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Message<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  username: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  icon_emoji: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  icon_url: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  link_names: Option<bool>,
+}
+
+impl<'a> PostEphemeral<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "chat.postEphemeral";
+
+  /// Build a new `chat.postEphemeral` request body.
+  ///
+  /// For example, see docs for [`build::PostEphemeralBuilder`].
+  pub fn builder() -> build::PostEphemeralBuilderInit<'a> {
+    build::PostEphemeralBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `channel` is empty
+  /// - If `user` is empty
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::PostEphemeral, surfaces::Message};
+  ///
+  /// let req =
+  ///   PostEphemeral::builder().channel("C123")
+  ///                           .user("")
+  ///                           .message(Message::builder().text("psst").build())
+  ///                           .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `chat.update`
+///
+/// [slack api docs 🔗]
+///
+/// Updates a message, identified by its `channel` and `ts`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/chat.update
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_update",
+                           skip_on_field_errors = false)))]
+pub struct Update<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::channel"))]
+  channel: Cow<'a, str>,
+
+  #[cfg_attr(feature = "validation", validate(custom = "validate::ts"))]
+  ts: Cow<'a, str>,
+
+  #[serde(flatten)]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Message<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  link_names: Option<bool>,
+}
+
+impl<'a> Update<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "chat.update";
+
+  /// Build a new `chat.update` request body.
+  ///
+  /// For example, see docs for [`build::UpdateBuilder`].
+  pub fn builder() -> build::UpdateBuilderInit<'a> {
+    build::UpdateBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `channel` is empty
+  /// - If `ts` is empty
+  /// - If `message` sets `thread_ts`; a message can't be moved into a thread
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::Update, surfaces::Message};
+  ///
+  /// let req = Update::builder().channel("C123")
+  ///                            .ts("")
+  ///                            .message(Message::builder().text("edited").build())
+  ///                            .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `chat.scheduleMessage`
+///
+/// [slack api docs 🔗]
+///
+/// Schedules a message to be sent to a channel at `post_at`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/chat.scheduleMessage
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct ScheduleMessage<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::channel"))]
+  channel: Cow<'a, str>,
+
+  #[cfg_attr(feature = "validation", validate(range(min = 1)))]
+  post_at: i64,
+
+  #[serde(flatten)]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Message<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  link_names: Option<bool>,
+}
+
+impl<'a> ScheduleMessage<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "chat.scheduleMessage";
+
+  /// Build a new `chat.scheduleMessage` request body.
+  ///
+  /// For example, see docs for [`build::ScheduleMessageBuilder`].
+  pub fn builder() -> build::ScheduleMessageBuilderInit<'a> {
+    build::ScheduleMessageBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `channel` is empty
+  /// - If `post_at` is not a positive Unix timestamp
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::ScheduleMessage, surfaces::Message};
+  ///
+  /// let req =
+  ///   ScheduleMessage::builder().channel("C123")
+  ///                             .post_at(-1)
+  ///                             .message(Message::builder().text("later").build())
+  ///                             .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `chat.unfurl`
+///
+/// [slack api docs 🔗]
+///
+/// Provides custom unfurl behavior for URLs posted in a message,
+/// in response to a [`link_shared` 🔗] event.
+///
+/// Each URL is unfurled with an [`Attachment`], which may contain
+/// [layout blocks 🔗] and/or legacy attachment fields.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/chat.unfurl
+/// [`link_shared` 🔗]: https://api.slack.com/events/link_shared
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_unfurls",
+                           skip_on_field_errors = false)))]
+pub struct Unfurl<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::channel"))]
+  channel: Cow<'a, str>,
+
+  #[cfg_attr(feature = "validation", validate(custom = "validate::ts"))]
+  ts: Cow<'a, str>,
+
+  unfurls: BTreeMap<Cow<'a, str>, Attachment<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_auth_message: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_auth_required: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  user_auth_url: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  #[cfg_attr(feature = "validation", validate(length(max = 50)))]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::user_auth_blocks"))]
+  #[cfg_attr(feature = "validation", validate)]
+  user_auth_blocks: Vec<Block<'a>>,
+}
+
+impl<'a> Unfurl<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "chat.unfurl";
+
+  /// Build a new `chat.unfurl` request body.
+  ///
+  /// For example, see docs for [`build::UnfurlBuilder`].
+  pub fn builder() -> build::UnfurlBuilderInit<'a> {
+    build::UnfurlBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `channel` is empty
+  /// - If `ts` is empty
+  /// - If any unfurl in `unfurls` is invalid (see [`Attachment::validate`])
+  /// - If `user_auth_blocks` contains more than 50 blocks
+  /// - If `user_auth_blocks` can't be used in a message (see [`Block::validate_for`])
+  /// - If any block in `user_auth_blocks` is invalid (see [`Block::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::Unfurl, blocks::Block, surfaces::Attachment};
+  ///
+  /// let too_many = Attachment::builder().blocks((0..51).map(|_| Block::Divider))
+  ///                                     .build();
+  ///
+  /// let req = Unfurl::builder().channel("C123")
+  ///                            .ts("1623956385.000200")
+  ///                            .unfurl("https://example.com", too_many)
+  ///                            .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// `chat.*` request body builders
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// PostMessageBuilder.channel, etc.
+    #[derive(Clone, Copy, Debug)]
+    pub struct channel;
+
+    /// PostEphemeralBuilder.user
+    #[derive(Clone, Copy, Debug)]
+    pub struct user;
+
+    /// UpdateBuilder.ts
+    #[derive(Clone, Copy, Debug)]
+    pub struct ts;
+
+    /// ScheduleMessageBuilder.post_at
+    #[derive(Clone, Copy, Debug)]
+    pub struct post_at;
+
+    /// PostMessageBuilder.message, etc.
+    #[derive(Clone, Copy, Debug)]
+    pub struct message;
+
+    /// UnfurlBuilder.unfurl or UnfurlBuilder.unfurls
+    #[derive(Clone, Copy, Debug)]
+    pub struct unfurls;
+  }
+
+  /// Initial state for `PostMessageBuilder`
+  pub type PostMessageBuilderInit<'a> =
+    PostMessageBuilder<'a,
+                       RequiredMethodNotCalled<method::channel>,
+                       RequiredMethodNotCalled<method::message>>;
+
+  /// Build a `chat.postMessage` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `PostMessageBuilder::build()` is only available if these methods have been called:
+  ///  - `channel`
+  ///  - `message`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::PostMessage,
+  ///                    blocks::Block,
+  ///                    surfaces::Message};
+  ///
+  /// let msg = Message::builder().text("Deploy finished")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  ///
+  /// let req = PostMessage::builder().channel("C0123456")
+  ///                                 .message(msg)
+  ///                                 .username("deploybot")
+  ///                                 .icon_emoji(":rocket:")
+  ///                                 .build();
+  ///
+  /// let body = serde_json::to_string(&req).unwrap();
+  /// // POST https://slack.com/api/chat.postMessage
+  /// ```
+  #[derive(Debug)]
+  pub struct PostMessageBuilder<'a, Channel, Msg> {
+    channel: Option<Cow<'a, str>>,
+    message: Option<Message<'a>>,
+    username: Option<Cow<'a, str>>,
+    icon_emoji: Option<Cow<'a, str>>,
+    icon_url: Option<Cow<'a, str>>,
+    link_names: Option<bool>,
+    state: PhantomData<(Channel, Msg)>,
+  }
+
+  impl<'a, C, M> PostMessageBuilder<'a, C, M> {
+    /// Create a new PostMessageBuilder
+    pub fn new() -> Self {
+      Self { channel: None,
+             message: None,
+             username: None,
+             icon_emoji: None,
+             icon_url: None,
+             link_names: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<C2, M2>(self) -> PostMessageBuilder<'a, C2, M2> {
+      PostMessageBuilder { channel: self.channel,
+                           message: self.message,
+                           username: self.username,
+                           icon_emoji: self.icon_emoji,
+                           icon_url: self.icon_url,
+                           link_names: self.link_names,
+                           state: PhantomData::<_> }
+    }
+
+    /// Set `channel` (**Required**)
+    ///
+    /// Channel, private group, or IM channel to send the message to.
+    /// Can be an encoded ID, or a name.
+    pub fn channel(mut self,
+                   channel: impl Into<Cow<'a, str>>)
+                   -> PostMessageBuilder<'a, Set<method::channel>, M> {
+      self.channel = Some(channel.into());
+      self.cast_state()
+    }
+
+    /// Set `message` (**Required**)
+    ///
+    /// The [`Message`] to send.
+    pub fn message(mut self,
+                   message: Message<'a>)
+                   -> PostMessageBuilder<'a, C, Set<method::message>> {
+      self.message = Some(message);
+      self.cast_state()
+    }
+
+    /// Set `username` (Optional)
+    ///
+    /// Set your bot's user name.
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+      self.username = Some(username.into());
+      self
+    }
+
+    /// Set `icon_emoji` (Optional)
+    ///
+    /// Emoji to use as the icon for this message. Overrides `icon_url`.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<Cow<'a, str>>) -> Self {
+      self.icon_emoji = Some(icon_emoji.into());
+      self
+    }
+
+    /// Set `icon_url` (Optional)
+    ///
+    /// URL to an image to use as the icon for this message.
+    pub fn icon_url(mut self, icon_url: impl Into<Cow<'a, str>>) -> Self {
+      self.icon_url = Some(icon_url.into());
+      self
+    }
+
+    /// Set `link_names` (Optional)
+    ///
+    /// Find and link user groups.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+      self.link_names = Some(link_names);
+      self
+    }
+  }
+
+  impl<'a> PostMessageBuilder<'a, Set<method::channel>, Set<method::message>> {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'PostMessageBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `PostMessageBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::api::chat::PostMessage;
+    ///
+    /// let foo = PostMessage::builder().channel("C123").build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::chat::PostMessage, surfaces::Message};
+    ///
+    /// let req =
+    ///   PostMessage::builder().channel("C123")
+    ///                         .message(Message::builder().text("hi").build())
+    ///                         .build();
+    /// ```
+    pub fn build(self) -> PostMessage<'a> {
+      PostMessage { channel: self.channel.unwrap(),
+                    message: self.message.unwrap(),
+                    username: self.username,
+                    icon_emoji: self.icon_emoji,
+                    icon_url: self.icon_url,
+                    link_names: self.link_names }
+    }
+  }
+
+  /// Initial state for `PostEphemeralBuilder`
+  pub type PostEphemeralBuilderInit<'a> =
+    PostEphemeralBuilder<'a,
+                         RequiredMethodNotCalled<method::channel>,
+                         RequiredMethodNotCalled<method::user>,
+                         RequiredMethodNotCalled<method::message>>;
+
+  /// Build a `chat.postEphemeral` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `PostEphemeralBuilder::build()` is only available if these methods have been called:
+  ///  - `channel`
+  ///  - `user`
+  ///  - `message`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::PostEphemeral, surfaces::Message};
+  ///
+  /// let req =
+  ///   PostEphemeral::builder().channel("C0123456")
+  ///                           .user("U0123456")
+  ///                           .message(Message::builder().text("Only you can see this")
+  ///                                                      .build())
+  ///                           .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct PostEphemeralBuilder<'a, Channel, User, Msg> {
+    channel: Option<Cow<'a, str>>,
+    user: Option<Cow<'a, str>>,
+    message: Option<Message<'a>>,
+    username: Option<Cow<'a, str>>,
+    icon_emoji: Option<Cow<'a, str>>,
+    icon_url: Option<Cow<'a, str>>,
+    link_names: Option<bool>,
+    state: PhantomData<(Channel, User, Msg)>,
+  }
+
+  impl<'a, C, U, M> PostEphemeralBuilder<'a, C, U, M> {
+    /// Create a new PostEphemeralBuilder
+    pub fn new() -> Self {
+      Self { channel: None,
+             user: None,
+             message: None,
+             username: None,
+             icon_emoji: None,
+             icon_url: None,
+             link_names: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<C2, U2, M2>(self) -> PostEphemeralBuilder<'a, C2, U2, M2> {
+      PostEphemeralBuilder { channel: self.channel,
+                             user: self.user,
+                             message: self.message,
+                             username: self.username,
+                             icon_emoji: self.icon_emoji,
+                             icon_url: self.icon_url,
+                             link_names: self.link_names,
+                             state: PhantomData::<_> }
+    }
+
+    /// Set `channel` (**Required**)
+    ///
+    /// Channel, private group, or IM channel to send the message to.
+    /// Can be an encoded ID, or a name.
+    pub fn channel(mut self,
+                   channel: impl Into<Cow<'a, str>>)
+                   -> PostEphemeralBuilder<'a, Set<method::channel>, U, M> {
+      self.channel = Some(channel.into());
+      self.cast_state()
+    }
+
+    /// Set `user` (**Required**)
+    ///
+    /// `id` of the user who will receive the ephemeral message.
+    /// The user should be in the channel specified by `channel`.
+    pub fn user(mut self,
+                user: impl Into<Cow<'a, str>>)
+                -> PostEphemeralBuilder<'a, C, Set<method::user>, M> {
+      self.user = Some(user.into());
+      self.cast_state()
+    }
+
+    /// Set `message` (**Required**)
+    ///
+    /// The [`Message`] to send.
+    pub fn message(mut self,
+                   message: Message<'a>)
+                   -> PostEphemeralBuilder<'a, C, U, Set<method::message>> {
+      self.message = Some(message);
+      self.cast_state()
+    }
+
+    /// Set `username` (Optional)
+    ///
+    /// Set your bot's user name.
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+      self.username = Some(username.into());
+      self
+    }
+
+    /// Set `icon_emoji` (Optional)
+    ///
+    /// Emoji to use as the icon for this message. Overrides `icon_url`.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<Cow<'a, str>>) -> Self {
+      self.icon_emoji = Some(icon_emoji.into());
+      self
+    }
+
+    /// Set `icon_url` (Optional)
+    ///
+    /// URL to an image to use as the icon for this message.
+    pub fn icon_url(mut self, icon_url: impl Into<Cow<'a, str>>) -> Self {
+      self.icon_url = Some(icon_url.into());
+      self
+    }
+
+    /// Set `link_names` (Optional)
+    ///
+    /// Find and link user groups.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+      self.link_names = Some(link_names);
+      self
+    }
+  }
+
+  impl<'a>
+    PostEphemeralBuilder<'a,
+                         Set<method::channel>,
+                         Set<method::user>,
+                         Set<method::message>>
+  {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'PostEphemeralBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `PostEphemeralBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::{api::chat::PostEphemeral, surfaces::Message};
+    ///
+    /// let foo = PostEphemeral::builder().channel("C123")
+    ///                                   .message(Message::builder().text("hi").build())
+    ///                                   .build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::chat::PostEphemeral, surfaces::Message};
+    ///
+    /// let req =
+    ///   PostEphemeral::builder().channel("C123")
+    ///                           .user("U123")
+    ///                           .message(Message::builder().text("hi").build())
+    ///                           .build();
+    /// ```
+    pub fn build(self) -> PostEphemeral<'a> {
+      PostEphemeral { channel: self.channel.unwrap(),
+                      user: self.user.unwrap(),
+                      message: self.message.unwrap(),
+                      username: self.username,
+                      icon_emoji: self.icon_emoji,
+                      icon_url: self.icon_url,
+                      link_names: self.link_names }
+    }
+  }
+
+  /// Initial state for `UpdateBuilder`
+  pub type UpdateBuilderInit<'a> =
+    UpdateBuilder<'a,
+                  RequiredMethodNotCalled<method::channel>,
+                  RequiredMethodNotCalled<method::ts>,
+                  RequiredMethodNotCalled<method::message>>;
+
+  /// Build a `chat.update` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `UpdateBuilder::build()` is only available if these methods have been called:
+  ///  - `channel`
+  ///  - `ts`
+  ///  - `message`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::Update, surfaces::Message};
+  ///
+  /// let req = Update::builder().channel("C0123456")
+  ///                            .ts("1623956385.000200")
+  ///                            .message(Message::builder().text("Deploy finished (edited)")
+  ///                                                       .build())
+  ///                            .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct UpdateBuilder<'a, Channel, Ts, Msg> {
+    channel: Option<Cow<'a, str>>,
+    ts: Option<Cow<'a, str>>,
+    message: Option<Message<'a>>,
+    link_names: Option<bool>,
+    state: PhantomData<(Channel, Ts, Msg)>,
+  }
+
+  impl<'a, C, T, M> UpdateBuilder<'a, C, T, M> {
+    /// Create a new UpdateBuilder
+    pub fn new() -> Self {
+      Self { channel: None,
+             ts: None,
+             message: None,
+             link_names: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<C2, T2, M2>(self) -> UpdateBuilder<'a, C2, T2, M2> {
+      UpdateBuilder { channel: self.channel,
+                      ts: self.ts,
+                      message: self.message,
+                      link_names: self.link_names,
+                      state: PhantomData::<_> }
+    }
+
+    /// Set `channel` (**Required**)
+    ///
+    /// Channel containing the message to be updated.
+    pub fn channel(mut self,
+                   channel: impl Into<Cow<'a, str>>)
+                   -> UpdateBuilder<'a, Set<method::channel>, T, M> {
+      self.channel = Some(channel.into());
+      self.cast_state()
+    }
+
+    /// Set `ts` (**Required**)
+    ///
+    /// Timestamp of the message to be updated.
+    pub fn ts(mut self,
+              ts: impl Into<Cow<'a, str>>)
+              -> UpdateBuilder<'a, C, Set<method::ts>, M> {
+      self.ts = Some(ts.into());
+      self.cast_state()
+    }
+
+    /// Set `message` (**Required**)
+    ///
+    /// The new contents of the message.
+    pub fn message(mut self,
+                   message: Message<'a>)
+                   -> UpdateBuilder<'a, C, T, Set<method::message>> {
+      self.message = Some(message);
+      self.cast_state()
+    }
+
+    /// Set `link_names` (Optional)
+    ///
+    /// Find and link user groups.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+      self.link_names = Some(link_names);
+      self
+    }
+  }
+
+  impl<'a>
+    UpdateBuilder<'a,
+                  Set<method::channel>,
+                  Set<method::ts>,
+                  Set<method::message>>
+  {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'UpdateBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `UpdateBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::{api::chat::Update, surfaces::Message};
+    ///
+    /// let foo = Update::builder().channel("C123")
+    ///                            .message(Message::builder().text("hi").build())
+    ///                            .build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::chat::Update, surfaces::Message};
+    ///
+    /// let req = Update::builder().channel("C123")
+    ///                            .ts("1623956385.000200")
+    ///                            .message(Message::builder().text("hi").build())
+    ///                            .build();
+    /// ```
+    pub fn build(self) -> Update<'a> {
+      Update { channel: self.channel.unwrap(),
+               ts: self.ts.unwrap(),
+               message: self.message.unwrap(),
+               link_names: self.link_names }
+    }
+  }
+
+  /// Initial state for `ScheduleMessageBuilder`
+  pub type ScheduleMessageBuilderInit<'a> =
+    ScheduleMessageBuilder<'a,
+                           RequiredMethodNotCalled<method::channel>,
+                           RequiredMethodNotCalled<method::post_at>,
+                           RequiredMethodNotCalled<method::message>>;
+
+  /// Build a `chat.scheduleMessage` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `ScheduleMessageBuilder::build()` is only available if these methods have been called:
+  ///  - `channel`
+  ///  - `post_at`
+  ///  - `message`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::ScheduleMessage, surfaces::Message};
+  ///
+  /// let req =
+  ///   ScheduleMessage::builder().channel("C0123456")
+  ///                             .post_at(1893456000)
+  ///                             .message(Message::builder().text("Happy new year!").build())
+  ///                             .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct ScheduleMessageBuilder<'a, Channel, PostAt, Msg> {
+    channel: Option<Cow<'a, str>>,
+    post_at: Option<i64>,
+    message: Option<Message<'a>>,
+    link_names: Option<bool>,
+    state: PhantomData<(Channel, PostAt, Msg)>,
+  }
+
+  impl<'a, C, P, M> ScheduleMessageBuilder<'a, C, P, M> {
+    /// Create a new ScheduleMessageBuilder
+    pub fn new() -> Self {
+      Self { channel: None,
+             post_at: None,
+             message: None,
+             link_names: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<C2, P2, M2>(self) -> ScheduleMessageBuilder<'a, C2, P2, M2> {
+      ScheduleMessageBuilder { channel: self.channel,
+                               post_at: self.post_at,
+                               message: self.message,
+                               link_names: self.link_names,
+                               state: PhantomData::<_> }
+    }
+
+    /// Set `channel` (**Required**)
+    ///
+    /// Channel, private group, or DM channel to send the message to.
+    pub fn channel(
+      mut self,
+      channel: impl Into<Cow<'a, str>>)
+      -> ScheduleMessageBuilder<'a, Set<method::channel>, P, M> {
+      self.channel = Some(channel.into());
+      self.cast_state()
+    }
+
+    /// Set `post_at` (**Required**)
+    ///
+    /// Unix timestamp (in seconds) representing the future time
+    /// the message should post to Slack.
+    pub fn post_at(
+      mut self,
+      post_at: i64)
+      -> ScheduleMessageBuilder<'a, C, Set<method::post_at>, M> {
+      self.post_at = Some(post_at);
+      self.cast_state()
+    }
+
+    /// Set `message` (**Required**)
+    ///
+    /// The [`Message`] to send.
+    pub fn message(
+      mut self,
+      message: Message<'a>)
+      -> ScheduleMessageBuilder<'a, C, P, Set<method::message>> {
+      self.message = Some(message);
+      self.cast_state()
+    }
+
+    /// Set `link_names` (Optional)
+    ///
+    /// Find and link user groups.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+      self.link_names = Some(link_names);
+      self
+    }
+  }
+
+  impl<'a>
+    ScheduleMessageBuilder<'a,
+                           Set<method::channel>,
+                           Set<method::post_at>,
+                           Set<method::message>>
+  {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'ScheduleMessageBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `ScheduleMessageBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::{api::chat::ScheduleMessage, surfaces::Message};
+    ///
+    /// let foo = ScheduleMessage::builder().channel("C123")
+    ///                                     .message(Message::builder().text("hi").build())
+    ///                                     .build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::chat::ScheduleMessage, surfaces::Message};
+    ///
+    /// let req =
+    ///   ScheduleMessage::builder().channel("C123")
+    ///                             .post_at(1893456000)
+    ///                             .message(Message::builder().text("hi").build())
+    ///                             .build();
+    /// ```
+    pub fn build(self) -> ScheduleMessage<'a> {
+      ScheduleMessage { channel: self.channel.unwrap(),
+                        post_at: self.post_at.unwrap(),
+                        message: self.message.unwrap(),
+                        link_names: self.link_names }
+    }
+  }
+  /// Initial state for `UnfurlBuilder`
+  pub type UnfurlBuilderInit<'a> =
+    UnfurlBuilder<'a,
+                  RequiredMethodNotCalled<method::channel>,
+                  RequiredMethodNotCalled<method::ts>,
+                  RequiredMethodNotCalled<method::unfurls>>;
+
+  /// Build a `chat.unfurl` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `UnfurlBuilder::build()` is only available if these methods have been called:
+  ///  - `channel`
+  ///  - `ts`
+  ///  - `unfurl` or `unfurls`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::chat::Unfurl,
+  ///                    blocks::Section,
+  ///                    surfaces::Attachment,
+  ///                    text::ToSlackMarkdown};
+  ///
+  /// let preview =
+  ///   Attachment::builder().block(Section::builder().text("*INC-42*: Database is on fire".markdown())
+  ///                                                 .build())
+  ///                        .build();
+  ///
+  /// let req = Unfurl::builder().channel("C0123456")
+  ///                            .ts("1623956385.000200")
+  ///                            .unfurl("https://incidents.example.com/42", preview)
+  ///                            .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct UnfurlBuilder<'a, Channel, Ts, Unfurls> {
+    channel: Option<Cow<'a, str>>,
+    ts: Option<Cow<'a, str>>,
+    unfurls: BTreeMap<Cow<'a, str>, Attachment<'a>>,
+    user_auth_message: Option<Cow<'a, str>>,
+    user_auth_required: Option<bool>,
+    user_auth_url: Option<Cow<'a, str>>,
+    user_auth_blocks: Vec<Block<'a>>,
+    state: PhantomData<(Channel, Ts, Unfurls)>,
+  }
+
+  impl<'a, C, T, U> UnfurlBuilder<'a, C, T, U> {
+    /// Create a new UnfurlBuilder
+    pub fn new() -> Self {
+      Self { channel: None,
+             ts: None,
+             unfurls: BTreeMap::new(),
+             user_auth_message: None,
+             user_auth_required: None,
+             user_auth_url: None,
+             user_auth_blocks: vec![],
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<C2, T2, U2>(self) -> UnfurlBuilder<'a, C2, T2, U2> {
+      UnfurlBuilder { channel: self.channel,
+                      ts: self.ts,
+                      unfurls: self.unfurls,
+                      user_auth_message: self.user_auth_message,
+                      user_auth_required: self.user_auth_required,
+                      user_auth_url: self.user_auth_url,
+                      user_auth_blocks: self.user_auth_blocks,
+                      state: PhantomData::<_> }
+    }
+
+    /// Set `channel` (**Required**)
+    ///
+    /// Channel ID of the message containing the links.
+    pub fn channel(mut self,
+                   channel: impl Into<Cow<'a, str>>)
+                   -> UnfurlBuilder<'a, Set<method::channel>, T, U> {
+      self.channel = Some(channel.into());
+      self.cast_state()
+    }
+
+    /// Set `ts` (**Required**)
+    ///
+    /// Timestamp of the message containing the links.
+    pub fn ts(mut self,
+              ts: impl Into<Cow<'a, str>>)
+              -> UnfurlBuilder<'a, C, Set<method::ts>, U> {
+      self.ts = Some(ts.into());
+      self.cast_state()
+    }
+
+    /// Add an unfurl (**Required**, can be called many times)
+    ///
+    /// Unfurl `url` with the contents of `unfurl`.
+    ///
+    /// `url` should be exactly as it was received in the `link_shared` event.
+    pub fn unfurl(mut self,
+                  url: impl Into<Cow<'a, str>>,
+                  unfurl: Attachment<'a>)
+                  -> UnfurlBuilder<'a, C, T, Set<method::unfurls>> {
+      self.unfurls.insert(url.into(), unfurl);
+      self.cast_state()
+    }
+
+    /// Set `unfurls` (**Required**)
+    ///
+    /// Replaces any unfurls added so far with the URL / unfurl pairs in `unfurls`.
+    pub fn unfurls<I, S>(mut self,
+                         unfurls: I)
+                         -> UnfurlBuilder<'a, C, T, Set<method::unfurls>>
+      where I: IntoIterator<Item = (S, Attachment<'a>)>,
+            S: Into<Cow<'a, str>>
+    {
+      self.unfurls = unfurls.into_iter()
+                            .map(|(url, unfurl)| (url.into(), unfurl))
+                            .collect();
+      self.cast_state()
+    }
+
+    /// Set `user_auth_message` (Optional)
+    ///
+    /// Provide a simply-formatted string to send as an ephemeral message
+    /// to the user as invitation to authenticate further and enable full unfurling behavior.
+    pub fn user_auth_message(mut self,
+                             message: impl Into<Cow<'a, str>>)
+                             -> Self {
+      self.user_auth_message = Some(message.into());
+      self
+    }
+
+    /// Set `user_auth_required` (Optional)
+    ///
+    /// Set to `true` to indicate the user must install your Slack app
+    /// to trigger unfurls for this domain.
+    pub fn user_auth_required(mut self, required: bool) -> Self {
+      self.user_auth_required = Some(required);
+      self
+    }
+
+    /// Set `user_auth_url` (Optional)
+    ///
+    /// Send users to this custom URL where they will complete authentication in your app
+    /// to fully trigger unfurling.
+    pub fn user_auth_url(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+      self.user_auth_url = Some(url.into());
+      self
+    }
+
+    /// Add a `user_auth_block` (Optional, can be called many times)
+    ///
+    /// A [layout block 🔗] to send as an ephemeral message
+    /// to the user as invitation to authenticate further.
+    ///
+    /// [layout block 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn user_auth_block(mut self, block: impl Into<Block<'a>>) -> Self {
+      self.user_auth_blocks.push(block.into());
+      self
+    }
+
+    /// Set `user_auth_blocks` (Optional)
+    ///
+    /// Replaces any user auth blocks added so far with `blocks`.
+    pub fn user_auth_blocks<I>(mut self, blocks: I) -> Self
+      where I: IntoIterator,
+            I::Item: Into<Block<'a>>
+    {
+      self.user_auth_blocks = blocks.into_iter().map(Into::into).collect();
+      self
+    }
+  }
+
+  impl<'a>
+    UnfurlBuilder<'a,
+                  Set<method::channel>,
+                  Set<method::ts>,
+                  Set<method::unfurls>>
+  {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'UnfurlBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `UnfurlBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::api::chat::Unfurl;
+    ///
+    /// let foo = Unfurl::builder().channel("C123")
+    ///                            .ts("1623956385.000200")
+    ///                            .build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::chat::Unfurl, surfaces::Attachment};
+    ///
+    /// let req =
+    ///   Unfurl::builder().channel("C123")
+    ///                    .ts("1623956385.000200")
+    ///                    .unfurl("https://example.com",
+    ///                            Attachment::builder().text("Example").build())
+    ///                    .build();
+    /// ```
+    pub fn build(self) -> Unfurl<'a> {
+      Unfurl { channel: self.channel.unwrap(),
+               ts: self.ts.unwrap(),
+               unfurls: self.unfurls,
+               user_auth_message: self.user_auth_message,
+               user_auth_required: self.user_auth_required,
+               user_auth_url: self.user_auth_url,
+               user_auth_blocks: self.user_auth_blocks }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_update(update: &Update) -> ValidatorResult {
+  match update.message.thread_ts {
+    | Some(_) => {
+      Err(error("Update.message", "chat.update does not support `thread_ts`"))
+    },
+    | None => Ok(()),
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_unfurls(unfurl: &Unfurl) -> ValidatorResult {
+  for (url, att) in &unfurl.unfurls {
+    att.validate().map_err(|e| {
+                     let msg = format!("unfurl for {} is invalid: {}", url, e);
+                     error("Unfurl.unfurls", msg)
+                   })?;
+  }
+
+  Ok(())
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::{surfaces::{Surface, ValidateFor},
+              val_helpr::len};
+
+  pub(super) fn channel(channel: &str) -> ValidatorResult {
+    len("channel", 1.., channel)
+  }
+
+  pub(super) fn user(user: &str) -> ValidatorResult {
+    len("user", 1.., user)
+  }
+
+  pub(super) fn ts(ts: &str) -> ValidatorResult {
+    len("ts", 1.., ts)
+  }
+
+  pub(super) fn user_auth_blocks(blocks: &[Block]) -> ValidatorResult {
+    blocks.validate_for(Surface::Message)
+          .map_err(|e| error("Unfurl.user_auth_blocks", e.to_string()))
+  }
+}
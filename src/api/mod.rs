@@ -0,0 +1,19 @@
+//! # Web API request bodies
+//!
+//! [slack api docs 🔗]
+//!
+//! Serializable request bodies for the [Web API methods 🔗] that carry Block Kit content,
+//! as well as payloads for `response_url`s and incoming webhooks,
+//! ready to be sent as JSON with whichever HTTP client you prefer.
+//!
+//! Each Web API request body has a `METHOD` constant with the name of its method,
+//! e.g. `"chat.postMessage"`, which can be appended to `https://slack.com/api/`
+//! to get the method's URL.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/web
+//! [Web API methods 🔗]: https://api.slack.com/methods
+
+pub mod chat;
+pub mod response_url;
+pub mod views;
+pub mod webhook;
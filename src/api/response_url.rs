@@ -0,0 +1,285 @@
+//! # `response_url` replies
+//!
+//! [slack api docs 🔗]
+//!
+//! Interaction payloads sent to your app include a `response_url`,
+//! which can be used to publish a new message to the conversation the interaction
+//! came from, or to replace or delete the message the interaction happened in.
+//!
+//! A `response_url` can be used up to 5 times within 30 minutes.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/interactivity/handling#message_responses
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::surfaces::Message;
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+
+/// # `response_url` reply
+///
+/// [slack api docs 🔗]
+///
+/// A payload to `POST` to an interaction's `response_url`.
+///
+/// A reply either sends a `message` or deletes the original message;
+/// a reply that deletes the original can't set `message`, `replace_original`
+/// or `response_type`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/interactivity/handling#message_responses
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_flags",
+                           skip_on_field_errors = false)))]
+pub struct Reply<'a> {
+  #[serde(flatten,
+          skip_serializing_if = "Option::is_none",
+          deserialize_with = "message::deserialize")]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Option<Message<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  response_type: Option<ResponseType>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  replace_original: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  delete_original: Option<bool>,
+}
+
+impl<'a> Reply<'a> {
+  /// Build a new `response_url` reply.
+  ///
+  /// For example, see docs for [`build::ReplyBuilder`].
+  pub fn builder() -> build::ReplyBuilderInit<'a> {
+    build::ReplyBuilderInit::new()
+  }
+
+  /// Validate that this reply agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If there is no `message` and `delete_original` is not `true`
+  /// - If both `replace_original` and `delete_original` are `true`
+  /// - If `delete_original` is `true` and `message` or `response_type` is set
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::response_url::Reply, surfaces::Message};
+  ///
+  /// let reply = Reply::builder().message(Message::builder().text("Done!")
+  ///                                                        .build())
+  ///                             .replace_original(true)
+  ///                             .delete_original(true)
+  ///                             .build();
+  ///
+  /// assert!(matches!(reply.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+mod message {
+  use serde::{Deserialize, Deserializer};
+
+  use crate::surfaces::Message;
+
+  /// A flattened `Option` is always `Some`, so a reply with none of
+  /// `Message`'s fields (e.g. one that only deletes) has no message
+  pub(super) fn deserialize<'de, 'a, D: Deserializer<'de>>(
+    deserializer: D)
+    -> Result<Option<Message<'a>>, D::Error> {
+    Message::deserialize(deserializer).map(|msg| {
+                                        Some(msg).filter(|msg| !msg.is_empty())
+                                      })
+  }
+}
+
+/// # Response Type
+///
+/// Who a message sent to a `response_url` is visible to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+  /// Only visible to the user who interacted with your app (the default)
+  Ephemeral,
+
+  /// Visible to everyone in the conversation
+  InChannel,
+}
+
+/// `response_url` reply builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// ReplyBuilder.message or ReplyBuilder.delete_original
+    #[derive(Clone, Copy, Debug)]
+    pub struct contents;
+  }
+
+  /// Initial state for `ReplyBuilder`
+  pub type ReplyBuilderInit<'a> =
+    ReplyBuilder<'a, RequiredMethodNotCalled<method::contents>>;
+
+  /// Build a `response_url` reply
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `ReplyBuilder::build()` is only available if one of these methods has been called:
+  ///  - `message`
+  ///  - `delete_original`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::response_url::{Reply, ResponseType},
+  ///                    surfaces::Message};
+  ///
+  /// let announce =
+  ///   Reply::builder().message(Message::builder().text("Lunch is here!").build())
+  ///                   .response_type(ResponseType::InChannel)
+  ///                   .build();
+  ///
+  /// let replace = Reply::builder().message(Message::builder().text("Approved")
+  ///                                                          .build())
+  ///                               .replace_original(true)
+  ///                               .build();
+  ///
+  /// let delete = Reply::builder().delete_original(true).build();
+  /// ```
+  #[derive(Debug)]
+  pub struct ReplyBuilder<'a, Contents> {
+    message: Option<Message<'a>>,
+    response_type: Option<ResponseType>,
+    replace_original: Option<bool>,
+    delete_original: Option<bool>,
+    state: PhantomData<Contents>,
+  }
+
+  impl<'a, C> ReplyBuilder<'a, C> {
+    /// Create a new ReplyBuilder
+    pub fn new() -> Self {
+      Self { message: None,
+             response_type: None,
+             replace_original: None,
+             delete_original: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type param to some other arbitrary marker type param
+    fn cast_state<C2>(self) -> ReplyBuilder<'a, C2> {
+      ReplyBuilder { message: self.message,
+                     response_type: self.response_type,
+                     replace_original: self.replace_original,
+                     delete_original: self.delete_original,
+                     state: PhantomData::<_> }
+    }
+
+    /// Set `message` (this or `delete_original` is **Required**)
+    ///
+    /// The [`Message`] to publish, or to replace the original message with.
+    pub fn message(mut self,
+                   message: Message<'a>)
+                   -> ReplyBuilder<'a, Set<method::contents>> {
+      self.message = Some(message);
+      self.cast_state()
+    }
+
+    /// Set `delete_original` (this or `message` is **Required**)
+    ///
+    /// Pass `true` to delete the message the interaction happened in.
+    ///
+    /// Can't be combined with `message`, `replace_original` or `response_type`.
+    pub fn delete_original(mut self,
+                           delete_original: bool)
+                           -> ReplyBuilder<'a, Set<method::contents>> {
+      self.delete_original = Some(delete_original);
+      self.cast_state()
+    }
+
+    /// Set `response_type` (Optional)
+    ///
+    /// Whether the message is visible only to the user who interacted
+    /// with your app, or to everyone in the conversation.
+    ///
+    /// Defaults to [`ResponseType::Ephemeral`].
+    pub fn response_type(mut self, response_type: ResponseType) -> Self {
+      self.response_type = Some(response_type);
+      self
+    }
+
+    /// Set `replace_original` (Optional)
+    ///
+    /// Pass `true` to replace the message the interaction happened in
+    /// with `message`, instead of publishing a new one.
+    ///
+    /// Can't be combined with `delete_original`.
+    pub fn replace_original(mut self, replace_original: bool) -> Self {
+      self.replace_original = Some(replace_original);
+      self
+    }
+  }
+
+  impl<'a> ReplyBuilder<'a, Set<method::contents>> {
+    /// All done building, now give me a darn reply!
+    ///
+    /// > `no method name 'build' found for struct 'ReplyBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `ReplyBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::api::response_url::Reply;
+    ///
+    /// let foo = Reply::builder().replace_original(true).build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::response_url::Reply, surfaces::Message};
+    ///
+    /// let reply = Reply::builder().message(Message::builder().text("Thanks!")
+    ///                                                        .build())
+    ///                             .build();
+    /// ```
+    pub fn build(self) -> Reply<'a> {
+      Reply { message: self.message,
+              response_type: self.response_type,
+              replace_original: self.replace_original,
+              delete_original: self.delete_original }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_flags(reply: &Reply) -> ValidatorResult {
+  let replace = reply.replace_original == Some(true);
+  let delete = reply.delete_original == Some(true);
+
+  if !delete && reply.message.is_none() {
+    Err(error("Reply",
+              "a reply needs a `message` unless `delete_original` is true"))
+  } else if replace && delete {
+    Err(error("Reply",
+              "`replace_original` and `delete_original` can't both be true"))
+  } else if delete && reply.message.is_some() {
+    Err(error("Reply",
+              "`message` can't be set when `delete_original` is true"))
+  } else if delete && reply.response_type.is_some() {
+    Err(error("Reply",
+              "`response_type` can't be set when `delete_original` is true"))
+  } else {
+    Ok(())
+  }
+}
@@ -0,0 +1,793 @@
+//! # `views.*` request bodies
+//!
+//! Request bodies for the Web API methods that open, update and publish views:
+//!  - [`views.open` 🔗] - [`Open`]
+//!  - [`views.push` 🔗] - [`Push`]
+//!  - [`views.update` 🔗] - [`Update`]
+//!  - [`views.publish` 🔗] - [`Publish`]
+//!
+//! [`views.open` 🔗]: https://api.slack.com/methods/views.open
+//! [`views.push` 🔗]: https://api.slack.com/methods/views.push
+//! [`views.update` 🔗]: https://api.slack.com/methods/views.update
+//! [`views.publish` 🔗]: https://api.slack.com/methods/views.publish
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::surfaces::{HomeTab, Modal};
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+
+/// # `views.open`
+///
+/// [slack api docs 🔗]
+///
+/// Opens a modal with a user, in response to an interaction
+/// that provided a `trigger_id` (or `interactivity_pointer`).
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/views.open
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_open",
+                           skip_on_field_errors = false)))]
+pub struct Open<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  trigger_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  interactivity_pointer: Option<Cow<'a, str>>,
+
+  #[cfg_attr(feature = "validation", validate)]
+  view: Modal<'a>,
+}
+
+impl<'a> Open<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "views.open";
+
+  /// Build a new `views.open` request body.
+  ///
+  /// For example, see docs for [`build::OpenBuilder`].
+  pub fn builder() -> build::OpenBuilderInit<'a> {
+    build::OpenBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If neither or both of `trigger_id` and `interactivity_pointer` are set, or it's empty
+  /// - If `view` is invalid (see [`Modal::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Open, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+  /// let req = Open::builder().trigger_id("").view(modal).build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `views.push`
+///
+/// [slack api docs 🔗]
+///
+/// Pushes a new modal onto the stack of an open modal,
+/// in response to an interaction within that modal.
+///
+/// At most 3 views can be in a modal's stack at once.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/views.push
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_push",
+                           skip_on_field_errors = false)))]
+pub struct Push<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  trigger_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  interactivity_pointer: Option<Cow<'a, str>>,
+
+  #[cfg_attr(feature = "validation", validate)]
+  view: Modal<'a>,
+}
+
+impl<'a> Push<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "views.push";
+
+  /// Build a new `views.push` request body.
+  ///
+  /// For example, see docs for [`build::PushBuilder`].
+  pub fn builder() -> build::PushBuilderInit<'a> {
+    build::PushBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If neither or both of `trigger_id` and `interactivity_pointer` are set, or it's empty
+  /// - If `view` is invalid (see [`Modal::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Push, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("This title is much too long")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  /// let req = Push::builder().trigger_id("12345.98765.abcd2358fdea")
+  ///                          .view(modal)
+  ///                          .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `views.update`
+///
+/// [slack api docs 🔗]
+///
+/// Updates an existing modal, identified by its `view_id` or `external_id`.
+///
+/// Pass the `hash` of the view you're updating to protect against
+/// race conditions; Slack will reject the update if the view has
+/// been changed since that `hash` was issued.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/views.update
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_update",
+                           skip_on_field_errors = false)))]
+pub struct Update<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  view_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  external_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  hash: Option<Cow<'a, str>>,
+
+  #[cfg_attr(feature = "validation", validate)]
+  view: Modal<'a>,
+}
+
+impl<'a> Update<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "views.update";
+
+  /// Build a new `views.update` request body.
+  ///
+  /// For example, see docs for [`build::UpdateBuilder`].
+  pub fn builder() -> build::UpdateBuilderInit<'a> {
+    build::UpdateBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If neither or both of `view_id` and `external_id` are set, or it's empty
+  /// - If `view` is invalid (see [`Modal::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Update, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+  /// let req = Update::builder().view_id("VMM512F2U")
+  ///                            .external_id("my_view")
+  ///                            .view(modal)
+  ///                            .build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # `views.publish`
+///
+/// [slack api docs 🔗]
+///
+/// Publishes a static [`HomeTab`] for a user.
+///
+/// [slack api docs 🔗]: https://api.slack.com/methods/views.publish
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Publish<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::user_id"))]
+  user_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  hash: Option<Cow<'a, str>>,
+
+  #[cfg_attr(feature = "validation", validate)]
+  view: HomeTab<'a>,
+}
+
+impl<'a> Publish<'a> {
+  /// The name of this Web API method
+  pub const METHOD: &'static str = "views.publish";
+
+  /// Build a new `views.publish` request body.
+  ///
+  /// For example, see docs for [`build::PublishBuilder`].
+  pub fn builder() -> build::PublishBuilderInit<'a> {
+    build::PublishBuilderInit::new()
+  }
+
+  /// Validate that this request body agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `user_id` is empty
+  /// - If `view` is invalid (see [`HomeTab::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Publish, blocks::Block, surfaces::HomeTab};
+  ///
+  /// let home = HomeTab::builder().block(Block::Divider).build();
+  /// let req = Publish::builder().user_id("").view(home).build();
+  ///
+  /// assert!(matches!(req.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// `views.*` request body builders
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// OpenBuilder.trigger_id or OpenBuilder.interactivity_pointer, etc.
+    #[derive(Clone, Copy, Debug)]
+    pub struct trigger_id;
+
+    /// UpdateBuilder.view_id or UpdateBuilder.external_id
+    #[derive(Clone, Copy, Debug)]
+    pub struct view_id;
+
+    /// PublishBuilder.user_id
+    #[derive(Clone, Copy, Debug)]
+    pub struct user_id;
+
+    /// OpenBuilder.view, etc.
+    #[derive(Clone, Copy, Debug)]
+    pub struct view;
+  }
+
+  /// Initial state for `OpenBuilder`
+  pub type OpenBuilderInit<'a> =
+    OpenBuilder<'a,
+                RequiredMethodNotCalled<method::trigger_id>,
+                RequiredMethodNotCalled<method::view>>;
+
+  /// Build a `views.open` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `OpenBuilder::build()` is only available if these methods have been called:
+  ///  - `trigger_id` or `interactivity_pointer`
+  ///  - `view`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Open, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("Hello!")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  ///
+  /// let req = Open::builder().trigger_id("12345.98765.abcd2358fdea")
+  ///                          .view(modal)
+  ///                          .build();
+  ///
+  /// let body = serde_json::to_string(&req).unwrap();
+  /// // POST https://slack.com/api/views.open
+  /// ```
+  #[derive(Debug)]
+  pub struct OpenBuilder<'a, Trigger, View> {
+    trigger_id: Option<Cow<'a, str>>,
+    interactivity_pointer: Option<Cow<'a, str>>,
+    view: Option<Modal<'a>>,
+    state: PhantomData<(Trigger, View)>,
+  }
+
+  impl<'a, T, V> OpenBuilder<'a, T, V> {
+    /// Create a new OpenBuilder
+    pub fn new() -> Self {
+      Self { trigger_id: None,
+             interactivity_pointer: None,
+             view: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<T2, V2>(self) -> OpenBuilder<'a, T2, V2> {
+      OpenBuilder { trigger_id: self.trigger_id,
+                    interactivity_pointer: self.interactivity_pointer,
+                    view: self.view,
+                    state: PhantomData::<_> }
+    }
+
+    /// Set `trigger_id` (this or `interactivity_pointer` is **Required**)
+    ///
+    /// Exchange a trigger to post to the user.
+    /// Triggers expire 3 seconds after they're issued.
+    pub fn trigger_id(mut self,
+                      trigger_id: impl Into<Cow<'a, str>>)
+                      -> OpenBuilder<'a, Set<method::trigger_id>, V> {
+      self.trigger_id = Some(trigger_id.into());
+      self.cast_state()
+    }
+
+    /// Set `interactivity_pointer` (this or `trigger_id` is **Required**)
+    ///
+    /// Exchange an interactivity pointer to post to the user.
+    pub fn interactivity_pointer(
+      mut self,
+      pointer: impl Into<Cow<'a, str>>)
+      -> OpenBuilder<'a, Set<method::trigger_id>, V> {
+      self.interactivity_pointer = Some(pointer.into());
+      self.cast_state()
+    }
+
+    /// Set `view` (**Required**)
+    ///
+    /// The [`Modal`] to open.
+    pub fn view(mut self,
+                view: Modal<'a>)
+                -> OpenBuilder<'a, T, Set<method::view>> {
+      self.view = Some(view);
+      self.cast_state()
+    }
+  }
+
+  impl<'a> OpenBuilder<'a, Set<method::trigger_id>, Set<method::view>> {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'OpenBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `OpenBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::{api::views::Open, blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+    /// let foo = Open::builder().view(modal).build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::views::Open, blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+    /// let req = Open::builder().trigger_id("12345.98765.abcd2358fdea")
+    ///                          .view(modal)
+    ///                          .build();
+    /// ```
+    pub fn build(self) -> Open<'a> {
+      Open { trigger_id: self.trigger_id,
+             interactivity_pointer: self.interactivity_pointer,
+             view: self.view.unwrap() }
+    }
+  }
+
+  /// Initial state for `PushBuilder`
+  pub type PushBuilderInit<'a> =
+    PushBuilder<'a,
+                RequiredMethodNotCalled<method::trigger_id>,
+                RequiredMethodNotCalled<method::view>>;
+
+  /// Build a `views.push` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `PushBuilder::build()` is only available if these methods have been called:
+  ///  - `trigger_id` or `interactivity_pointer`
+  ///  - `view`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Push, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("Step 2")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  ///
+  /// let req = Push::builder().trigger_id("12345.98765.abcd2358fdea")
+  ///                          .view(modal)
+  ///                          .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct PushBuilder<'a, Trigger, View> {
+    trigger_id: Option<Cow<'a, str>>,
+    interactivity_pointer: Option<Cow<'a, str>>,
+    view: Option<Modal<'a>>,
+    state: PhantomData<(Trigger, View)>,
+  }
+
+  impl<'a, T, V> PushBuilder<'a, T, V> {
+    /// Create a new PushBuilder
+    pub fn new() -> Self {
+      Self { trigger_id: None,
+             interactivity_pointer: None,
+             view: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<T2, V2>(self) -> PushBuilder<'a, T2, V2> {
+      PushBuilder { trigger_id: self.trigger_id,
+                    interactivity_pointer: self.interactivity_pointer,
+                    view: self.view,
+                    state: PhantomData::<_> }
+    }
+
+    /// Set `trigger_id` (this or `interactivity_pointer` is **Required**)
+    ///
+    /// Exchange a trigger to post to the user.
+    /// Triggers expire 3 seconds after they're issued.
+    pub fn trigger_id(mut self,
+                      trigger_id: impl Into<Cow<'a, str>>)
+                      -> PushBuilder<'a, Set<method::trigger_id>, V> {
+      self.trigger_id = Some(trigger_id.into());
+      self.cast_state()
+    }
+
+    /// Set `interactivity_pointer` (this or `trigger_id` is **Required**)
+    ///
+    /// Exchange an interactivity pointer to post to the user.
+    pub fn interactivity_pointer(
+      mut self,
+      pointer: impl Into<Cow<'a, str>>)
+      -> PushBuilder<'a, Set<method::trigger_id>, V> {
+      self.interactivity_pointer = Some(pointer.into());
+      self.cast_state()
+    }
+
+    /// Set `view` (**Required**)
+    ///
+    /// The [`Modal`] to push onto the stack.
+    pub fn view(mut self,
+                view: Modal<'a>)
+                -> PushBuilder<'a, T, Set<method::view>> {
+      self.view = Some(view);
+      self.cast_state()
+    }
+  }
+
+  impl<'a> PushBuilder<'a, Set<method::trigger_id>, Set<method::view>> {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'PushBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `PushBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::api::views::Push;
+    ///
+    /// let foo = Push::builder().trigger_id("12345.98765.abcd2358fdea").build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::views::Push, blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+    /// let req = Push::builder().trigger_id("12345.98765.abcd2358fdea")
+    ///                          .view(modal)
+    ///                          .build();
+    /// ```
+    pub fn build(self) -> Push<'a> {
+      Push { trigger_id: self.trigger_id,
+             interactivity_pointer: self.interactivity_pointer,
+             view: self.view.unwrap() }
+    }
+  }
+
+  /// Initial state for `UpdateBuilder`
+  pub type UpdateBuilderInit<'a> =
+    UpdateBuilder<'a,
+                  RequiredMethodNotCalled<method::view_id>,
+                  RequiredMethodNotCalled<method::view>>;
+
+  /// Build a `views.update` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `UpdateBuilder::build()` is only available if these methods have been called:
+  ///  - `view_id` or `external_id`
+  ///  - `view`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Update, blocks::Block, surfaces::Modal};
+  ///
+  /// let modal = Modal::builder().title("Updated!")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  ///
+  /// let req = Update::builder().view_id("VMM512F2U")
+  ///                            .hash("156772938.1827394")
+  ///                            .view(modal)
+  ///                            .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct UpdateBuilder<'a, ViewId, View> {
+    view_id: Option<Cow<'a, str>>,
+    external_id: Option<Cow<'a, str>>,
+    hash: Option<Cow<'a, str>>,
+    view: Option<Modal<'a>>,
+    state: PhantomData<(ViewId, View)>,
+  }
+
+  impl<'a, I, V> UpdateBuilder<'a, I, V> {
+    /// Create a new UpdateBuilder
+    pub fn new() -> Self {
+      Self { view_id: None,
+             external_id: None,
+             hash: None,
+             view: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<I2, V2>(self) -> UpdateBuilder<'a, I2, V2> {
+      UpdateBuilder { view_id: self.view_id,
+                      external_id: self.external_id,
+                      hash: self.hash,
+                      view: self.view,
+                      state: PhantomData::<_> }
+    }
+
+    /// Set `view_id` (this or `external_id` is **Required**)
+    ///
+    /// A unique identifier of the view to be updated.
+    pub fn view_id(mut self,
+                   view_id: impl Into<Cow<'a, str>>)
+                   -> UpdateBuilder<'a, Set<method::view_id>, V> {
+      self.view_id = Some(view_id.into());
+      self.cast_state()
+    }
+
+    /// Set `external_id` (this or `view_id` is **Required**)
+    ///
+    /// A unique identifier of the view set by the developer.
+    pub fn external_id(mut self,
+                       external_id: impl Into<Cow<'a, str>>)
+                       -> UpdateBuilder<'a, Set<method::view_id>, V> {
+      self.external_id = Some(external_id.into());
+      self.cast_state()
+    }
+
+    /// Set `hash` (Optional)
+    ///
+    /// A string that represents view state to protect against possible race conditions.
+    ///
+    /// Use the `hash` of the view being updated, e.g. from [`Modal::hash`].
+    pub fn hash(mut self, hash: impl Into<Cow<'a, str>>) -> Self {
+      self.hash = Some(hash.into());
+      self
+    }
+
+    /// Set `view` (**Required**)
+    ///
+    /// The [`Modal`] to replace the existing view with.
+    pub fn view(mut self,
+                view: Modal<'a>)
+                -> UpdateBuilder<'a, I, Set<method::view>> {
+      self.view = Some(view);
+      self.cast_state()
+    }
+  }
+
+  impl<'a> UpdateBuilder<'a, Set<method::view_id>, Set<method::view>> {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'UpdateBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `UpdateBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::{api::views::Update, blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+    /// let foo = Update::builder().view(modal).build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::views::Update, blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Hi").block(Block::Divider).build();
+    /// let req = Update::builder().external_id("my_view").view(modal).build();
+    /// ```
+    pub fn build(self) -> Update<'a> {
+      Update { view_id: self.view_id,
+               external_id: self.external_id,
+               hash: self.hash,
+               view: self.view.unwrap() }
+    }
+  }
+
+  /// Initial state for `PublishBuilder`
+  pub type PublishBuilderInit<'a> =
+    PublishBuilder<'a,
+                   RequiredMethodNotCalled<method::user_id>,
+                   RequiredMethodNotCalled<method::view>>;
+
+  /// Build a `views.publish` request body
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `PublishBuilder::build()` is only available if these methods have been called:
+  ///  - `user_id`
+  ///  - `view`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::views::Publish, blocks::Block, surfaces::HomeTab};
+  ///
+  /// let home = HomeTab::builder().block(Block::Divider).build();
+  ///
+  /// let req = Publish::builder().user_id("U0123456").view(home).build();
+  /// ```
+  #[derive(Debug)]
+  pub struct PublishBuilder<'a, UserId, View> {
+    user_id: Option<Cow<'a, str>>,
+    hash: Option<Cow<'a, str>>,
+    view: Option<HomeTab<'a>>,
+    state: PhantomData<(UserId, View)>,
+  }
+
+  impl<'a, U, V> PublishBuilder<'a, U, V> {
+    /// Create a new PublishBuilder
+    pub fn new() -> Self {
+      Self { user_id: None,
+             hash: None,
+             view: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<U2, V2>(self) -> PublishBuilder<'a, U2, V2> {
+      PublishBuilder { user_id: self.user_id,
+                       hash: self.hash,
+                       view: self.view,
+                       state: PhantomData::<_> }
+    }
+
+    /// Set `user_id` (**Required**)
+    ///
+    /// `id` of the user you want to publish a view to.
+    pub fn user_id(mut self,
+                   user_id: impl Into<Cow<'a, str>>)
+                   -> PublishBuilder<'a, Set<method::user_id>, V> {
+      self.user_id = Some(user_id.into());
+      self.cast_state()
+    }
+
+    /// Set `hash` (Optional)
+    ///
+    /// A string that represents view state to protect against possible race conditions.
+    pub fn hash(mut self, hash: impl Into<Cow<'a, str>>) -> Self {
+      self.hash = Some(hash.into());
+      self
+    }
+
+    /// Set `view` (**Required**)
+    ///
+    /// The [`HomeTab`] to publish.
+    pub fn view(mut self,
+                view: HomeTab<'a>)
+                -> PublishBuilder<'a, U, Set<method::view>> {
+      self.view = Some(view);
+      self.cast_state()
+    }
+  }
+
+  impl<'a> PublishBuilder<'a, Set<method::user_id>, Set<method::view>> {
+    /// All done building, now give me a darn request body!
+    ///
+    /// > `no method name 'build' found for struct 'PublishBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `PublishBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::api::views::Publish;
+    ///
+    /// let foo = Publish::builder().user_id("U123").build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{api::views::Publish, blocks::Block, surfaces::HomeTab};
+    ///
+    /// let home = HomeTab::builder().block(Block::Divider).build();
+    /// let req = Publish::builder().user_id("U123").view(home).build();
+    /// ```
+    pub fn build(self) -> Publish<'a> {
+      Publish { user_id: self.user_id.unwrap(),
+                hash: self.hash,
+                view: self.view.unwrap() }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_open(open: &Open) -> ValidatorResult {
+  validate::one_of("Open",
+                   ("trigger_id", &open.trigger_id),
+                   ("interactivity_pointer", &open.interactivity_pointer))
+}
+
+#[cfg(feature = "validation")]
+fn validate_push(push: &Push) -> ValidatorResult {
+  validate::one_of("Push",
+                   ("trigger_id", &push.trigger_id),
+                   ("interactivity_pointer", &push.interactivity_pointer))
+}
+
+#[cfg(feature = "validation")]
+fn validate_update(update: &Update) -> ValidatorResult {
+  validate::one_of("Update",
+                   ("view_id", &update.view_id),
+                   ("external_id", &update.external_id))
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::val_helpr::len;
+
+  type Named<'a, 'b> = (&'static str, &'b Option<Cow<'a, str>>);
+
+  /// Exactly one of the two fields must be set, and non-empty.
+  pub(super) fn one_of(context: &'static str,
+                       (a_name, a): Named,
+                       (b_name, b): Named)
+                       -> ValidatorResult {
+    match (a, b) {
+      | (Some(val), None) | (None, Some(val)) => len(context, 1.., val),
+      | _ => Err(error(context,
+                       format!("exactly one of `{}` or `{}` must be set",
+                               a_name, b_name))),
+    }
+  }
+
+  pub(super) fn user_id(user_id: &str) -> ValidatorResult {
+    len("Publish.user_id", 1.., user_id)
+  }
+}
@@ -0,0 +1,90 @@
+//! # Incoming Webhooks
+//!
+//! [slack api docs 🔗]
+//!
+//! Incoming Webhooks are a simple way to post messages from apps into Slack,
+//! by sending a JSON payload to a unique URL.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/messaging/webhooks
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+use crate::{convert, surfaces::Message};
+
+/// # Incoming Webhook payload
+///
+/// [slack api docs 🔗]
+///
+/// A payload to `POST` to an incoming webhook URL.
+///
+/// The channel a webhook posts to is fixed when the webhook is created,
+/// so a webhook payload is only a [`Message`].
+///
+/// [slack api docs 🔗]: https://api.slack.com/messaging/webhooks
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_flags",
+                           skip_on_field_errors = false)))]
+pub struct Webhook<'a> {
+  #[serde(flatten)]
+  #[cfg_attr(feature = "validation", validate)]
+  message: Message<'a>,
+}
+
+impl<'a> Webhook<'a> {
+  /// Create an incoming webhook payload from a message
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::webhook::Webhook, blocks::Block, surfaces::Message};
+  ///
+  /// let msg = Message::builder().text("Nightly build failed")
+  ///                             .block(Block::Divider)
+  ///                             .build();
+  ///
+  /// let payload = serde_json::to_string(&Webhook::new(msg)).unwrap();
+  /// ```
+  pub fn new(message: Message<'a>) -> Self {
+    Self { message }
+  }
+
+  /// Validate that this payload agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `message` sets `reply_broadcast` without `thread_ts`
+  /// - If `message` is invalid (see [`Message::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{api::webhook::Webhook, surfaces::Message};
+  ///
+  /// let msg = Message::builder().text("hi").reply_broadcast(true).build();
+  ///
+  /// assert!(matches!(Webhook::new(msg).validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+convert!(impl<'a> From<Message<'a>> for Webhook<'a> => |m| Webhook::new(m));
+
+#[cfg(feature = "validation")]
+fn validate_flags(webhook: &Webhook) -> ValidatorResult {
+  let msg = &webhook.message;
+
+  match (msg.reply_broadcast, &msg.thread_ts) {
+    | (Some(true), None) => {
+      Err(error("Webhook.message",
+                "`reply_broadcast` can only be used with `thread_ts`"))
+    },
+    | _ => Ok(()),
+  }
+}
@@ -37,12 +37,12 @@ use crate::{convert,
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct Actions<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 5)))]
-  elements: Vec<SupportedElement<'a>>,
+  pub(crate) elements: Vec<SupportedElement<'a>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation",
              validate(custom = "super::validate_block_id"))]
-  block_id: Option<Cow<'a, str>>,
+  pub(crate) block_id: Option<Cow<'a, str>>,
 }
 
 impl<'a> Actions<'a> {
@@ -231,7 +231,7 @@ pub mod build {
 ///   - Static
 ///   - User
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct SupportedElement<'a>(BlockElement<'a>);
+pub struct SupportedElement<'a>(pub(crate) BlockElement<'a>);
 
 impl<'a> TryFrom<BlockElement<'a>> for self::SupportedElement<'a> {
   type Error = super::UnsupportedElement<'a>;
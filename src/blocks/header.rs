@@ -86,7 +86,7 @@ impl<'a> Header<'a> {
   #[cfg(feature = "validation")]
   #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
   pub fn validate(&self) -> ValidationResult {
-    todo!()
+    Validate::validate(self)
   }
 }
 
@@ -40,22 +40,22 @@ pub struct Input<'a> {
   #[cfg_attr(feature = "validation", validate(custom = "validate::label"))]
   label: text::Text,
 
-  element: SupportedElement<'a>,
+  pub(crate) element: SupportedElement<'a>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation",
              validate(custom = "super::validate_block_id"))]
-  block_id: Option<Cow<'a, str>>,
+  pub(crate) block_id: Option<Cow<'a, str>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(custom = "validate::hint"))]
   hint: Option<text::Text>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  dispatch_action: Option<bool>,
+  pub(crate) dispatch_action: Option<bool>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  optional: Option<bool>,
+  pub(crate) optional: Option<bool>,
 }
 
 impl<'a> Input<'a> {
@@ -323,7 +323,7 @@ pub mod build {
 /// - All Select Menus
 /// - All Multi-Select Menus
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct SupportedElement<'a>(BlockElement<'a>);
+pub struct SupportedElement<'a>(pub(crate) BlockElement<'a>);
 
 convert!(impl<'a> From<elems::Radio<'a>> for SupportedElement<'a> => |r| SupportedElement(BlockElement::from(r)));
 convert!(impl<'a> From<elems::TextInput<'a>> for SupportedElement<'a> => |r| SupportedElement(BlockElement::from(r)));
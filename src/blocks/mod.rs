@@ -13,7 +13,9 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-use crate::convert;
+use crate::{convert,
+            elems::BlockElement,
+            surfaces::{Surface, SurfaceError, SurfaceErrorReason}};
 
 pub mod actions;
 #[doc(inline)]
@@ -43,6 +45,10 @@ pub mod header;
 #[doc(inline)]
 pub use header::Header;
 
+pub mod rich_text;
+#[doc(inline)]
+pub use rich_text::RichText;
+
 /// # Layout Blocks
 ///
 /// Blocks are a series of components that can be combined
@@ -53,7 +59,7 @@ pub use header::Header;
 /// You can include up to 50 blocks in each message, and 100 blocks in modals or home tabs.
 ///
 /// [building block layouts 🔗]: https://api.slack.com/block-kit/building
-#[derive(Hash, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Block<'a> {
   /// # Section Block
@@ -88,6 +94,16 @@ pub enum Block<'a> {
 
   /// # File Block
   File(File<'a>),
+
+  /// # Rich Text Block
+  RichText(RichText<'a>),
+
+  /// A block this crate doesn't model yet, e.g. in a message
+  /// received from Slack.
+  ///
+  /// Its contents are discarded, so it can't be sent back to Slack.
+  #[serde(other)]
+  Unknown,
 }
 
 impl fmt::Display for Block<'_> {
@@ -101,6 +117,8 @@ impl fmt::Display for Block<'_> {
       | Block::Context { .. } => "Context",
       | Block::Input { .. } => "Input",
       | Block::File { .. } => "File",
+      | Block::RichText { .. } => "RichText",
+      | Block::Unknown => "Unknown",
     };
 
     write!(f, "{}", kind)
@@ -132,9 +150,81 @@ impl<'a> Block<'a> {
       | Input(contents) => contents.validate(),
       | Header(contents) => contents.validate(),
       | File(contents) => contents.validate(),
-      | Divider => Ok(()),
+      | RichText(contents) => contents.validate(),
+      | Divider | Unknown => Ok(()),
     }
   }
+
+  /// Validate that this block can be used in some [`Surface`].
+  ///
+  /// # Errors
+  /// - If this is an [`Unknown`](Block::Unknown) block
+  /// - If this is a `File` block and `surface` is not [`Surface::Message`]
+  ///   or [`Surface::Attachment`]
+  /// - If this is an `Input` block, `surface` is not [`Surface::Modal`]
+  ///   and `dispatch_action` is not `true`
+  /// - If this is a `Section` block whose accessory can't be used in a section
+  ///   or in `surface` (see [`BlockElement::validate_for`])
+  /// - If this is an `Actions` or `Input` block containing an element
+  ///   that can't be used in `surface`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::{Block, Input},
+  ///                    elems::TextInput,
+  ///                    surfaces::{Surface, SurfaceErrorReason}};
+  ///
+  /// let input: Block =
+  ///   Input::builder().label("Name")
+  ///                   .element(TextInput::builder().action_id("name").build())
+  ///                   .build()
+  ///                   .into();
+  ///
+  /// assert_eq!(input.validate_for(Surface::Modal), Ok(()));
+  ///
+  /// let err = input.validate_for(Surface::Message).unwrap_err();
+  /// assert_eq!(err.reason(),
+  ///            &SurfaceErrorReason::InputRequiresDispatchAction);
+  /// ```
+  pub fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError> {
+    use SurfaceErrorReason::*;
+
+    let err = |reason| Err(SurfaceError::new(surface, reason));
+
+    match self {
+      | Block::Unknown => err(BlockNotSupported { block: "Unknown" }),
+      | Block::File(_) if !surface.follows_message_rules() => {
+        err(BlockNotSupported { block: "File" })
+      },
+      | Block::Input(input)
+        if surface != Surface::Modal && input.dispatch_action != Some(true) =>
+      {
+        err(InputRequiresDispatchAction)
+      },
+      | Block::Section(Section { accessory: Some(acc),
+                                 .. }) => match acc {
+        | BlockElement::TextInput(_) => {
+          err(ElementNotSupportedInBlock { element: acc.kind(),
+                                           block: "Section" })
+        },
+        | _ => acc.validate_for(surface),
+      },
+      | Block::Actions(actions) => {
+        actions.elements
+               .iter()
+               .try_for_each(|el| el.0.validate_for(surface))
+      },
+      | Block::Input(input) => input.element.0.validate_for(surface),
+      | _ => Ok(()),
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+impl validator::Validate for Block<'_> {
+  fn validate(&self) -> crate::val_helpr::ValidationResult {
+    Block::validate(self)
+  }
 }
 
 convert!(impl<'a> From<Actions<'a>> for Block<'a> => |a| Block::Actions(a));
@@ -144,6 +234,7 @@ convert!(impl<'a> From<Image<'a>>   for Block<'a> => |a| Block::Image(a));
 convert!(impl<'a> From<Context<'a>> for Block<'a> => |a| Block::Context(a));
 convert!(impl<'a> From<File<'a>>    for Block<'a> => |a| Block::File(a));
 convert!(impl<'a> From<Header<'a>>  for Block<'a> => |a| Block::Header(a));
+convert!(impl<'a> From<RichText<'a>> for Block<'a> => |a| Block::RichText(a));
 
 /// Error yielded when `TryFrom` is called on an unsupported block element.
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
@@ -0,0 +1,524 @@
+//! # Rich Text Inline Elements
+//!
+//! [slack api docs 🔗]
+//!
+//! The contents of rich text [sections](super::Section), [quotes](super::Quote)
+//! and [preformatted text](super::Preformatted): styled text, links, emoji,
+//! mentions of users, channels and user groups, and so on.
+//!
+//! Text, links and mentions can be styled as bold, italic,
+//! strikethrough and/or code:
+//!
+//! ```
+//! use slack_blocks::blocks::rich_text::inline::{Inline, Text};
+//!
+//! let text: Inline = Text::new("Very important").bold().italic().into();
+//!
+//! assert_eq!(serde_json::to_value(&text).unwrap(),
+//!            serde_json::json!({
+//!              "type": "text",
+//!              "text": "Very important",
+//!              "style": { "bold": true, "italic": true }
+//!            }));
+//! ```
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#element-types
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::convert;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Inline Element
+///
+/// An element within a rich text [section](super::Section),
+/// [quote](super::Quote) or [preformatted text](super::Preformatted).
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Inline<'a> {
+  /// Text, optionally styled
+  Text(Text<'a>),
+
+  /// A link
+  Link(Link<'a>),
+
+  /// An emoji
+  Emoji(Emoji<'a>),
+
+  /// A mention of a user
+  User(User<'a>),
+
+  /// A mention of a channel
+  Channel(Channel<'a>),
+
+  /// A mention of a user group
+  Usergroup(Usergroup<'a>),
+
+  /// A mention of `@here`, `@channel` or `@everyone`
+  Broadcast(Broadcast),
+
+  /// A date, displayed in the reader's timezone
+  Date(Date<'a>),
+
+  /// A color, displayed with a swatch
+  Color(Color<'a>),
+
+  /// An inline element this crate doesn't model yet
+  #[serde(other)]
+  Unknown,
+}
+
+impl<'a> Inline<'a> {
+  /// Validate that this element agrees with Slack's model requirements
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    match self {
+      | Inline::Text(el) => el.validate(),
+      | Inline::Link(el) => el.validate(),
+      | Inline::Emoji(el) => el.validate(),
+      | Inline::User(el) => el.validate(),
+      | Inline::Channel(el) => el.validate(),
+      | Inline::Usergroup(el) => el.validate(),
+      | Inline::Broadcast(_) | Inline::Unknown => Ok(()),
+      | Inline::Date(el) => el.validate(),
+      | Inline::Color(el) => el.validate(),
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+impl Validate for Inline<'_> {
+  fn validate(&self) -> ValidationResult {
+    Inline::validate(self)
+  }
+}
+
+convert!(impl<'a> From<Text<'a>> for Inline<'a> => |e| Inline::Text(e));
+convert!(impl<'a> From<Link<'a>> for Inline<'a> => |e| Inline::Link(e));
+convert!(impl<'a> From<Emoji<'a>> for Inline<'a> => |e| Inline::Emoji(e));
+convert!(impl<'a> From<User<'a>> for Inline<'a> => |e| Inline::User(e));
+convert!(impl<'a> From<Channel<'a>> for Inline<'a> => |e| Inline::Channel(e));
+convert!(impl<'a> From<Usergroup<'a>> for Inline<'a> => |e| Inline::Usergroup(e));
+convert!(impl<'a> From<Broadcast> for Inline<'a> => |e| Inline::Broadcast(e));
+convert!(impl<'a> From<Date<'a>> for Inline<'a> => |e| Inline::Date(e));
+convert!(impl<'a> From<Color<'a>> for Inline<'a> => |e| Inline::Color(e));
+convert!(impl<'a> From<&'a str> for Inline<'a> => |s| Text::new(s).into());
+convert!(impl<'a> From<String> for Inline<'a> => |s| Text::new(s).into());
+
+/// # Style
+///
+/// How [`Text`], [`Link`]s and mentions are styled.
+#[derive(Clone,
+           Copy,
+           Debug,
+           Default,
+           Deserialize,
+           Eq,
+           Hash,
+           PartialEq,
+           Serialize)]
+pub struct Style {
+  #[serde(default, skip_serializing_if = "is_false")]
+  bold: bool,
+
+  #[serde(default, skip_serializing_if = "is_false")]
+  italic: bool,
+
+  #[serde(default, skip_serializing_if = "is_false")]
+  strike: bool,
+
+  #[serde(default, skip_serializing_if = "is_false")]
+  code: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+  !b
+}
+
+impl Style {
+  /// Whether the element is bold
+  pub fn is_bold(&self) -> bool {
+    self.bold
+  }
+
+  /// Whether the element is italic
+  pub fn is_italic(&self) -> bool {
+    self.italic
+  }
+
+  /// Whether the element is struck through
+  pub fn is_strike(&self) -> bool {
+    self.strike
+  }
+
+  /// Whether the element is displayed as inline code
+  pub fn is_code(&self) -> bool {
+    self.code
+  }
+}
+
+/// Add the style setters and getter to an inline element with a `style` field
+macro_rules! styled {
+  ($($el:ident),+) => {$(
+    impl<'a> $el<'a> {
+      /// Display in bold
+      pub fn bold(self) -> Self {
+        self.styled(|s| s.bold = true)
+      }
+
+      /// Display in italics
+      pub fn italic(self) -> Self {
+        self.styled(|s| s.italic = true)
+      }
+
+      /// Display with a line through it
+      pub fn strike(self) -> Self {
+        self.styled(|s| s.strike = true)
+      }
+
+      /// Display as inline code
+      pub fn code(self) -> Self {
+        self.styled(|s| s.code = true)
+      }
+
+      /// How this element is styled
+      pub fn style(&self) -> Style {
+        self.style.unwrap_or_default()
+      }
+
+      fn styled(mut self, f: impl FnOnce(&mut Style)) -> Self {
+        let mut style = self.style.unwrap_or_default();
+        f(&mut style);
+        self.style = Some(style);
+        self
+      }
+    }
+  )+};
+}
+
+styled!(Text, Link, User, Channel, Usergroup);
+
+/// # Text
+///
+/// Text, optionally [styled](Style).
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Text<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  text: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<Style>,
+}
+
+impl<'a> Text<'a> {
+  /// Create an unstyled text element
+  pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+    Self { text: text.into(),
+           style: None }
+  }
+
+  /// The text
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+}
+
+/// # Link
+///
+/// A link to a URL, optionally with text to display instead of the URL.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Link<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1, max = 3000)))]
+  url: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  text: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<Style>,
+}
+
+impl<'a> Link<'a> {
+  /// Create a link to `url`, displaying the URL
+  pub fn new(url: impl Into<Cow<'a, str>>) -> Self {
+    Self { url: url.into(),
+           text: None,
+           style: None }
+  }
+
+  /// Display `text` instead of the URL
+  pub fn text(mut self, text: impl Into<Cow<'a, str>>) -> Self {
+    self.text = Some(text.into());
+    self
+  }
+
+  /// The URL linked to
+  pub fn url(&self) -> &str {
+    &self.url
+  }
+
+  /// The text displayed instead of the URL, if set
+  pub fn display_text(&self) -> Option<&str> {
+    self.text.as_deref()
+  }
+}
+
+/// # Emoji
+///
+/// An emoji, by name (without colons) e.g. `"wave"`.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Emoji<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  name: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  unicode: Option<Cow<'a, str>>,
+}
+
+impl<'a> Emoji<'a> {
+  /// Create an emoji element from the emoji's name
+  pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+    Self { name: name.into(),
+           unicode: None }
+  }
+
+  /// Set the emoji's unicode code point(s) in hex, e.g. `"1f44b"`
+  pub fn unicode(mut self, unicode: impl Into<Cow<'a, str>>) -> Self {
+    self.unicode = Some(unicode.into());
+    self
+  }
+
+  /// The emoji's name
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// The emoji's unicode code point(s), if set
+  pub fn unicode_hex(&self) -> Option<&str> {
+    self.unicode.as_deref()
+  }
+}
+
+/// # User Mention
+///
+/// A mention of a user, by id.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct User<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  user_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<Style>,
+}
+
+impl<'a> User<'a> {
+  /// Mention the user with this id
+  pub fn new(user_id: impl Into<Cow<'a, str>>) -> Self {
+    Self { user_id: user_id.into(),
+           style: None }
+  }
+
+  /// The id of the mentioned user
+  pub fn user_id(&self) -> &str {
+    &self.user_id
+  }
+}
+
+/// # Channel Mention
+///
+/// A mention of a channel, by id.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Channel<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  channel_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<Style>,
+}
+
+impl<'a> Channel<'a> {
+  /// Mention the channel with this id
+  pub fn new(channel_id: impl Into<Cow<'a, str>>) -> Self {
+    Self { channel_id: channel_id.into(),
+           style: None }
+  }
+
+  /// The id of the mentioned channel
+  pub fn channel_id(&self) -> &str {
+    &self.channel_id
+  }
+}
+
+/// # User Group Mention
+///
+/// A mention of a user group, by id.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Usergroup<'a> {
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  usergroup_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  style: Option<Style>,
+}
+
+impl<'a> Usergroup<'a> {
+  /// Mention the user group with this id
+  pub fn new(usergroup_id: impl Into<Cow<'a, str>>) -> Self {
+    Self { usergroup_id: usergroup_id.into(),
+           style: None }
+  }
+
+  /// The id of the mentioned user group
+  pub fn usergroup_id(&self) -> &str {
+    &self.usergroup_id
+  }
+}
+
+/// # Broadcast Mention
+///
+/// A mention of everyone in a channel or workspace.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Broadcast {
+  range: BroadcastRange,
+}
+
+impl Broadcast {
+  /// Mention everyone in `range`
+  pub fn new(range: BroadcastRange) -> Self {
+    Self { range }
+  }
+
+  /// Who is mentioned
+  pub fn range(&self) -> BroadcastRange {
+    self.range
+  }
+}
+
+/// Who a [`Broadcast`] mentions
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BroadcastRange {
+  /// `@here`; active members of the channel
+  Here,
+
+  /// `@channel`; all members of the channel
+  Channel,
+
+  /// `@everyone`; all members of the workspace
+  Everyone,
+}
+
+/// # Date
+///
+/// [slack api docs 🔗]
+///
+/// A unix timestamp, displayed in the reader's timezone using a
+/// format string of date tokens, e.g. `"{date_short} at {time}"`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/surfaces/formatting#date-formatting
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Date<'a> {
+  timestamp: i64,
+
+  #[cfg_attr(feature = "validation", validate(length(min = 1)))]
+  format: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  url: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  fallback: Option<Cow<'a, str>>,
+}
+
+impl<'a> Date<'a> {
+  /// Display the unix timestamp `timestamp` (in seconds) using `format`
+  pub fn new(timestamp: i64, format: impl Into<Cow<'a, str>>) -> Self {
+    Self { timestamp,
+           format: format.into(),
+           url: None,
+           fallback: None }
+  }
+
+  /// Link the date to `url`
+  pub fn url(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+    self.url = Some(url.into());
+    self
+  }
+
+  /// Set the text displayed by clients that can't format the date
+  pub fn fallback(mut self, fallback: impl Into<Cow<'a, str>>) -> Self {
+    self.fallback = Some(fallback.into());
+    self
+  }
+
+  /// The unix timestamp, in seconds
+  pub fn timestamp(&self) -> i64 {
+    self.timestamp
+  }
+
+  /// The format string
+  pub fn format(&self) -> &str {
+    &self.format
+  }
+
+  /// The URL the date links to, if set
+  pub fn link(&self) -> Option<&str> {
+    self.url.as_deref()
+  }
+
+  /// The fallback text, if set
+  pub fn fallback_text(&self) -> Option<&str> {
+    self.fallback.as_deref()
+  }
+}
+
+/// # Color
+///
+/// A hex color e.g. `"#F405B3"`, displayed with a swatch of the color.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Color<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::hex_color"))]
+  value: Cow<'a, str>,
+}
+
+impl<'a> Color<'a> {
+  /// Create a color element from a hex color, e.g. `"#F405B3"`
+  pub fn new(value: impl Into<Cow<'a, str>>) -> Self {
+    Self { value: value.into() }
+  }
+
+  /// The hex color
+  pub fn value(&self) -> &str {
+    &self.value
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use crate::val_helpr::{error, ValidatorResult};
+
+  pub(super) fn hex_color(value: &str) -> ValidatorResult {
+    let hex = value.strip_prefix('#').unwrap_or_default();
+
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+      Ok(())
+    } else {
+      Err(error("Color.value",
+                format!("must be a hex color like `#F405B3`, got `{}`", value)))
+    }
+  }
+}
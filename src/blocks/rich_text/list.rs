@@ -0,0 +1,306 @@
+//! # Rich Text List
+//!
+//! [slack api docs 🔗]
+//!
+//! A bulleted or numbered list, each item of which is a [`Section`].
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_list
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Section;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Rich Text List
+///
+/// [slack api docs 🔗]
+///
+/// A bulleted or numbered list, each item of which is a [`Section`].
+///
+/// Nested lists are represented as consecutive lists with increasing `indent`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_list
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct List<'a> {
+  style: ListStyle,
+
+  #[serde(with = "items")]
+  #[cfg_attr(feature = "validation", validate)]
+  elements: Vec<Section<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  indent: Option<u8>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  offset: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(range(max = 1)))]
+  border: Option<u8>,
+}
+
+impl<'a> List<'a> {
+  /// Build a new Rich Text List.
+  ///
+  /// For example, see docs for [`build::ListBuilder`].
+  pub fn builder() -> build::ListBuilderInit<'a> {
+    build::ListBuilderInit::new()
+  }
+
+  /// Whether this list is bulleted or numbered
+  pub fn style(&self) -> ListStyle {
+    self.style
+  }
+
+  /// The items in this list
+  pub fn items(&self) -> &[Section<'a>] {
+    &self.elements
+  }
+
+  /// How many levels this list is indented, if set
+  pub fn indent(&self) -> Option<u8> {
+    self.indent
+  }
+
+  /// The number of items to skip when numbering this list, if set
+  pub fn offset(&self) -> Option<u32> {
+    self.offset
+  }
+
+  /// The width of the list's border, if set
+  pub fn border(&self) -> Option<u8> {
+    self.border
+  }
+
+  /// Validate that this list agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `border` is not 0 or 1
+  /// - If any of the items are invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::{list::ListStyle, List, Section};
+  ///
+  /// let list = List::builder().style(ListStyle::Ordered)
+  ///                           .item(Section::builder().element("First").build())
+  ///                           .border(3)
+  ///                           .build();
+  ///
+  /// assert!(matches!(list.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// Whether a [`List`] is bulleted or numbered
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListStyle {
+  /// A bulleted list
+  Bullet,
+
+  /// A numbered list
+  Ordered,
+}
+
+/// (De)serialize list items, which are tagged as `rich_text_section`s
+mod items {
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+  use super::Section;
+
+  #[derive(Serialize)]
+  #[serde(tag = "type", rename_all = "snake_case")]
+  enum ItemRef<'s, 'a> {
+    RichTextSection(&'s Section<'a>),
+  }
+
+  #[derive(Deserialize)]
+  #[serde(tag = "type", rename_all = "snake_case")]
+  enum Item<'a> {
+    RichTextSection(Section<'a>),
+  }
+
+  pub(super) fn serialize<S: Serializer>(items: &[Section<'_>],
+                                         serializer: S)
+                                         -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(items.iter().map(ItemRef::RichTextSection))
+  }
+
+  pub(super) fn deserialize<'de, 'a, D: Deserializer<'de>>(
+    deserializer: D)
+    -> Result<Vec<Section<'a>>, D::Error> {
+    Vec::<Item>::deserialize(deserializer).map(|items| {
+                                            items.into_iter()
+           .map(|Item::RichTextSection(section)| section)
+           .collect()
+                                          })
+  }
+}
+
+/// Rich Text List builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// ListBuilder.style
+    #[derive(Clone, Copy, Debug)]
+    pub struct style;
+
+    /// ListBuilder.items
+    #[derive(Clone, Copy, Debug)]
+    pub struct items;
+  }
+
+  /// Initial state for `ListBuilder`
+  pub type ListBuilderInit<'a> =
+    ListBuilder<'a,
+                RequiredMethodNotCalled<method::style>,
+                RequiredMethodNotCalled<method::items>>;
+
+  /// Build a Rich Text List
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `ListBuilder::build()` is only available if these methods have been called:
+  ///  - `style`
+  ///  - `item` or `items`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::{list::ListStyle, List, Section};
+  ///
+  /// let list =
+  ///   List::builder().style(ListStyle::Ordered)
+  ///                  .item(Section::builder().element("Preheat the oven").build())
+  ///                  .item(Section::builder().element("Bake").build())
+  ///                  .indent(1)
+  ///                  .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct ListBuilder<'a, Style, Items> {
+    style: Option<ListStyle>,
+    elements: Vec<Section<'a>>,
+    indent: Option<u8>,
+    offset: Option<u32>,
+    border: Option<u8>,
+    state: PhantomData<(Style, Items)>,
+  }
+
+  impl<'a, S, I> ListBuilder<'a, S, I> {
+    /// Create a new ListBuilder
+    pub fn new() -> Self {
+      Self { style: None,
+             elements: vec![],
+             indent: None,
+             offset: None,
+             border: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<S2, I2>(self) -> ListBuilder<'a, S2, I2> {
+      ListBuilder { style: self.style,
+                    elements: self.elements,
+                    indent: self.indent,
+                    offset: self.offset,
+                    border: self.border,
+                    state: PhantomData::<_> }
+    }
+
+    /// Set `style` (**Required**)
+    ///
+    /// Whether the list is bulleted or numbered.
+    pub fn style(mut self,
+                 style: ListStyle)
+                 -> ListBuilder<'a, Set<method::style>, I> {
+      self.style = Some(style);
+      self.cast_state()
+    }
+
+    /// Add an item (**Required**, can be called many times)
+    pub fn item(mut self,
+                item: Section<'a>)
+                -> ListBuilder<'a, S, Set<method::items>> {
+      self.elements.push(item);
+      self.cast_state()
+    }
+
+    /// Add many items (**Required**, or call `item`)
+    pub fn items(mut self,
+                 items: impl IntoIterator<Item = Section<'a>>)
+                 -> ListBuilder<'a, S, Set<method::items>> {
+      self.elements.extend(items);
+      self.cast_state()
+    }
+
+    /// Set `indent` (Optional)
+    ///
+    /// How many levels to indent the list.
+    pub fn indent(mut self, indent: u8) -> Self {
+      self.indent = Some(indent);
+      self
+    }
+
+    /// Set `offset` (Optional)
+    ///
+    /// The number of items to skip when numbering the list,
+    /// e.g. to continue a numbered list interrupted by a nested one.
+    pub fn offset(mut self, offset: u32) -> Self {
+      self.offset = Some(offset);
+      self
+    }
+
+    /// Set `border` (Optional)
+    ///
+    /// The width of the list's border; 0 or 1.
+    pub fn border(mut self, border: u8) -> Self {
+      self.border = Some(border);
+      self
+    }
+  }
+
+  impl<'a> ListBuilder<'a, Set<method::style>, Set<method::items>> {
+    /// All done building, now give me a darn rich text list!
+    ///
+    /// > `no method name 'build' found for struct 'ListBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `ListBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::blocks::rich_text::{List, Section};
+    ///
+    /// let foo = List::builder().item(Section::builder().element("Bake").build())
+    ///                          .build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::blocks::rich_text::{list::ListStyle, List, Section};
+    ///
+    /// let list = List::builder().style(ListStyle::Bullet)
+    ///                           .item(Section::builder().element("Bake").build())
+    ///                           .build();
+    /// ```
+    pub fn build(self) -> List<'a> {
+      List { style: self.style.unwrap(),
+             elements: self.elements,
+             indent: self.indent,
+             offset: self.offset,
+             border: self.border }
+    }
+  }
+}
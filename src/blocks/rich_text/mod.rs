@@ -0,0 +1,280 @@
+//! # Rich Text Block
+//!
+//! [slack api docs 🔗]
+//!
+//! Displays formatted, structured representation of text.
+//!
+//! This is the block that messages written in a Slack client's composer arrive as,
+//! made up of [sections](Section), [lists](List), [quotes](Quote)
+//! and [preformatted text](Preformatted), each of which contains
+//! [inline elements](Inline) such as styled text, links, emoji and mentions.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::convert;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+pub mod inline;
+#[doc(inline)]
+pub use inline::Inline;
+
+pub mod list;
+#[doc(inline)]
+pub use list::List;
+
+pub mod preformatted;
+#[doc(inline)]
+pub use preformatted::Preformatted;
+
+pub mod quote;
+#[doc(inline)]
+pub use quote::Quote;
+
+pub mod section;
+#[doc(inline)]
+pub use section::Section;
+
+/// # Rich Text Block
+///
+/// [slack api docs 🔗]
+///
+/// Displays formatted, structured representation of text.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct RichText<'a> {
+  #[cfg_attr(feature = "validation", validate)]
+  elements: Vec<Element<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "super::validate_block_id"))]
+  block_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> RichText<'a> {
+  /// Build a new Rich Text block.
+  ///
+  /// For example, see docs for [`build::RichTextBuilder`].
+  pub fn builder() -> build::RichTextBuilderInit<'a> {
+    build::RichTextBuilderInit::new()
+  }
+
+  /// The sections, lists, quotes and preformatted text in this block
+  pub fn elements(&self) -> &[Element<'a>] {
+    &self.elements
+  }
+
+  /// Validate that this Rich Text block agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `block_id` longer than 255 chars
+  /// - If any of `elements` are invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::{rich_text::{inline::Color, Section},
+  ///                            RichText};
+  ///
+  /// let block =
+  ///   RichText::builder().element(Section::builder().element(Color::new("red"))
+  ///                                                 .build())
+  ///                      .build();
+  ///
+  /// assert!(matches!(block.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # Rich Text Element
+///
+/// One of the top-level elements of a [`RichText`] block.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Element<'a> {
+  /// A section of inline elements
+  #[serde(rename = "rich_text_section")]
+  Section(Section<'a>),
+
+  /// A bulleted or numbered list
+  #[serde(rename = "rich_text_list")]
+  List(List<'a>),
+
+  /// A quote
+  #[serde(rename = "rich_text_quote")]
+  Quote(Quote<'a>),
+
+  /// A block of preformatted text, like a code block
+  #[serde(rename = "rich_text_preformatted")]
+  Preformatted(Preformatted<'a>),
+
+  /// An element this crate doesn't model yet
+  #[serde(other)]
+  Unknown,
+}
+
+impl<'a> Element<'a> {
+  /// Validate that this element agrees with Slack's model requirements
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    match self {
+      | Element::Section(el) => el.validate(),
+      | Element::List(el) => el.validate(),
+      | Element::Quote(el) => el.validate(),
+      | Element::Preformatted(el) => el.validate(),
+      | Element::Unknown => Ok(()),
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+impl Validate for Element<'_> {
+  fn validate(&self) -> ValidationResult {
+    Element::validate(self)
+  }
+}
+
+convert!(impl<'a> From<Section<'a>> for Element<'a> => |s| Element::Section(s));
+convert!(impl<'a> From<List<'a>> for Element<'a> => |l| Element::List(l));
+convert!(impl<'a> From<Quote<'a>> for Element<'a> => |q| Element::Quote(q));
+convert!(impl<'a> From<Preformatted<'a>> for Element<'a> => |p| Element::Preformatted(p));
+
+/// Rich Text block builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// RichTextBuilder.elements
+    #[derive(Clone, Copy, Debug)]
+    pub struct elements;
+  }
+
+  /// Initial state for `RichTextBuilder`
+  pub type RichTextBuilderInit<'a> =
+    RichTextBuilder<'a, RequiredMethodNotCalled<method::elements>>;
+
+  /// Build a Rich Text block
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `RichTextBuilder::build()` is only available if these methods have been called:
+  ///  - `element` or `elements`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::{rich_text::{inline::{Text, User},
+  ///                                        list::ListStyle,
+  ///                                        List,
+  ///                                        Section},
+  ///                            RichText};
+  ///
+  /// let block =
+  ///   RichText::builder().element(Section::builder().element(User::new("U0123ABC"))
+  ///                                                 .element(Text::new(" please review:"))
+  ///                                                 .build())
+  ///                      .element(List::builder().style(ListStyle::Bullet)
+  ///                                              .item(Section::builder().element(Text::new("the PR").bold())
+  ///                                                                      .build())
+  ///                                              .build())
+  ///                      .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct RichTextBuilder<'a, Elements> {
+    elements: Vec<Element<'a>>,
+    block_id: Option<Cow<'a, str>>,
+    state: PhantomData<Elements>,
+  }
+
+  impl<'a, E> RichTextBuilder<'a, E> {
+    /// Create a new RichTextBuilder
+    pub fn new() -> Self {
+      Self { elements: vec![],
+             block_id: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Add an element (**Required**, can be called many times)
+    ///
+    /// A [`Section`], [`List`], [`Quote`] or [`Preformatted`].
+    pub fn element(mut self,
+                   element: impl Into<Element<'a>>)
+                   -> RichTextBuilder<'a, Set<method::elements>> {
+      self.elements.push(element.into());
+
+      RichTextBuilder { elements: self.elements,
+                        block_id: self.block_id,
+                        state: PhantomData::<_> }
+    }
+
+    /// Add many elements (**Required**, or call `element`)
+    pub fn elements<I>(mut self,
+                       elements: I)
+                       -> RichTextBuilder<'a, Set<method::elements>>
+      where I: IntoIterator,
+            I::Item: Into<Element<'a>>
+    {
+      self.elements.extend(elements.into_iter().map(Into::into));
+
+      RichTextBuilder { elements: self.elements,
+                        block_id: self.block_id,
+                        state: PhantomData::<_> }
+    }
+
+    /// Set `block_id` (Optional)
+    ///
+    /// A string acting as a unique identifier for a block.
+    ///
+    /// If not specified, a `block_id` will be generated.
+    ///
+    /// Maximum length for this field is 255 characters.
+    pub fn block_id(mut self, block_id: impl Into<Cow<'a, str>>) -> Self {
+      self.block_id = Some(block_id.into());
+      self
+    }
+  }
+
+  impl<'a> RichTextBuilder<'a, Set<method::elements>> {
+    /// All done building, now give me a darn rich text block!
+    ///
+    /// > `no method name 'build' found for struct 'RichTextBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `RichTextBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::blocks::RichText;
+    ///
+    /// let foo = RichText::builder().build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::blocks::{rich_text::Section, RichText};
+    ///
+    /// let block = RichText::builder().element(Section::builder().element("Hi!")
+    ///                                                           .build())
+    ///                                .build();
+    /// ```
+    pub fn build(self) -> RichText<'a> {
+      RichText { elements: self.elements,
+                 block_id: self.block_id }
+    }
+  }
+}
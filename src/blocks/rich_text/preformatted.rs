@@ -0,0 +1,182 @@
+//! # Rich Text Preformatted
+//!
+//! [slack api docs 🔗]
+//!
+//! [Inline elements](super::Inline) displayed as preformatted text, like a code block.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_preformatted
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Inline;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Rich Text Preformatted
+///
+/// [slack api docs 🔗]
+///
+/// [Inline elements](super::Inline) displayed as preformatted text, like a code block.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_preformatted
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Preformatted<'a> {
+  #[cfg_attr(feature = "validation", validate)]
+  elements: Vec<Inline<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(range(max = 1)))]
+  border: Option<u8>,
+}
+
+impl<'a> Preformatted<'a> {
+  /// Build a new Rich Text Preformatted.
+  ///
+  /// For example, see docs for [`build::PreformattedBuilder`].
+  pub fn builder() -> build::PreformattedBuilderInit<'a> {
+    build::PreformattedBuilderInit::new()
+  }
+
+  /// The inline elements in this preformatted block
+  pub fn elements(&self) -> &[Inline<'a>] {
+    &self.elements
+  }
+
+  /// The width of the border, if set
+  pub fn border(&self) -> Option<u8> {
+    self.border
+  }
+
+  /// Validate that this preformatted block agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `border` is not 0 or 1
+  /// - If any of `elements` are invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::Preformatted;
+  ///
+  /// let code = Preformatted::builder().element("fn main() {}")
+  ///                                   .border(2)
+  ///                                   .build();
+  ///
+  /// assert!(matches!(code.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// Rich Text Preformatted builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// PreformattedBuilder.elements
+    #[derive(Clone, Copy, Debug)]
+    pub struct elements;
+  }
+
+  /// Initial state for `PreformattedBuilder`
+  pub type PreformattedBuilderInit<'a> =
+    PreformattedBuilder<'a, RequiredMethodNotCalled<method::elements>>;
+
+  /// Build a Rich Text Preformatted
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `PreformattedBuilder::build()` is only available if these methods have been called:
+  ///  - `element` or `elements`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::Preformatted;
+  ///
+  /// let code = Preformatted::builder().element("cargo build --release")
+  ///                                   .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct PreformattedBuilder<'a, Elements> {
+    elements: Vec<Inline<'a>>,
+    border: Option<u8>,
+    state: PhantomData<Elements>,
+  }
+
+  impl<'a, E> PreformattedBuilder<'a, E> {
+    /// Create a new PreformattedBuilder
+    pub fn new() -> Self {
+      Self { elements: vec![],
+             border: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Add an inline element (**Required**, can be called many times)
+    pub fn element(mut self,
+                   element: impl Into<Inline<'a>>)
+                   -> PreformattedBuilder<'a, Set<method::elements>> {
+      self.elements.push(element.into());
+
+      PreformattedBuilder { elements: self.elements,
+                            border: self.border,
+                            state: PhantomData::<_> }
+    }
+
+    /// Add many inline elements (**Required**, or call `element`)
+    pub fn elements<I>(mut self,
+                       elements: I)
+                       -> PreformattedBuilder<'a, Set<method::elements>>
+      where I: IntoIterator,
+            I::Item: Into<Inline<'a>>
+    {
+      self.elements.extend(elements.into_iter().map(Into::into));
+
+      PreformattedBuilder { elements: self.elements,
+                            border: self.border,
+                            state: PhantomData::<_> }
+    }
+
+    /// Set `border` (Optional)
+    ///
+    /// The width of the border; 0 or 1.
+    pub fn border(mut self, border: u8) -> Self {
+      self.border = Some(border);
+      self
+    }
+  }
+
+  impl<'a> PreformattedBuilder<'a, Set<method::elements>> {
+    /// All done building, now give me a darn rich text preformatted block!
+    ///
+    /// > `no method name 'build' found for struct 'PreformattedBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `PreformattedBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::blocks::rich_text::Preformatted;
+    ///
+    /// let foo = Preformatted::builder().border(1).build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::blocks::rich_text::Preformatted;
+    ///
+    /// let code = Preformatted::builder().element("Hello!").build();
+    /// ```
+    pub fn build(self) -> Preformatted<'a> {
+      Preformatted { elements: self.elements,
+                     border: self.border }
+    }
+  }
+}
@@ -0,0 +1,182 @@
+//! # Rich Text Quote
+//!
+//! [slack api docs 🔗]
+//!
+//! [Inline elements](super::Inline) displayed as a block quote.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_quote
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Inline;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Rich Text Quote
+///
+/// [slack api docs 🔗]
+///
+/// [Inline elements](super::Inline) displayed as a block quote.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_quote
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Quote<'a> {
+  #[cfg_attr(feature = "validation", validate)]
+  elements: Vec<Inline<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(range(max = 1)))]
+  border: Option<u8>,
+}
+
+impl<'a> Quote<'a> {
+  /// Build a new Rich Text Quote.
+  ///
+  /// For example, see docs for [`build::QuoteBuilder`].
+  pub fn builder() -> build::QuoteBuilderInit<'a> {
+    build::QuoteBuilderInit::new()
+  }
+
+  /// The inline elements in this quote
+  pub fn elements(&self) -> &[Inline<'a>] {
+    &self.elements
+  }
+
+  /// The width of the quote's border, if set
+  pub fn border(&self) -> Option<u8> {
+    self.border
+  }
+
+  /// Validate that this quote agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `border` is not 0 or 1
+  /// - If any of `elements` are invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::Quote;
+  ///
+  /// let quote = Quote::builder().element("To be or not to be")
+  ///                             .border(2)
+  ///                             .build();
+  ///
+  /// assert!(matches!(quote.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// Rich Text Quote builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// QuoteBuilder.elements
+    #[derive(Clone, Copy, Debug)]
+    pub struct elements;
+  }
+
+  /// Initial state for `QuoteBuilder`
+  pub type QuoteBuilderInit<'a> =
+    QuoteBuilder<'a, RequiredMethodNotCalled<method::elements>>;
+
+  /// Build a Rich Text Quote
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `QuoteBuilder::build()` is only available if these methods have been called:
+  ///  - `element` or `elements`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::{inline::Text, Quote};
+  ///
+  /// let quote = Quote::builder().element(Text::new("Ship it").bold())
+  ///                             .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct QuoteBuilder<'a, Elements> {
+    elements: Vec<Inline<'a>>,
+    border: Option<u8>,
+    state: PhantomData<Elements>,
+  }
+
+  impl<'a, E> QuoteBuilder<'a, E> {
+    /// Create a new QuoteBuilder
+    pub fn new() -> Self {
+      Self { elements: vec![],
+             border: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Add an inline element (**Required**, can be called many times)
+    pub fn element(mut self,
+                   element: impl Into<Inline<'a>>)
+                   -> QuoteBuilder<'a, Set<method::elements>> {
+      self.elements.push(element.into());
+
+      QuoteBuilder { elements: self.elements,
+                     border: self.border,
+                     state: PhantomData::<_> }
+    }
+
+    /// Add many inline elements (**Required**, or call `element`)
+    pub fn elements<I>(mut self,
+                       elements: I)
+                       -> QuoteBuilder<'a, Set<method::elements>>
+      where I: IntoIterator,
+            I::Item: Into<Inline<'a>>
+    {
+      self.elements.extend(elements.into_iter().map(Into::into));
+
+      QuoteBuilder { elements: self.elements,
+                     border: self.border,
+                     state: PhantomData::<_> }
+    }
+
+    /// Set `border` (Optional)
+    ///
+    /// The width of the quote's border; 0 or 1.
+    pub fn border(mut self, border: u8) -> Self {
+      self.border = Some(border);
+      self
+    }
+  }
+
+  impl<'a> QuoteBuilder<'a, Set<method::elements>> {
+    /// All done building, now give me a darn rich text quote!
+    ///
+    /// > `no method name 'build' found for struct 'QuoteBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `QuoteBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::blocks::rich_text::Quote;
+    ///
+    /// let foo = Quote::builder().border(1).build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::blocks::rich_text::Quote;
+    ///
+    /// let quote = Quote::builder().element("Hello!").build();
+    /// ```
+    pub fn build(self) -> Quote<'a> {
+      Quote { elements: self.elements,
+              border: self.border }
+    }
+  }
+}
@@ -0,0 +1,161 @@
+//! # Rich Text Section
+//!
+//! [slack api docs 🔗]
+//!
+//! A run of [inline elements](super::Inline), like a paragraph.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_section
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Inline;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Rich Text Section
+///
+/// [slack api docs 🔗]
+///
+/// A run of [inline elements](super::Inline), like a paragraph.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#rich_text_section
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Section<'a> {
+  #[cfg_attr(feature = "validation", validate)]
+  elements: Vec<Inline<'a>>,
+}
+
+impl<'a> Section<'a> {
+  /// Build a new Rich Text Section.
+  ///
+  /// For example, see docs for [`build::SectionBuilder`].
+  pub fn builder() -> build::SectionBuilderInit<'a> {
+    build::SectionBuilderInit::new()
+  }
+
+  /// The inline elements in this section
+  pub fn elements(&self) -> &[Inline<'a>] {
+    &self.elements
+  }
+
+  /// Validate that this section agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If any of `elements` are invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::{inline::Emoji, Section};
+  ///
+  /// let section = Section::builder().element(Emoji::new("")).build();
+  ///
+  /// assert!(matches!(section.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// Rich Text Section builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// SectionBuilder.elements
+    #[derive(Clone, Copy, Debug)]
+    pub struct elements;
+  }
+
+  /// Initial state for `SectionBuilder`
+  pub type SectionBuilderInit<'a> =
+    SectionBuilder<'a, RequiredMethodNotCalled<method::elements>>;
+
+  /// Build a Rich Text Section
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `SectionBuilder::build()` is only available if these methods have been called:
+  ///  - `element` or `elements`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::blocks::rich_text::{inline::{Link, Text},
+  ///                                       Section};
+  ///
+  /// let section =
+  ///   Section::builder().element(Text::new("See "))
+  ///                     .element(Link::new("https://example.com").text("the docs"))
+  ///                     .element(Text::new(" for more.").italic())
+  ///                     .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct SectionBuilder<'a, Elements> {
+    elements: Vec<Inline<'a>>,
+    state: PhantomData<Elements>,
+  }
+
+  impl<'a, E> SectionBuilder<'a, E> {
+    /// Create a new SectionBuilder
+    pub fn new() -> Self {
+      Self { elements: vec![],
+             state: PhantomData::<_> }
+    }
+
+    /// Add an inline element (**Required**, can be called many times)
+    pub fn element(mut self,
+                   element: impl Into<Inline<'a>>)
+                   -> SectionBuilder<'a, Set<method::elements>> {
+      self.elements.push(element.into());
+
+      SectionBuilder { elements: self.elements,
+                       state: PhantomData::<_> }
+    }
+
+    /// Add many inline elements (**Required**, or call `element`)
+    pub fn elements<I>(mut self,
+                       elements: I)
+                       -> SectionBuilder<'a, Set<method::elements>>
+      where I: IntoIterator,
+            I::Item: Into<Inline<'a>>
+    {
+      self.elements.extend(elements.into_iter().map(Into::into));
+
+      SectionBuilder { elements: self.elements,
+                       state: PhantomData::<_> }
+    }
+  }
+
+  impl<'a> SectionBuilder<'a, Set<method::elements>> {
+    /// All done building, now give me a darn rich text section!
+    ///
+    /// > `no method name 'build' found for struct 'SectionBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `SectionBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::blocks::rich_text::Section;
+    ///
+    /// let foo = Section::builder().build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::blocks::rich_text::Section;
+    ///
+    /// let section = Section::builder().element("Hello!").build();
+    /// ```
+    pub fn build(self) -> Section<'a> {
+      Section { elements: self.elements }
+    }
+  }
+}
@@ -60,13 +60,13 @@ pub struct Section<'a> {
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(custom = "validate::block_id"))]
-  block_id: Option<Cow<'a, str>>,
+  pub(crate) block_id: Option<Cow<'a, str>>,
 
   /// One of the available [element objects 🔗][element_objects].
   ///
   /// [element_objects]: https://api.slack.com/reference/messaging/block-elements
   #[serde(skip_serializing_if = "Option::is_none")]
-  accessory: Option<BlockElement<'a>>,
+  pub(crate) accessory: Option<BlockElement<'a>>,
 }
 
 impl<'a> Section<'a> {
@@ -0,0 +1,124 @@
+//! # Typed Action Ids
+//!
+//! Block elements are identified by string `action_id`s (and blocks by `block_id`s),
+//! which your app has to spell the same way when building a view
+//! and when handling an interaction with it.
+//!
+//! The [`ActionId`] trait describes a type that can be used as one of these ids.
+//! With the `derive` feature, it can be derived for enums:
+//!
+//! - unit variants are encoded as their name, in `snake_case`
+//! - variants with fields are encoded as their name followed by each field
+//!   (using `Display` and `FromStr`), separated by `:`, e.g. `approve:42`
+//!
+//! Variants accept `#[slack(id = "..")]` to use a different name.
+//!
+//! Deriving `ActionId` also lets values of the enum be passed
+//! directly to any `action_id` or `block_id` builder method.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use slack_blocks::{compose::action_id::ActionId,
+//!                    elems::Button,
+//!                    interaction::BlockActions};
+//!
+//! #[derive(ActionId, Debug, PartialEq)]
+//! enum Review {
+//!   Approve { ticket: u32 },
+//!   #[slack(id = "reject")]
+//!   Deny,
+//! }
+//!
+//! let button = Button::builder().text("Approve")
+//!                               .action_id(Review::Approve { ticket: 42 })
+//!                               .build();
+//!
+//! assert_eq!(Review::Approve { ticket: 42 }.to_action_id(), "approve:42");
+//! assert_eq!(Review::from_action_id("reject"), Ok(Review::Deny));
+//!
+//! let payload: BlockActions = serde_json::from_value(serde_json::json!({
+//!   "team": { "id": "T9TK3CUKW" },
+//!   "user": { "id": "UA8RXUSPL" },
+//!   "actions": [{ "type": "button", "action_id": "approve:42", "block_id": "review" }]
+//! })).unwrap();
+//!
+//! assert_eq!(payload.actions()[0].parse_action_id(),
+//!            Ok(Review::Approve { ticket: 42 }));
+//! # }
+//! ```
+//!
+//! Values of non-final fields must not contain `:`.
+
+use std::fmt;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use slack_blocks_derive::ActionId;
+
+/// Maximum length of an `action_id` or `block_id`
+pub const ACTION_ID_MAX_LEN: usize = 255;
+
+/// A type that can be used as an `action_id` or `block_id`
+///
+/// See the [module docs](self) for an example.
+pub trait ActionId: Sized {
+  /// Encode `self` as an id
+  fn to_action_id(&self) -> String;
+
+  /// Parse an id produced by [`ActionId::to_action_id`]
+  ///
+  /// # Errors
+  /// [`ActionIdError::Unrecognized`] if `id` is not the encoding of any `Self`
+  fn from_action_id(id: &str) -> Result<Self, ActionIdError>;
+
+  /// Encode `self` as an id, failing if it is longer
+  /// than Slack allows (255 characters).
+  ///
+  /// # Errors
+  /// [`ActionIdError::TooLong`] if the encoded id is too long
+  fn checked_action_id(&self) -> Result<String, ActionIdError> {
+    let id = self.to_action_id();
+    let len = id.chars().count();
+
+    if len > ACTION_ID_MAX_LEN {
+      Err(ActionIdError::TooLong { len })
+    } else {
+      Ok(id)
+    }
+  }
+}
+
+/// Error yielded when encoding or parsing a typed id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ActionIdError {
+  /// The encoded id is longer than 255 characters
+  TooLong {
+    /// The length of the encoded id
+    len: usize,
+  },
+
+  /// The id does not identify any value of the type
+  Unrecognized {
+    /// The id that could not be parsed
+    id: String,
+  },
+}
+
+impl fmt::Display for ActionIdError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | ActionIdError::TooLong { len } => {
+        write!(f, "id is {} chars, max is {}", len, ACTION_ID_MAX_LEN)
+      },
+      | ActionIdError::Unrecognized { id } => {
+        write!(f, "unrecognized id `{}`", id)
+      },
+    }
+  }
+}
+
+impl std::error::Error for ActionIdError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
@@ -11,11 +11,16 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod action_id;
 pub mod confirm;
 pub mod conversation_filter;
 pub mod opt;
 pub mod opt_group;
+#[cfg(feature = "signing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+pub mod signing;
 pub mod text;
+pub mod value;
 
 #[doc(inline)]
 pub use confirm::{Confirm, ConfirmStyle};
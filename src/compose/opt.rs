@@ -17,11 +17,14 @@
 
 use std::{borrow::Cow, marker::PhantomData};
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 #[cfg(feature = "validation")]
 use validator::Validate;
 
-use super::text;
+#[cfg(feature = "signing")]
+use super::signing::{self, SigningKey};
+use super::{text,
+            value::{self, ValueError}};
 #[cfg(feature = "validation")]
 use crate::val_helpr::ValidationResult;
 use crate::{build::*, convert};
@@ -147,6 +150,69 @@ impl<'a, U> Opt<'a, text::Plain, U> {
 
 // Methods available to all specializations
 impl<'a, T, U> Opt<'a, T, U> {
+  /// Clone into an owned opt with different type flags,
+  /// e.g. to use an option received from Slack in an element.
+  pub(crate) fn cast<'b, T2, U2>(&self) -> Opt<'b, T2, U2> {
+    Opt { text: self.text.clone(),
+          value: Cow::Owned(self.value.to_string()),
+          description: self.description.clone(),
+          url: self.url.as_ref().map(|url| Cow::Owned(url.to_string())),
+          marker: PhantomData::<(T2, U2)> }
+  }
+
+  /// The text shown for this option
+  pub fn text(&self) -> &text::Text {
+    &self.text
+  }
+
+  /// The value sent to your app when this option is chosen
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::compose::Opt;
+  ///
+  /// let opt = Opt::builder().text_plain("Seattle").value("SEA").build();
+  ///
+  /// assert_eq!(opt.value(), "SEA");
+  /// ```
+  pub fn value(&self) -> &str {
+    &self.value
+  }
+
+  /// Decode a `value` set with [`OptBuilder::typed_value`](build::OptBuilder::typed_value)
+  ///
+  /// # Errors
+  /// If the value is not the JSON encoding of a `V`.
+  pub fn typed_value<V: DeserializeOwned>(&self) -> Result<V, ValueError> {
+    value::decode(&self.value)
+  }
+
+  /// Verify and decode a `value` set with
+  /// [`OptBuilder::signed_value`](build::OptBuilder::signed_value)
+  /// for the action `action_id`
+  ///
+  /// # Errors
+  /// - [`ValueError::BadSignature`] if the value was not sealed by `key` for `action_id`
+  /// - [`ValueError::Deserialize`] if the value is not the JSON encoding of a `V`
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn verified_value<V: DeserializeOwned>(&self,
+                                             key: &SigningKey,
+                                             action_id: &str)
+                                             -> Result<V, ValueError> {
+    key.open_typed(&signing::value_context(action_id), &self.value)
+  }
+
+  /// The description shown below this option's text, if any
+  pub fn description(&self) -> Option<&text::Text> {
+    self.description.as_ref()
+  }
+
+  /// The URL loaded in the user's browser when this option is clicked, if any
+  pub fn url(&self) -> Option<&str> {
+    self.url.as_deref()
+  }
+
   /// Validate that this Option composition object
   /// agrees with Slack's model requirements
   ///
@@ -283,6 +349,45 @@ pub mod build {
       self.cast_state()
     }
 
+    /// Set `value` to the compact JSON encoding of `value` (**Required**)
+    ///
+    /// Decode it with [`Opt::typed_value`].
+    ///
+    /// # Errors
+    /// If `value` can't be serialized, or is longer than 75 characters once encoded.
+    pub fn typed_value<Val>(
+      self,
+      value: &Val)
+      -> Result<OptBuilder<'a, T, Set<method::value>, U>, ValueError>
+      where Val: Serialize + ?Sized
+    {
+      value::encode(value, value::OPT_VALUE_MAX_LEN).map(|v| self.value(v))
+    }
+
+    /// Set `value` to the compact JSON encoding of `value`,
+    /// sealed with `key` for the action `action_id`
+    /// of the element this option is in (**Required**)
+    ///
+    /// Verify and decode it with [`Opt::verified_value`].
+    ///
+    /// # Errors
+    /// If `value` can't be serialized, or is longer than 75 characters once sealed.
+    #[cfg(feature = "signing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+    pub fn signed_value<Val>(
+      self,
+      key: &SigningKey,
+      action_id: &str,
+      value: &Val)
+      -> Result<OptBuilder<'a, T, Set<method::value>, U>, ValueError>
+      where Val: Serialize + ?Sized
+    {
+      key.seal_typed(&signing::value_context(action_id),
+                     value,
+                     value::OPT_VALUE_MAX_LEN)
+         .map(|v| self.value(v))
+    }
+
     /// Set `description` (Optional)
     ///
     /// A [`plain_text` only text object 🔗] that defines
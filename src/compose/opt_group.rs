@@ -34,7 +34,7 @@ pub struct OptGroup<'a, T = AnyText, U = NoUrl> {
   label: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 100)))]
-  options: Vec<Opt<'a, T, U>>,
+  pub(crate) options: Vec<Opt<'a, T, U>>,
 }
 
 impl<'a> OptGroup<'a> {
@@ -0,0 +1,239 @@
+//! # Signed Values
+//!
+//! Anything your app puts in a `value`, `action_id` or `private_metadata`
+//! is sent to the user's Slack client, and comes back in interaction payloads
+//! exactly as the client reports it - which means a sufficiently motivated
+//! workspace member can forge it.
+//!
+//! A [`SigningKey`] seals these strings with an HMAC-SHA256 tag
+//! derived from a secret only your app knows, and refuses to open
+//! any value whose tag is missing or does not match.
+//!
+//! Every tag is bound to a context naming what the value is for,
+//! like [`value_context`] for the `value` of a given `action_id`.
+//! A value sealed for one context will not open in any other,
+//! so it can't be replayed into a different action, field or view.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use slack_blocks::{compose::signing::{value_context, SigningKey},
+//!                    elems::Button};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Refund {
+//!   order: u32,
+//! }
+//!
+//! let key = SigningKey::new("an app secret that is not in source control");
+//!
+//! let refund = Refund { order: 7 };
+//! let button = Button::builder().text("Refund")
+//!                               .action_id("refund")
+//!                               .signed_value(&key, "refund", &refund)
+//!                               .unwrap()
+//!                               .build();
+//!
+//! // A value sealed by `key` opens back up to the original...
+//! let ctx = value_context("refund");
+//! let sealed = key.seal(&ctx, r#"{"order":7}"#);
+//! assert_eq!(key.open(&ctx, &sealed), Ok(r#"{"order":7}"#));
+//!
+//! // ...but one that was tampered with does not,
+//! let forged = sealed.replace('7', "8");
+//! assert!(key.open(&ctx, &forged).is_err());
+//!
+//! // and neither does one sent back for a different action.
+//! assert!(key.open(&value_context("approve"), &sealed).is_err());
+//! ```
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::Sha256;
+
+use super::value::{self, ValueError};
+
+/// Number of bytes of the HMAC-SHA256 digest kept in a sealed value's tag
+const TAG_BYTES: usize = 16;
+
+/// Number of characters a [`SigningKey::seal`] adds to a value
+///
+/// This is the length of the URL-safe base64 tag, plus the `.` separating
+/// it from the value.
+pub const SEAL_LEN: usize = 23;
+
+/// # Signing Key
+///
+/// A secret used to seal values sent to Slack,
+/// and to verify them when they come back.
+///
+/// A sealed value looks like `<value>.<tag>`, where `tag` is the first
+/// 128 bits of the HMAC-SHA256 of the length of the context, the context
+/// and `value`, encoded as unpadded URL-safe base64.
+///
+/// Keep the key secret, and use a different one from your Slack app's
+/// signing secret.
+#[derive(Clone)]
+pub struct SigningKey {
+  key: Vec<u8>,
+}
+
+impl fmt::Debug for SigningKey {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SigningKey").finish_non_exhaustive()
+  }
+}
+
+impl SigningKey {
+  /// Create a signing key from some secret bytes
+  pub fn new(key: impl AsRef<[u8]>) -> Self {
+    Self { key: key.as_ref().to_vec() }
+  }
+
+  fn mac(&self, context: &str, value: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&self.key)
+      .expect("HMAC accepts keys of any length");
+    mac.update(&(context.len() as u64).to_be_bytes());
+    mac.update(context.as_bytes());
+    mac.update(value.as_bytes());
+    mac
+  }
+
+  /// Seal `value` with a tag that proves it was produced with this key
+  /// for `context`
+  ///
+  /// The sealed string is [`SEAL_LEN`] characters longer than `value`.
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::compose::signing::{action_id_context,
+  ///                                      SigningKey,
+  ///                                      SEAL_LEN};
+  ///
+  /// let key = SigningKey::new("secret");
+  /// let sealed = key.seal(&action_id_context("request"), "approve");
+  ///
+  /// assert!(sealed.starts_with("approve."));
+  /// assert_eq!(sealed.len(), "approve".len() + SEAL_LEN);
+  /// ```
+  pub fn seal(&self, context: &str, value: &str) -> String {
+    let tag = self.mac(context, value).finalize().into_bytes();
+    let tag = base64::encode_config(&tag[..TAG_BYTES], base64::URL_SAFE_NO_PAD);
+
+    format!("{}.{}", value, tag)
+  }
+
+  /// Verify a value sealed with [`SigningKey::seal`],
+  /// returning the original value without its tag.
+  ///
+  /// Tags are compared in constant time.
+  ///
+  /// # Errors
+  /// [`ValueError::BadSignature`] if `sealed` has no tag,
+  /// or if its tag was not produced by this key for this value and `context`.
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::compose::{signing::{action_id_context, SigningKey},
+  ///                             value::ValueError};
+  ///
+  /// let key = SigningKey::new("secret");
+  /// let other = SigningKey::new("not the secret");
+  /// let ctx = action_id_context("request");
+  ///
+  /// assert_eq!(key.open(&ctx, &key.seal(&ctx, "approve")), Ok("approve"));
+  /// assert_eq!(key.open(&ctx, &other.seal(&ctx, "approve")),
+  ///            Err(ValueError::BadSignature));
+  /// assert_eq!(key.open(&action_id_context("other"), &key.seal(&ctx, "ok")),
+  ///            Err(ValueError::BadSignature));
+  /// assert_eq!(key.open(&ctx, "approve"), Err(ValueError::BadSignature));
+  /// ```
+  pub fn open<'v>(&self,
+                  context: &str,
+                  sealed: &'v str)
+                  -> Result<&'v str, ValueError> {
+    let (value, tag) =
+      sealed.rsplit_once('.').ok_or(ValueError::BadSignature)?;
+
+    let tag = base64::decode_config(tag, base64::URL_SAFE_NO_PAD)
+      .map_err(|_| ValueError::BadSignature)?;
+
+    if tag.len() != TAG_BYTES {
+      return Err(ValueError::BadSignature);
+    }
+
+    self.mac(context, value)
+        .verify_truncated_left(&tag)
+        .map(|_| value)
+        .map_err(|_| ValueError::BadSignature)
+  }
+
+  /// Encode `value` as compact JSON (see [`value::encode`]) and seal it
+  /// for `context`, failing if the sealed string is longer
+  /// than `max_len` characters.
+  ///
+  /// # Errors
+  /// - [`ValueError::TooLong`] if the sealed value is too long
+  /// - [`ValueError::Serialize`] if `value` could not be serialized
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::compose::{signing::{value_context, SigningKey},
+  ///                             value::{ValueError, OPT_VALUE_MAX_LEN}};
+  ///
+  /// let key = SigningKey::new("secret");
+  /// let ctx = value_context("airport");
+  /// let sealed = key.seal_typed(&ctx, &("SEA", 1), OPT_VALUE_MAX_LEN)
+  ///                 .unwrap();
+  ///
+  /// assert_eq!(key.open_typed::<(String, u8)>(&ctx, &sealed),
+  ///            Ok(("SEA".to_string(), 1)));
+  /// assert!(matches!(key.seal_typed(&ctx, &"a".repeat(60), OPT_VALUE_MAX_LEN),
+  ///                  Err(ValueError::TooLong { .. })));
+  /// ```
+  pub fn seal_typed<T: Serialize + ?Sized>(&self,
+                                           context: &str,
+                                           value: &T,
+                                           max_len: usize)
+                                           -> Result<String, ValueError> {
+    match value::encode(value, max_len.saturating_sub(SEAL_LEN)) {
+      | Ok(encoded) => Ok(self.seal(context, &encoded)),
+      | Err(ValueError::TooLong { len, .. }) => {
+        Err(ValueError::TooLong { max: max_len,
+                                  len: len + SEAL_LEN })
+      },
+      | Err(e) => Err(e),
+    }
+  }
+
+  /// Verify a value sealed with [`SigningKey::seal_typed`] and decode it
+  ///
+  /// # Errors
+  /// - [`ValueError::BadSignature`] if the value's tag is missing or does not match
+  /// - [`ValueError::Deserialize`] if the value is not the JSON encoding of a `T`
+  pub fn open_typed<T: DeserializeOwned>(&self,
+                                         context: &str,
+                                         sealed: &str)
+                                         -> Result<T, ValueError> {
+    self.open(context, sealed).and_then(value::decode)
+  }
+}
+
+/// The context a button or option `value` is sealed for,
+/// when it is sent back with the action `action_id`
+pub fn value_context(action_id: &str) -> String {
+  format!("value\0{}\0", action_id)
+}
+
+/// The context an `action_id` is sealed for,
+/// when it is sent back from the block `block_id`
+pub fn action_id_context(block_id: &str) -> String {
+  format!("action_id\0{}\0", block_id)
+}
+
+/// The context `private_metadata` is sealed for,
+/// when it is sent back with the view `callback_id`
+pub fn private_metadata_context(callback_id: &str) -> String {
+  format!("private_metadata\0{}\0", callback_id)
+}
@@ -0,0 +1,147 @@
+//! # Typed Values
+//!
+//! The `value` of a [`Button`](crate::elems::Button) or an [`Opt`](super::Opt)
+//! is a plain string, sent back to your app when a user interacts with it.
+//!
+//! The helpers in this module encode any `Serialize` type into a compact
+//! JSON string for these fields (checking Slack's length limit up front),
+//! and decode it back into the same type from an interaction payload.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use slack_blocks::{compose::Opt, elems::Button};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Approve {
+//!   ticket: u32,
+//! }
+//!
+//! let button = Button::builder().text("Approve")
+//!                               .action_id("approve")
+//!                               .typed_value(&Approve { ticket: 42 })
+//!                               .unwrap()
+//!                               .build();
+//!
+//! let opt = Opt::builder().text_plain("Ticket #42")
+//!                         .typed_value(&Approve { ticket: 42 })
+//!                         .unwrap()
+//!                         .build();
+//!
+//! assert_eq!(opt.value(), r#"{"ticket":42}"#);
+//! assert_eq!(opt.typed_value::<Approve>(), Ok(Approve { ticket: 42 }));
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Maximum length of a [`Button`](crate::elems::Button)'s `value`
+pub const BUTTON_VALUE_MAX_LEN: usize = 2000;
+
+/// Maximum length of an [`Opt`](super::Opt)'s `value`
+pub const OPT_VALUE_MAX_LEN: usize = 75;
+
+/// Maximum length of a view's `private_metadata`
+pub const PRIVATE_METADATA_MAX_LEN: usize = 3000;
+
+/// Encode `value` as compact JSON, failing if the encoded
+/// string is longer than `max_len` characters.
+///
+/// # Errors
+/// - [`ValueError::TooLong`] if the encoded value is too long
+/// - [`ValueError::Serialize`] if `value` could not be serialized
+///
+/// # Example
+/// ```
+/// use slack_blocks::compose::value::{decode, encode, ValueError};
+///
+/// let encoded = encode(&(1, "two"), 75).unwrap();
+/// assert_eq!(encoded, r#"[1,"two"]"#);
+/// assert_eq!(decode::<(u8, String)>(&encoded), Ok((1, "two".to_string())));
+///
+/// assert_eq!(encode(&"a".repeat(10), 5),
+///            Err(ValueError::TooLong { max: 5, len: 12 }));
+/// ```
+pub fn encode<T: Serialize + ?Sized>(value: &T,
+                                     max_len: usize)
+                                     -> Result<String, ValueError> {
+  let encoded =
+    serde_json::to_string(value).map_err(|e| {
+                                  ValueError::Serialize(e.to_string())
+                                })?;
+
+  check_len(encoded, max_len)
+}
+
+/// Fail if `encoded` is longer than `max_len` characters
+pub(crate) fn check_len(encoded: String,
+                        max_len: usize)
+                        -> Result<String, ValueError> {
+  let len = encoded.chars().count();
+
+  if len > max_len {
+    Err(ValueError::TooLong { max: max_len, len })
+  } else {
+    Ok(encoded)
+  }
+}
+
+/// Decode a value encoded with [`encode`]
+///
+/// # Errors
+/// [`ValueError::Deserialize`] if `value` is not the JSON encoding of a `T`.
+pub fn decode<T: DeserializeOwned>(value: &str) -> Result<T, ValueError> {
+  serde_json::from_str(value).map_err(|e| {
+                               ValueError::Deserialize(e.to_string())
+                             })
+}
+
+/// Error yielded when encoding or decoding a typed value
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ValueError {
+  /// The encoded value is longer than the field allows
+  TooLong {
+    /// The maximum length of the field
+    max: usize,
+    /// The length of the encoded value
+    len: usize,
+  },
+
+  /// The value could not be serialized
+  Serialize(String),
+
+  /// The value could not be deserialized into the requested type
+  Deserialize(String),
+
+  /// The interaction carried no value to decode
+  Missing,
+
+  /// The value's signature was missing, or did not match its contents
+  ///
+  /// See [`signing`](super::signing).
+  BadSignature,
+}
+
+impl fmt::Display for ValueError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | ValueError::TooLong { max, len } => {
+        write!(f, "encoded value is {} chars, max is {}", len, max)
+      },
+      | ValueError::Serialize(e) => write!(f, "failed to encode value: {}", e),
+      | ValueError::Deserialize(e) => {
+        write!(f, "failed to decode value: {}", e)
+      },
+      | ValueError::Missing => write!(f, "no value to decode"),
+      | ValueError::BadSignature => {
+        write!(f, "value signature is missing or invalid")
+      },
+    }
+  }
+}
+
+impl std::error::Error for ValueError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
@@ -26,9 +26,13 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "validation")]
 use validator::Validate;
 
+#[cfg(feature = "signing")]
+use crate::compose::signing::{self, SigningKey};
 #[cfg(feature = "validation")]
 use crate::val_helpr::ValidationResult;
-use crate::{compose::Confirm, text};
+use crate::{compose::{value::{self, ValueError},
+                      Confirm},
+            text};
 
 /// # Button
 /// [slack api docs 🔗]
@@ -58,7 +62,7 @@ pub struct Button<'a> {
   text: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(custom = "validate::url"))]
@@ -238,6 +242,42 @@ pub mod build {
       self
     }
 
+    /// Set `value` to the compact JSON encoding of `value` (Optional)
+    ///
+    /// Decode it from an interaction payload with
+    /// [`Action::typed_value`](crate::interaction::block_actions::Action::typed_value).
+    ///
+    /// # Errors
+    /// If `value` can't be serialized, or is longer than 2000 characters once encoded.
+    pub fn typed_value<Val>(self, value: &Val) -> Result<Self, ValueError>
+      where Val: Serialize + ?Sized
+    {
+      value::encode(value, value::BUTTON_VALUE_MAX_LEN).map(|v| self.value(v))
+    }
+
+    /// Set `value` to the compact JSON encoding of `value`,
+    /// sealed with `key` for the action `action_id` (Optional)
+    ///
+    /// Verify and decode it from an interaction payload with
+    /// [`Action::verified_value`](crate::interaction::block_actions::Action::verified_value).
+    ///
+    /// # Errors
+    /// If `value` can't be serialized, or is longer than 2000 characters once sealed.
+    #[cfg(feature = "signing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+    pub fn signed_value<Val>(self,
+                             key: &SigningKey,
+                             action_id: &str,
+                             value: &Val)
+                             -> Result<Self, ValueError>
+      where Val: Serialize + ?Sized
+    {
+      key.seal_typed(&signing::value_context(action_id),
+                     value,
+                     value::BUTTON_VALUE_MAX_LEN)
+         .map(|v| self.value(v))
+    }
+
     /// Set `action_id` (**Required**)
     ///
     /// An identifier for this action.
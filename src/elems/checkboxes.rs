@@ -52,15 +52,15 @@ fn validate_initial_options<'a>(o: &Cow<'a, [MyOpt<'a>]>) -> ValidatorResult {
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct Checkboxes<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[cfg_attr(feature = "validation", validate(custom = "validate_options"))]
-  options: Cow<'a, [MyOpt<'a>]>,
+  pub(crate) options: Cow<'a, [MyOpt<'a>]>,
 
   #[cfg_attr(feature = "validation",
              validate(custom = "validate_initial_options"))]
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_options: Option<Cow<'a, [MyOpt<'a>]>>,
+  pub(crate) initial_options: Option<Cow<'a, [MyOpt<'a>]>>,
 
   #[cfg_attr(feature = "validation", validate)]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,7 +33,7 @@ use crate::{compose::Confirm, text};
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct DatePicker<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[cfg_attr(feature = "validation",
              validate(custom = "validate_placeholder"))]
@@ -41,7 +41,7 @@ pub struct DatePicker<'a> {
   placeholder: Option<text::Text>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_date: Option<String>,
+  pub(crate) initial_date: Option<String>,
 
   #[cfg_attr(feature = "validation", validate)]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -13,11 +13,14 @@
 //! [handling user interactivity guide 🔗]: https://api.slack.com/interactivity/handling
 //! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::convert;
 #[cfg(feature = "validation")]
 use crate::val_helpr::ValidationResult;
+use crate::{convert,
+            surfaces::{Surface, SurfaceError, SurfaceErrorReason}};
 
 pub mod button;
 pub mod checkboxes;
@@ -123,6 +126,31 @@ pub enum BlockElement<'a> {
 }
 
 impl<'a> BlockElement<'a> {
+  /// The `action_id` of this element, if it has one
+  pub(crate) fn action_id(&self) -> Option<&str> {
+    use BlockElement::*;
+
+    match self {
+      | Button(el) => Some(&el.action_id),
+      | Checkboxes(el) => Some(&el.action_id),
+      | Image(_) => None,
+      | DatePicker(el) => Some(&el.action_id),
+      | Overflow(el) => Some(&el.action_id),
+      | RadioButtons(el) => Some(&el.action_id),
+      | TextInput(el) => Some(&el.action_id),
+      | SelectPublicChannel(el) => Some(&el.action_id),
+      | SelectConversation(el) => Some(&el.action_id),
+      | SelectUser(el) => Some(&el.action_id),
+      | SelectExternal(el) => Some(&el.action_id),
+      | SelectStatic(el) => Some(&el.action_id),
+      | MultiSelectStatic(el) => Some(&el.action_id),
+      | MultiSelectUser(el) => Some(&el.action_id),
+      | MultiSelectExternal(el) => Some(&el.action_id),
+      | MultiSelectConversation(el) => Some(&el.action_id),
+      | MultiSelectPublicChannel(el) => Some(&el.action_id),
+    }
+  }
+
   /// Validate that this block element agrees with Slack's model requirements.
   ///
   /// ```
@@ -160,6 +188,104 @@ impl<'a> BlockElement<'a> {
       | TextInput(cts) => cts.validate(),
     }
   }
+
+  /// Validate that this block element can be used in some [`Surface`].
+  ///
+  /// Rules that depend on the block an element is used in (e.g. a `TextInput`
+  /// in a message needing `dispatch_action`) are checked by
+  /// [`Block::validate_for`](crate::blocks::Block::validate_for).
+  ///
+  /// # Errors
+  /// - If this is a multi-select menu and `surface` is not
+  ///   [`Surface::Modal`] or [`Surface::HomeTab`]
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{elems::{select, BlockElement, Button},
+  ///                    surfaces::{Surface, SurfaceErrorReason}};
+  ///
+  /// let btn: BlockElement =
+  ///   Button::builder().text("Go").action_id("go").build().into();
+  ///
+  /// assert_eq!(btn.validate_for(Surface::Message), Ok(()));
+  ///
+  /// let users: BlockElement = select::multi::User::builder().placeholder("Who")
+  ///                                                         .action_id("who")
+  ///                                                         .build()
+  ///                                                         .into();
+  ///
+  /// assert_eq!(users.validate_for(Surface::Modal), Ok(()));
+  /// assert_eq!(users.validate_for(Surface::Message).unwrap_err().reason(),
+  ///            &SurfaceErrorReason::ElementNotSupported { element:
+  ///                                                         "MultiSelectUser" });
+  /// ```
+  pub fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError> {
+    use BlockElement::*;
+
+    let in_message = surface.follows_message_rules();
+
+    // Exhaustive on purpose: new elements must decide which surfaces they support.
+    match self {
+      | MultiSelectStatic(_)
+      | MultiSelectUser(_)
+      | MultiSelectExternal(_)
+      | MultiSelectConversation(_)
+      | MultiSelectPublicChannel(_)
+        if in_message =>
+      {
+        let reason =
+          SurfaceErrorReason::ElementNotSupported { element: self.kind() };
+        Err(SurfaceError::new(surface, reason))
+      },
+      | Button(_)
+      | Checkboxes(_)
+      | Image(_)
+      | DatePicker(_)
+      | Overflow(_)
+      | RadioButtons(_)
+      | TextInput(_)
+      | SelectPublicChannel(_)
+      | SelectConversation(_)
+      | SelectUser(_)
+      | SelectExternal(_)
+      | SelectStatic(_)
+      | MultiSelectStatic(_)
+      | MultiSelectUser(_)
+      | MultiSelectExternal(_)
+      | MultiSelectConversation(_)
+      | MultiSelectPublicChannel(_) => Ok(()),
+    }
+  }
+
+  pub(crate) fn kind(&self) -> &'static str {
+    use BlockElement::*;
+
+    match self {
+      | Button(_) => "Button",
+      | Checkboxes(_) => "Checkboxes",
+      | Image(_) => "Image",
+      | DatePicker(_) => "DatePicker",
+      | Overflow(_) => "Overflow",
+      | RadioButtons(_) => "RadioButtons",
+      | TextInput(_) => "TextInput",
+      | SelectPublicChannel(_) => "SelectPublicChannel",
+      | SelectConversation(_) => "SelectConversation",
+      | SelectUser(_) => "SelectUser",
+      | SelectExternal(_) => "SelectExternal",
+      | SelectStatic(_) => "SelectStatic",
+      | MultiSelectStatic(_) => "MultiSelectStatic",
+      | MultiSelectUser(_) => "MultiSelectUser",
+      | MultiSelectExternal(_) => "MultiSelectExternal",
+      | MultiSelectConversation(_) => "MultiSelectConversation",
+      | MultiSelectPublicChannel(_) => "MultiSelectPublicChannel",
+    }
+  }
+}
+
+impl fmt::Display for BlockElement<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.kind())
+  }
 }
 
 convert!(impl<'a> From<Button<'a>> for BlockElement<'a> => |b| BlockElement::Button(b));
@@ -48,7 +48,7 @@ type MyOpt<'a> = Opt<'a, text::Plain, AllowUrl>;
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct Overflow<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[cfg_attr(feature = "validation", validate(length(min = 2, max = 5)))]
   #[cfg_attr(feature = "validation", validate)]
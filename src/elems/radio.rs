@@ -43,15 +43,15 @@ pub type RadioButtonOpt<'a> = Opt<'a, AnyText, NoUrl>;
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct Radio<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>, // max 255
+  pub(crate) action_id: Cow<'a, str>, // max 255
 
   #[cfg_attr(feature = "validation", validate(length(max = 10)))]
   #[cfg_attr(feature = "validation", validate)]
-  options: Vec<RadioButtonOpt<'a>>, // max 10, plain or md
+  pub(crate) options: Vec<RadioButtonOpt<'a>>, // max 10, plain or md
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
-  initial_option: Option<RadioButtonOpt<'a>>,
+  pub(crate) initial_option: Option<RadioButtonOpt<'a>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
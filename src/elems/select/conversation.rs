@@ -30,14 +30,15 @@ pub struct Conversation<'a> {
   placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_channel: Option<Cow<'a, str>>,
+  #[serde(rename = "initial_conversation", alias = "initial_channel")]
+  pub(crate) initial_channel: Option<Cow<'a, str>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   default_to_current_conversation: Option<bool>,
@@ -309,7 +310,7 @@ pub mod build {
                         A,
                         OptionalMethodNotCalled<method::initial_channel>>
   {
-    /// Set `initial_channel` (Optional, exclusive with `initial_channel_current`)
+    /// Set `initial_conversation` (Optional, exclusive with `initial_channel_current`)
     ///
     /// The ID of any valid conversation to be pre-selected when the menu loads.
     ///
@@ -336,7 +337,7 @@ pub mod build {
                         A,
                         OptionalMethodNotCalled<method::initial_channel>>
   {
-    /// Set `initial_channel` (Optional, exclusive with `initial_channel_current`)
+    /// Set `initial_conversations` (Optional, exclusive with `initial_channel_current`)
     ///
     /// A collection of IDs of any valid conversations to be pre-selected when the menu loads.
     pub fn initial_channels<S>(
@@ -43,10 +43,10 @@ pub struct External<'a> {
   placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_option: Option<OptOrOptGroup<'a>>,
+  pub(crate) initial_option: Option<OptOrOptGroup<'a>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   min_query_length: Option<u64>,
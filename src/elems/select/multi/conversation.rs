@@ -31,14 +31,15 @@ pub struct Conversation<'a> {
   pub(in crate::elems::select) placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  pub(in crate::elems::select) action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   pub(in crate::elems::select) confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) initial_channels: Option<Cow<'a, [String]>>,
+  #[serde(rename = "initial_conversations", alias = "initial_channels")]
+  pub(crate) initial_channels: Option<Cow<'a, [String]>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(in crate::elems::select) default_to_current_conversation: Option<bool>,
@@ -49,7 +50,7 @@ pub struct Conversation<'a> {
 
   #[cfg_attr(feature = "validation", validate(range(min = 1)))]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) max_selected_items: Option<u32>,
+  pub(crate) max_selected_items: Option<u32>,
 }
 
 impl<'a> Conversation<'a> {
@@ -42,7 +42,7 @@ pub struct External<'a> {
   pub(in crate::elems::select) placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  pub(in crate::elems::select) action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(in crate::elems::select) min_query_length: Option<u64>,
@@ -52,12 +52,11 @@ pub struct External<'a> {
   pub(in crate::elems::select) confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) initial_options:
-    Option<Cow<'a, [OptOrOptGroup<'a>]>>,
+  pub(crate) initial_options: Option<Cow<'a, [OptOrOptGroup<'a>]>>,
 
   #[cfg_attr(feature = "validation", validate(range(min = 1)))]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) max_selected_items: Option<u32>,
+  pub(crate) max_selected_items: Option<u32>,
 }
 
 impl<'a> External<'a> {
@@ -44,27 +44,26 @@ pub struct Static<'a> {
   pub(in crate::elems::select) placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  pub(in crate::elems::select) action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(length(max = 100)))]
-  pub(in crate::elems::select) options: Option<Vec<Opt<'a>>>,
+  pub(crate) options: Option<Vec<Opt<'a>>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(length(max = 100)))]
-  pub(in crate::elems::select) option_groups: Option<Vec<OptGroup<'a>>>,
+  pub(crate) option_groups: Option<Vec<OptGroup<'a>>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   pub(in crate::elems::select) confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) initial_options:
-    Option<Cow<'a, [OptOrOptGroup<'a>]>>,
+  pub(crate) initial_options: Option<Cow<'a, [OptOrOptGroup<'a>]>>,
 
   #[cfg_attr(feature = "validation", validate(range(min = 1)))]
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(in crate::elems::select) max_selected_items: Option<u32>,
+  pub(crate) max_selected_items: Option<u32>,
 }
 
 impl<'a> Static<'a> {
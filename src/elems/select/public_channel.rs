@@ -27,14 +27,14 @@ pub struct PublicChannel<'a> {
   placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_channel: Option<Cow<'a, str>>,
+  pub(crate) initial_channel: Option<Cow<'a, str>>,
 }
 
 impl<'a> PublicChannel<'a> {
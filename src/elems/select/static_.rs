@@ -37,22 +37,22 @@ pub struct Static<'a> {
   placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(length(max = 100)))]
-  options: Option<Vec<StaticOpt<'a>>>,
+  pub(crate) options: Option<Vec<StaticOpt<'a>>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate(length(max = 100)))]
-  option_groups: Option<Vec<StaticOptGroup<'a>>>,
+  pub(crate) option_groups: Option<Vec<StaticOptGroup<'a>>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_option: Option<StaticOptOrOptGroup<'a>>,
+  pub(crate) initial_option: Option<StaticOptOrOptGroup<'a>>,
 }
 
 impl<'a> Static<'a> {
@@ -27,14 +27,14 @@ pub struct User<'a> {
   placeholder: text::Text,
 
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   #[cfg_attr(feature = "validation", validate)]
   confirm: Option<Confirm>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_user: Option<Cow<'a, str>>,
+  pub(crate) initial_user: Option<Cow<'a, str>>,
 }
 
 impl<'a> User<'a> {
@@ -56,7 +56,7 @@ struct DispatchActionConfig {
 #[cfg_attr(feature = "validation", derive(Validate))]
 pub struct TextInput<'a> {
   #[cfg_attr(feature = "validation", validate(length(max = 255)))]
-  action_id: Cow<'a, str>,
+  pub(crate) action_id: Cow<'a, str>,
 
   #[cfg_attr(feature = "validation",
              validate(custom = "validate_placeholder"))]
@@ -64,17 +64,17 @@ pub struct TextInput<'a> {
   placeholder: Option<text::Text>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  initial_value: Option<Cow<'a, str>>,
+  pub(crate) initial_value: Option<Cow<'a, str>>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   multiline: Option<bool>,
 
   #[cfg_attr(feature = "validation", validate(range(max = 3000)))]
   #[serde(skip_serializing_if = "Option::is_none")]
-  min_length: Option<u32>,
+  pub(crate) min_length: Option<u32>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  max_length: Option<u32>,
+  pub(crate) max_length: Option<u32>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   dispatch_action_config: Option<DispatchActionConfig>,
@@ -0,0 +1,423 @@
+//! # Forms
+//!
+//! Describe a modal form as a plain Rust struct, generate its Input blocks
+//! and parse a [`view_submission`](crate::interaction::ViewSubmission)
+//! back into the struct.
+//!
+//! With the `derive` feature, [`SlackForm`] and [`Choice`] can be derived:
+//!
+//! | field type                 | element                                            |
+//! | -------------------------- | -------------------------------------------------- |
+//! | `String`                   | [`TextInput`](crate::elems::TextInput)             |
+//! | `bool`                     | [`Checkboxes`](crate::elems::Checkboxes)           |
+//! | [`Date`]                   | [`DatePicker`](crate::elems::DatePicker)           |
+//! | `#[derive(SlackChoice)]` enums | [`select::Static`](crate::elems::select::Static) |
+//! | `Option<T>`                | `T`'s element, in an `optional` Input block        |
+//!
+//! Fields accept `#[slack(label = "..", hint = "..", placeholder = "..")]`,
+//! and enum variants accept `#[slack(label = "..", value = "..")]`.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use slack_blocks::{form::{Date, SlackChoice, SlackForm},
+//!                    interaction::ViewState,
+//!                    surfaces::Modal};
+//!
+//! #[derive(SlackChoice)]
+//! enum Priority {
+//!   Low,
+//!   #[slack(label = "Drop everything")]
+//!   High,
+//! }
+//!
+//! #[derive(SlackForm)]
+//! struct Ticket {
+//!   #[slack(hint = "What went wrong?")]
+//!   title: String,
+//!   priority: Priority,
+//!   due_date: Option<Date>,
+//!   notify_me: bool,
+//! }
+//!
+//! let modal = Modal::builder().title("New ticket")
+//!                             .submit("Create")
+//!                             .blocks(Ticket::blocks())
+//!                             .build();
+//!
+//! let state: ViewState = serde_json::from_value(serde_json::json!({
+//!   "values": {
+//!     "title": { "title": { "type": "plain_text_input", "value": "Printer on fire" } },
+//!     "priority": { "priority": {
+//!       "type": "static_select",
+//!       "selected_option": {
+//!         "text": { "type": "plain_text", "text": "Drop everything" },
+//!         "value": "High"
+//!       }
+//!     } },
+//!     "due_date": { "due_date": { "type": "datepicker", "selected_date": null } },
+//!     "notify_me": { "notify_me": { "type": "checkboxes", "selected_options": [] } }
+//!   }
+//! })).unwrap();
+//!
+//! let ticket = Ticket::from_view_state(&state).unwrap();
+//!
+//! assert_eq!(ticket.title, "Printer on fire");
+//! assert!(matches!(ticket.priority, Priority::High));
+//! assert_eq!(ticket.due_date, None);
+//! assert!(!ticket.notify_me);
+//! # }
+//! ```
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use slack_blocks_derive::{SlackChoice, SlackForm};
+
+use crate::{blocks::{input::SupportedElement, Block, Input},
+            compose::Opt,
+            elems::{select, Checkboxes, DatePicker, TextInput},
+            interaction::{response_action::{InputErrors, UnknownBlockId},
+                          ViewState,
+                          ViewStateError},
+            surfaces::Modal};
+
+/// A struct that can be shown to users as a modal form,
+/// and parsed back out of the submitted [`ViewState`].
+///
+/// Usually derived; see the [module docs](self).
+pub trait SlackForm: Sized {
+  /// The Input blocks that make up this form
+  fn blocks() -> Vec<Block<'static>>;
+
+  /// Parse a submitted form
+  ///
+  /// # Errors
+  /// A [`FormErrors`] containing an error for each field that could not be parsed.
+  fn from_view_state(state: &ViewState) -> Result<Self, FormErrors>;
+}
+
+/// An enum that can be chosen from a static select menu in a [`SlackForm`].
+///
+/// Usually derived; see the [module docs](self).
+pub trait Choice: Sized {
+  /// The `(value, label)` of each option
+  fn choices() -> Vec<(&'static str, &'static str)>;
+
+  /// Get the variant that has this `value`
+  fn from_choice(value: &str) -> Option<Self>;
+}
+
+/// A type that can be a field of a [`SlackForm`].
+pub trait FormField: Sized {
+  /// Whether the Input block for this field may be left empty
+  const OPTIONAL: bool = false;
+
+  /// The element used to input this field
+  fn element(spec: &FieldSpec) -> SupportedElement<'static>;
+
+  /// Parse this field from a submitted form
+  ///
+  /// # Errors
+  /// - [`FieldError::Required`] if the input was left empty
+  /// - [`FieldError::Invalid`] if the input could not be parsed
+  /// - [`FieldError::State`] if the input was missing or unexpected
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError>;
+}
+
+/// Describes a single field of a [`SlackForm`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub struct FieldSpec {
+  /// The `block_id` and `action_id` of the field's input
+  pub id: &'static str,
+  /// The Input block's label
+  pub label: &'static str,
+  /// The Input block's hint
+  pub hint: Option<&'static str>,
+  /// The element's placeholder
+  pub placeholder: Option<&'static str>,
+}
+
+/// Build the Input block for a field of type `T`
+pub fn input<T: FormField>(spec: &FieldSpec) -> Input<'static> {
+  let input = Input::builder().label(spec.label)
+                              .element(T::element(spec))
+                              .block_id(spec.id)
+                              .optional(T::OPTIONAL);
+
+  match spec.hint {
+    | Some(hint) => input.hint(hint).build(),
+    | None => input.build(),
+  }
+}
+
+fn state_err(e: ViewStateError) -> FieldError {
+  FieldError::State(e)
+}
+
+impl FormField for String {
+  fn element(spec: &FieldSpec) -> SupportedElement<'static> {
+    let input = TextInput::builder().action_id(spec.id);
+
+    match spec.placeholder {
+      | Some(p) => input.placeholder(p).build().into(),
+      | None => input.build().into(),
+    }
+  }
+
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError> {
+    match state.text(spec.id, spec.id).map_err(state_err)? {
+      | Some(text) if !text.is_empty() => Ok(text.to_string()),
+      | _ => Err(FieldError::Required),
+    }
+  }
+}
+
+impl FormField for bool {
+  const OPTIONAL: bool = true;
+
+  fn element(spec: &FieldSpec) -> SupportedElement<'static> {
+    Checkboxes::builder().action_id(spec.id)
+                         .option(Opt::builder().text_plain(spec.label)
+                                               .value("true")
+                                               .build())
+                         .build()
+                         .into()
+  }
+
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError> {
+    state.selected_options(spec.id, spec.id)
+         .map(|opts| !opts.is_empty())
+         .map_err(state_err)
+  }
+}
+
+impl FormField for Date {
+  fn element(spec: &FieldSpec) -> SupportedElement<'static> {
+    let picker = DatePicker::builder().action_id(spec.id);
+
+    match spec.placeholder {
+      | Some(p) => picker.placeholder(p).build().into(),
+      | None => picker.build().into(),
+    }
+  }
+
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError> {
+    match state.selected_date(spec.id, spec.id).map_err(state_err)? {
+      | Some(date) => date.parse(),
+      | None => Err(FieldError::Required),
+    }
+  }
+}
+
+impl<T: Choice> FormField for T {
+  fn element(spec: &FieldSpec) -> SupportedElement<'static> {
+    let options = T::choices().into_iter().map(|(value, label)| {
+                                            Opt::builder().text_plain(label)
+                                                          .value(value)
+                                                          .build()
+                                          });
+
+    select::Static::builder().placeholder(spec.placeholder
+                                              .unwrap_or("Choose an option"))
+                             .action_id(spec.id)
+                             .options(options)
+                             .build()
+                             .into()
+  }
+
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError> {
+    let opt = state.selected_option(spec.id, spec.id)
+                   .map_err(state_err)?
+                   .ok_or(FieldError::Required)?;
+
+    T::from_choice(opt.value()).ok_or_else(|| {
+      FieldError::Invalid(format!("`{}` is not one of the choices",
+                                  opt.value()))
+    })
+  }
+}
+
+impl<T: FormField> FormField for Option<T> {
+  const OPTIONAL: bool = true;
+
+  fn element(spec: &FieldSpec) -> SupportedElement<'static> {
+    T::element(spec)
+  }
+
+  fn parse(state: &ViewState, spec: &FieldSpec) -> Result<Self, FieldError> {
+    match T::parse(state, spec) {
+      | Ok(value) => Ok(Some(value)),
+      | Err(FieldError::Required) => Ok(None),
+      | Err(e) => Err(e),
+    }
+  }
+}
+
+/// A calendar date picked in a [`DatePicker`](crate::elems::DatePicker)
+///
+/// Parsed from and displayed as `YYYY-MM-DD`.
+///
+/// # Example
+/// ```
+/// use slack_blocks::form::Date;
+///
+/// let date: Date = "2021-04-28".parse().unwrap();
+///
+/// assert_eq!(date,
+///            Date { year: 2021,
+///                   month: 4,
+///                   day: 28 });
+/// assert_eq!(date.to_string(), "2021-04-28");
+/// assert!("2021-13-01".parse::<Date>().is_err());
+/// assert!("2021-02-29".parse::<Date>().is_err());
+/// assert!("2020-02-29".parse::<Date>().is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+  /// The year, e.g. `2021`
+  pub year: u16,
+  /// The month, `1` through `12`
+  pub month: u8,
+  /// The day of the month, `1` through the number of days in `month`
+  pub day: u8,
+}
+
+impl FromStr for Date {
+  type Err = FieldError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || FieldError::Invalid(format!("`{}` is not a date", s));
+
+    let mut parts = s.splitn(3, '-');
+    let mut next = || parts.next().ok_or_else(invalid);
+
+    let year = next()?.parse().map_err(|_| invalid())?;
+    let month = next()?.parse().map_err(|_| invalid())?;
+    let day = next()?.parse().map_err(|_| invalid())?;
+
+    if !(1..=12).contains(&month)
+       || !(1..=days_in_month(year, month)).contains(&day)
+    {
+      return Err(invalid());
+    }
+
+    Ok(Date { year, month, day })
+  }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+  let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+  match month {
+    | 2 if leap => 29,
+    | 2 => 28,
+    | 4 | 6 | 9 | 11 => 30,
+    | _ => 31,
+  }
+}
+
+impl fmt::Display for Date {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+  }
+}
+
+/// Why a field of a [`SlackForm`] could not be parsed
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FieldError {
+  /// A required field was left empty
+  Required,
+
+  /// The submitted value could not be parsed
+  Invalid(String),
+
+  /// The field was missing from the view state,
+  /// or was a different kind of element than expected
+  State(ViewStateError),
+}
+
+impl fmt::Display for FieldError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | FieldError::Required => write!(f, "This field is required"),
+      | FieldError::Invalid(msg) => write!(f, "{}", msg),
+      | FieldError::State(e) => write!(f, "{}", e),
+    }
+  }
+}
+
+impl std::error::Error for FieldError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      | FieldError::State(e) => Some(e),
+      | _ => None,
+    }
+  }
+}
+
+/// Errors for each field of a [`SlackForm`] that could not be parsed,
+/// keyed by `block_id`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FormErrors {
+  errors: BTreeMap<String, FieldError>,
+}
+
+impl FormErrors {
+  /// Create an empty set of errors
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add an error for the field with `block_id`
+  pub fn insert(&mut self, block_id: impl ToString, error: FieldError) {
+    self.errors.insert(block_id.to_string(), error);
+  }
+
+  /// Whether there are no errors
+  pub fn is_empty(&self) -> bool {
+    self.errors.is_empty()
+  }
+
+  /// Get the error for the field with `block_id`
+  pub fn get(&self, block_id: &str) -> Option<&FieldError> {
+    self.errors.get(block_id)
+  }
+
+  /// All errors, keyed by `block_id`
+  pub fn errors(&self) -> &BTreeMap<String, FieldError> {
+    &self.errors
+  }
+
+  /// Show these errors under the Input blocks of the submitted modal
+  ///
+  /// # Errors
+  /// If the modal has no Input block for one of the errors.
+  pub fn input_errors<'a>(&self,
+                          view: &Modal<'_>)
+                          -> Result<InputErrors<'a>, UnknownBlockId> {
+    self.errors.iter().try_fold(InputErrors::for_view(view),
+                                |errs, (block_id, error)| {
+                                  errs.error(block_id.clone(),
+                                             error.to_string())
+                                })
+  }
+}
+
+impl fmt::Display for FormErrors {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid form submission")?;
+
+    for (block_id, error) in &self.errors {
+      write!(f, "; {}: {}", block_id, error)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl std::error::Error for FormErrors {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
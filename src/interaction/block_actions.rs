@@ -0,0 +1,468 @@
+//! # Block Actions payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received when a user interacts with a [block element 🔗]
+//! in a message, modal or home tab.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/block-actions
+//! [block element 🔗]: https://api.slack.com/reference/block-kit/block-elements
+
+use std::borrow::Cow;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{Channel, Container, Team, User};
+#[cfg(feature = "signing")]
+use crate::compose::signing::{self, SigningKey};
+use crate::{compose::{action_id::{ActionId, ActionIdError},
+                      opt::{AllowUrl, AnyText},
+                      text,
+                      value::{self, ValueError},
+                      Opt},
+            surfaces::{Message, View}};
+
+/// # Block Actions payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user interacts with a [block element 🔗]
+/// in a message, modal or home tab.
+///
+/// # Example
+/// ```
+/// use slack_blocks::interaction::{block_actions::ActionKind, BlockActions};
+///
+/// let payload = r#"{
+///   "type": "block_actions",
+///   "team": { "id": "T9TK3CUKW", "domain": "example" },
+///   "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+///   "api_app_id": "AABA1ABCD",
+///   "container": { "type": "message", "message_ts": "1548426417.840180", "channel_id": "CBR2V3XEX" },
+///   "trigger_id": "12345.98765.abcd2358fdea",
+///   "channel": { "id": "CBR2V3XEX", "name": "review-updates" },
+///   "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+///   "actions": [
+///     {
+///       "type": "button",
+///       "action_id": "approve",
+///       "block_id": "request",
+///       "text": { "type": "plain_text", "text": "Approve" },
+///       "value": "req_42",
+///       "action_ts": "1548426417.840180"
+///     }
+///   ]
+/// }"#;
+///
+/// let payload: BlockActions = serde_json::from_str(payload).unwrap();
+/// let action = &payload.actions()[0];
+///
+/// assert_eq!(action.action_id(), "approve");
+/// assert!(matches!(action.kind(), ActionKind::Button { value: Some(v), .. } if v == "req_42"));
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/block-actions
+/// [block element 🔗]: https://api.slack.com/reference/block-kit/block-elements
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BlockActions<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  container: Option<Container<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  trigger_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  channel: Option<Channel<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  message: Option<Message<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  view: Option<View<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  response_url: Option<Cow<'a, str>>,
+
+  #[serde(default)]
+  actions: Vec<Action<'a>>,
+}
+
+impl<'a> BlockActions<'a> {
+  /// The workspace the interaction happened in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user who interacted with your app
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// The surface containing the block that was interacted with
+  pub fn container(&self) -> Option<&Container<'a>> {
+    self.container.as_ref()
+  }
+
+  /// A short-lived ID that can be used to [open modals 🔗]
+  ///
+  /// [open modals 🔗]: https://api.slack.com/surfaces/modals/using#opening_modals
+  pub fn trigger_id(&self) -> Option<&str> {
+    self.trigger_id.as_deref()
+  }
+
+  /// The conversation the interaction happened in, if it happened in a message
+  pub fn channel(&self) -> Option<&Channel<'a>> {
+    self.channel.as_ref()
+  }
+
+  /// The message the interaction happened in, if any
+  pub fn message(&self) -> Option<&Message<'a>> {
+    self.message.as_ref()
+  }
+
+  /// The view the interaction happened in, if any
+  pub fn view(&self) -> Option<&View<'a>> {
+    self.view.as_ref()
+  }
+
+  /// A URL that can be used to [respond to the interaction 🔗],
+  /// if it happened in a message
+  ///
+  /// [respond to the interaction 🔗]: https://api.slack.com/interactivity/handling#message_responses
+  pub fn response_url(&self) -> Option<&str> {
+    self.response_url.as_deref()
+  }
+
+  /// The interactive elements that were interacted with
+  pub fn actions(&self) -> &[Action<'a>] {
+    &self.actions
+  }
+}
+
+/// # Action
+///
+/// A single interaction with a block element,
+/// e.g. a button being clicked.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Action<'a> {
+  action_id: Cow<'a, str>,
+
+  block_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  action_ts: Option<Cow<'a, str>>,
+
+  #[serde(flatten)]
+  kind: ActionKind<'a>,
+}
+
+impl<'a> Action<'a> {
+  /// The `action_id` of the element that was interacted with
+  pub fn action_id(&self) -> &str {
+    &self.action_id
+  }
+
+  /// The `block_id` of the block containing the element that was interacted with
+  pub fn block_id(&self) -> &str {
+    &self.block_id
+  }
+
+  /// Parse the `action_id` of the element that was interacted with
+  /// into a typed [`ActionId`]
+  ///
+  /// # Errors
+  /// [`ActionIdError::Unrecognized`] if the `action_id` is not the encoding of any `T`
+  pub fn parse_action_id<T: ActionId>(&self) -> Result<T, ActionIdError> {
+    T::from_action_id(&self.action_id)
+  }
+
+  /// Parse the `block_id` of the block containing the element
+  /// that was interacted with into a typed [`ActionId`]
+  ///
+  /// # Errors
+  /// [`ActionIdError::Unrecognized`] if the `block_id` is not the encoding of any `T`
+  pub fn parse_block_id<T: ActionId>(&self) -> Result<T, ActionIdError> {
+    T::from_action_id(&self.block_id)
+  }
+
+  /// When the interaction happened
+  pub fn action_ts(&self) -> Option<&str> {
+    self.action_ts.as_deref()
+  }
+
+  /// The kind of element that was interacted with, and its new value
+  pub fn kind(&self) -> &ActionKind<'a> {
+    &self.kind
+  }
+
+  /// Decode the `value` of a clicked button, or of the option chosen
+  /// in an overflow menu, radio button group or select menu,
+  /// that was set with a `typed_value` builder method.
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this kind of action has no single value
+  /// - [`ValueError::Deserialize`] if the value is not the JSON encoding of a `T`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::interaction::BlockActions;
+  ///
+  /// let payload: BlockActions = serde_json::from_value(serde_json::json!({
+  ///   "team": { "id": "T9TK3CUKW" },
+  ///   "user": { "id": "UA8RXUSPL" },
+  ///   "actions": [{
+  ///     "type": "button",
+  ///     "action_id": "approve",
+  ///     "block_id": "request",
+  ///     "value": "{\"ticket\":42}"
+  ///   }]
+  /// })).unwrap();
+  ///
+  /// let value: serde_json::Value = payload.actions()[0].typed_value().unwrap();
+  /// assert_eq!(value["ticket"], 42);
+  /// ```
+  pub fn typed_value<T: DeserializeOwned>(&self) -> Result<T, ValueError> {
+    self.value()
+        .ok_or(ValueError::Missing)
+        .and_then(value::decode)
+  }
+
+  /// The `value` of a clicked button, or of the option chosen
+  /// in an overflow menu, radio button group or select menu.
+  pub fn value(&self) -> Option<&str> {
+    use ActionKind::*;
+
+    match &self.kind {
+      | Button { value, .. } => value.as_deref(),
+      | Overflow { selected_option: Some(opt), } => Some(opt.value()),
+      | RadioButtons { selected_option: Some(opt), }
+      | SelectStatic { selected_option: Some(opt), }
+      | SelectExternal { selected_option: Some(opt), } => Some(opt.value()),
+      | _ => None,
+    }
+  }
+
+  /// Verify and decode the `value` of a clicked button, or of the option chosen
+  /// in an overflow menu, radio button group or select menu,
+  /// that was set with a `signed_value` builder method for the action `action_id`.
+  ///
+  /// Fails closed: a value that was not sealed by `key` for `action_id`
+  /// is never decoded.
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this kind of action has no single value
+  /// - [`ValueError::BadSignature`] if the value was not sealed by `key` for `action_id`
+  /// - [`ValueError::Deserialize`] if the value is not the JSON encoding of a `T`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{compose::{signing::{value_context, SigningKey},
+  ///                               value::ValueError},
+  ///                    interaction::BlockActions};
+  ///
+  /// let key = SigningKey::new("secret");
+  /// let payload = |value: String| -> BlockActions<'static> {
+  ///   serde_json::from_value(serde_json::json!({
+  ///     "team": { "id": "T9TK3CUKW" },
+  ///     "user": { "id": "UA8RXUSPL" },
+  ///     "actions": [{
+  ///       "type": "button",
+  ///       "action_id": "refund",
+  ///       "block_id": "order",
+  ///       "value": value
+  ///     }]
+  ///   })).unwrap()
+  /// };
+  ///
+  /// let sealed = key.seal_typed(&value_context("refund"), &7, 2000).unwrap();
+  /// let genuine = payload(sealed.clone());
+  /// let forged = payload(sealed.replacen('7', "8", 1));
+  ///
+  /// assert_eq!(genuine.actions()[0].verified_value::<u32>(&key, "refund"),
+  ///            Ok(7));
+  /// assert_eq!(forged.actions()[0].verified_value::<u32>(&key, "refund"),
+  ///            Err(ValueError::BadSignature));
+  /// assert_eq!(genuine.actions()[0].verified_value::<u32>(&key, "approve"),
+  ///            Err(ValueError::BadSignature));
+  /// ```
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn verified_value<T: DeserializeOwned>(&self,
+                                             key: &SigningKey,
+                                             action_id: &str)
+                                             -> Result<T, ValueError> {
+    let context = signing::value_context(action_id);
+
+    self.value()
+        .ok_or(ValueError::Missing)
+        .and_then(|v| key.open_typed(&context, v))
+  }
+
+  /// Verify an `action_id` that was sealed with [`SigningKey::seal`]
+  /// for the block `block_id` (see [`signing::action_id_context`]),
+  /// returning it without its tag.
+  ///
+  /// # Errors
+  /// [`ValueError::BadSignature`] if the `action_id` was not sealed by `key` for `block_id`
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn verified_action_id(&self,
+                            key: &SigningKey,
+                            block_id: &str)
+                            -> Result<&str, ValueError> {
+    key.open(&signing::action_id_context(block_id), &self.action_id)
+  }
+}
+
+/// # Action Kind
+///
+/// The kind of element that was interacted with,
+/// and the value the user chose.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionKind<'a> {
+  /// A [`Button`](crate::elems::Button) was clicked
+  Button {
+    /// The text of the button
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<text::Text>,
+
+    /// The `value` of the button
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<Cow<'a, str>>,
+  },
+
+  /// Options of a [`Checkboxes`](crate::elems::Checkboxes) group were toggled
+  Checkboxes {
+    /// All currently selected options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// A date was picked in a [`DatePicker`](crate::elems::DatePicker)
+  #[serde(rename = "datepicker")]
+  DatePicker {
+    /// The selected date, formatted `YYYY-MM-DD`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_date: Option<Cow<'a, str>>,
+  },
+
+  /// An option was chosen in an [`Overflow`](crate::elems::Overflow) menu
+  Overflow {
+    /// The chosen option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a, AnyText, AllowUrl>>,
+  },
+
+  /// An option was chosen in a [`Radio`](crate::elems::Radio) button group
+  RadioButtons {
+    /// The chosen option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// Text was entered in a [`TextInput`](crate::elems::TextInput)
+  /// whose Input block sets `dispatch_action`
+  #[serde(rename = "plain_text_input")]
+  TextInput {
+    /// The entered text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<Cow<'a, str>>,
+  },
+
+  /// A public channel was chosen in a [`select::PublicChannel`](crate::elems::select::PublicChannel) menu
+  #[serde(rename = "channels_select")]
+  SelectPublicChannel {
+    /// The ID of the chosen channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_channel: Option<Cow<'a, str>>,
+  },
+
+  /// A conversation was chosen in a [`select::Conversation`](crate::elems::select::Conversation) menu
+  #[serde(rename = "conversations_select")]
+  SelectConversation {
+    /// The ID of the chosen conversation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_conversation: Option<Cow<'a, str>>,
+  },
+
+  /// A user was chosen in a [`select::User`](crate::elems::select::User) menu
+  #[serde(rename = "users_select")]
+  SelectUser {
+    /// The ID of the chosen user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_user: Option<Cow<'a, str>>,
+  },
+
+  /// An option was chosen in a [`select::External`](crate::elems::select::External) menu
+  #[serde(rename = "external_select")]
+  SelectExternal {
+    /// The chosen option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// An option was chosen in a [`select::Static`](crate::elems::select::Static) menu
+  #[serde(rename = "static_select")]
+  SelectStatic {
+    /// The chosen option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// Options were chosen in a [`select::multi::Static`](crate::elems::select::multi::Static) menu
+  #[serde(rename = "multi_static_select")]
+  MultiSelectStatic {
+    /// All currently chosen options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// Users were chosen in a [`select::multi::User`](crate::elems::select::multi::User) menu
+  #[serde(rename = "multi_users_select")]
+  MultiSelectUser {
+    /// The IDs of all currently chosen users
+    #[serde(default)]
+    selected_users: Vec<Cow<'a, str>>,
+  },
+
+  /// Options were chosen in a [`select::multi::External`](crate::elems::select::multi::External) menu
+  #[serde(rename = "multi_external_select")]
+  MultiSelectExternal {
+    /// All currently chosen options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// Conversations were chosen in a [`select::multi::Conversation`](crate::elems::select::multi::Conversation) menu
+  #[serde(rename = "multi_conversations_select")]
+  MultiSelectConversation {
+    /// The IDs of all currently chosen conversations
+    #[serde(default)]
+    selected_conversations: Vec<Cow<'a, str>>,
+  },
+
+  /// Public channels were chosen in a [`select::multi::PublicChannel`](crate::elems::select::multi::PublicChannel) menu
+  #[serde(rename = "multi_channels_select")]
+  MultiSelectPublicChannel {
+    /// The IDs of all currently chosen channels
+    #[serde(default)]
+    selected_channels: Vec<Cow<'a, str>>,
+  },
+
+  /// An element this crate doesn't model yet
+  #[serde(other)]
+  Unknown,
+}
@@ -0,0 +1,316 @@
+//! # Block Suggestion payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received at your app's options load URL when a user types into
+//! an [external select menu 🔗] or [external multi-select menu 🔗],
+//! and answered with an [`OptionsResponse`].
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_select
+//! [external select menu 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_select
+//! [external multi-select menu 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_multi_select
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::{Channel, Container, Team, User};
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+use crate::{compose::{opt::NoUrl, Opt, OptGroup},
+            surfaces::{Message, View},
+            text};
+
+/// The number of characters Slack waits for before requesting options,
+/// when an external select doesn't set `min_query_length`.
+pub const DEFAULT_MIN_QUERY_LENGTH: u64 = 3;
+
+/// # Block Suggestion payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user types into an [external select menu 🔗]
+/// or [external multi-select menu 🔗].
+///
+/// # Example
+/// ```
+/// use slack_blocks::{compose::Opt,
+///                    interaction::{BlockSuggestion, OptionsResponse}};
+///
+/// let payload: BlockSuggestion = serde_json::from_value(serde_json::json!({
+///   "type": "block_suggestion",
+///   "team": { "id": "T9TK3CUKW", "domain": "example" },
+///   "user": { "id": "UA8RXUSPL" },
+///   "container": { "type": "view", "view_id": "VNHU13V36" },
+///   "action_id": "pick_fruit",
+///   "block_id": "fruit",
+///   "value": "ap"
+/// })).unwrap();
+///
+/// let fruits = ["apple", "apricot", "banana"];
+/// let matches = fruits.iter()
+///                     .filter(|f| f.starts_with(payload.value()))
+///                     .map(|f| Opt::builder().text_plain(*f).value(*f).build());
+///
+/// let response = OptionsResponse::options(matches);
+///
+/// assert_eq!(serde_json::to_value(&response).unwrap()["options"][1]["value"],
+///            "apricot");
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_select
+/// [external select menu 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_select
+/// [external multi-select menu 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_multi_select
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BlockSuggestion<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  container: Option<Container<'a>>,
+
+  action_id: Cow<'a, str>,
+
+  block_id: Cow<'a, str>,
+
+  #[serde(default)]
+  value: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  channel: Option<Channel<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  message: Option<Message<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  view: Option<View<'a>>,
+}
+
+impl<'a> BlockSuggestion<'a> {
+  /// The workspace the select menu is in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user typing into the select menu
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// The surface containing the select menu
+  pub fn container(&self) -> Option<&Container<'a>> {
+    self.container.as_ref()
+  }
+
+  /// The `action_id` of the select menu
+  pub fn action_id(&self) -> &str {
+    &self.action_id
+  }
+
+  /// The `block_id` of the block containing the select menu
+  pub fn block_id(&self) -> &str {
+    &self.block_id
+  }
+
+  /// The text the user has typed so far
+  pub fn value(&self) -> &str {
+    &self.value
+  }
+
+  /// The conversation the select menu is in, if it's in a message
+  pub fn channel(&self) -> Option<&Channel<'a>> {
+    self.channel.as_ref()
+  }
+
+  /// The message the select menu is in, if any
+  pub fn message(&self) -> Option<&Message<'a>> {
+    self.message.as_ref()
+  }
+
+  /// The view the select menu is in, if any
+  pub fn view(&self) -> Option<&View<'a>> {
+    self.view.as_ref()
+  }
+
+  /// Whether the typed `value` is at least `min_query_length` characters long.
+  ///
+  /// Pass the `min_query_length` the select menu was built with,
+  /// or `None` to use Slack's default of [`DEFAULT_MIN_QUERY_LENGTH`].
+  ///
+  /// Slack shouldn't send shorter queries, but this lets your
+  /// options load URL reject them (e.g. with [`OptionsResponse::empty`])
+  /// rather than run an expensive search.
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::interaction::BlockSuggestion;
+  ///
+  /// let payload: BlockSuggestion = serde_json::from_value(serde_json::json!({
+  ///   "type": "block_suggestion",
+  ///   "team": { "id": "T9TK3CUKW" },
+  ///   "user": { "id": "UA8RXUSPL" },
+  ///   "action_id": "pick_fruit",
+  ///   "block_id": "fruit",
+  ///   "value": "ap"
+  /// })).unwrap();
+  ///
+  /// assert!(!payload.meets_min_query_length(None));
+  /// assert!(payload.meets_min_query_length(Some(1)));
+  /// ```
+  pub fn meets_min_query_length(&self, min_query_length: Option<u64>) -> bool {
+    let min = min_query_length.unwrap_or(DEFAULT_MIN_QUERY_LENGTH);
+    self.value.chars().count() as u64 >= min
+  }
+}
+
+type PlainOpt<'a> = Opt<'a, text::Plain, NoUrl>;
+type PlainOptGroup<'a> = OptGroup<'a, text::Plain, NoUrl>;
+
+/// # Options Response
+///
+/// [slack api docs 🔗]
+///
+/// The response body for a [`BlockSuggestion`] request,
+/// containing either a list of options or a list of option groups
+/// to show in the external select menu.
+///
+/// Slack accepts at most 100 options (or option groups)
+/// per response; use [`OptionsResponse::validate`] to check.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/block-elements#external_select
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct OptionsResponse<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(length(max = 100)))]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::options"))]
+  options: Option<Vec<PlainOpt<'a>>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(length(max = 100)))]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::option_groups"))]
+  option_groups: Option<Vec<PlainOptGroup<'a>>>,
+}
+
+impl<'a> OptionsResponse<'a> {
+  /// Respond with a flat list of options
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{compose::Opt, interaction::OptionsResponse};
+  ///
+  /// let response = OptionsResponse::options(vec![
+  ///   Opt::builder().text_plain("Apple").value("apple").build(),
+  /// ]);
+  ///
+  /// assert_eq!(serde_json::to_value(&response).unwrap(),
+  ///            serde_json::json!({
+  ///              "options": [{
+  ///                "text": { "type": "plain_text", "text": "Apple" },
+  ///                "value": "apple"
+  ///              }]
+  ///            }));
+  /// ```
+  pub fn options<I>(options: I) -> Self
+    where I: IntoIterator<Item = PlainOpt<'a>>
+  {
+    Self { options: Some(options.into_iter().collect()),
+           option_groups: None }
+  }
+
+  /// Respond with a list of option groups
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{compose::{Opt, OptGroup},
+  ///                    interaction::OptionsResponse};
+  ///
+  /// let fruit = OptGroup::builder().label("Fruit")
+  ///                                .option(Opt::builder().text_plain("Apple")
+  ///                                                      .value("apple")
+  ///                                                      .build())
+  ///                                .build();
+  ///
+  /// let response = OptionsResponse::option_groups(vec![fruit]);
+  ///
+  /// assert!(serde_json::to_value(&response).unwrap()["option_groups"].is_array());
+  /// ```
+  pub fn option_groups<I>(groups: I) -> Self
+    where I: IntoIterator<Item = PlainOptGroup<'a>>
+  {
+    Self { options: None,
+           option_groups: Some(groups.into_iter().collect()) }
+  }
+
+  /// Respond with no options, e.g. when the query is too short
+  /// or nothing matched.
+  pub fn empty() -> Self {
+    Self::options(None)
+  }
+
+  /// Validate that this response agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `options` contains more than 100 options
+  /// - If `option_groups` contains more than 100 groups
+  /// - If any option or option group is invalid
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{compose::Opt, interaction::OptionsResponse};
+  ///
+  /// let too_many = (0..101).map(|i| {
+  ///                          Opt::builder().text_plain(i.to_string())
+  ///                                        .value(i.to_string())
+  ///                                        .build()
+  ///                        });
+  ///
+  /// assert!(matches!(OptionsResponse::options(too_many).validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::{PlainOpt, PlainOptGroup};
+  use crate::val_helpr::{error, ValidatorResult};
+
+  pub(super) fn options(options: &[PlainOpt]) -> ValidatorResult {
+    options.iter().try_for_each(|opt| {
+                    opt.validate()
+                       .map_err(|e| {
+                         error("OptionsResponse.options", e.to_string())
+                       })
+                  })
+  }
+
+  pub(super) fn option_groups(groups: &[PlainOptGroup]) -> ValidatorResult {
+    groups.iter().try_for_each(|group| {
+                   group.validate()
+                        .and_then(|_| {
+                          group.options
+                               .iter()
+                               .try_for_each(|opt| opt.validate())
+                        })
+                        .map_err(|e| {
+                          error("OptionsResponse.option_groups", e.to_string())
+                        })
+                 })
+  }
+}
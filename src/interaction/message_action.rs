@@ -0,0 +1,106 @@
+//! # Message Action payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received when a user invokes one of your app's [message shortcuts 🔗]
+//! from the "more actions" menu of a message.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/shortcuts#message
+//! [message shortcuts 🔗]: https://api.slack.com/interactivity/shortcuts/using#message_shortcuts
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Channel, Team, User};
+use crate::surfaces::Message;
+
+/// # Message Action payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user invokes one of your app's [message shortcuts 🔗]
+/// on a message.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/shortcuts#message
+/// [message shortcuts 🔗]: https://api.slack.com/interactivity/shortcuts/using#message_shortcuts
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MessageAction<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  channel: Channel<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  callback_id: Cow<'a, str>,
+
+  trigger_id: Cow<'a, str>,
+
+  response_url: Cow<'a, str>,
+
+  message_ts: Cow<'a, str>,
+
+  message: Message<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  action_ts: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageAction<'a> {
+  /// The workspace the shortcut was invoked in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user who invoked the shortcut
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The conversation containing the message
+  pub fn channel(&self) -> &Channel<'a> {
+    &self.channel
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// The `callback_id` configured for the shortcut in your app's settings
+  pub fn callback_id(&self) -> &str {
+    &self.callback_id
+  }
+
+  /// A short-lived ID that can be used to [open modals 🔗]
+  ///
+  /// [open modals 🔗]: https://api.slack.com/surfaces/modals/using#opening_modals
+  pub fn trigger_id(&self) -> &str {
+    &self.trigger_id
+  }
+
+  /// A URL that can be used to [respond in the conversation 🔗]
+  ///
+  /// [respond in the conversation 🔗]: https://api.slack.com/interactivity/handling#message_responses
+  pub fn response_url(&self) -> &str {
+    &self.response_url
+  }
+
+  /// The `ts` of the message the shortcut was invoked on
+  pub fn message_ts(&self) -> &str {
+    &self.message_ts
+  }
+
+  /// The message the shortcut was invoked on
+  pub fn message(&self) -> &Message<'a> {
+    &self.message
+  }
+
+  /// When the shortcut was invoked
+  pub fn action_ts(&self) -> Option<&str> {
+    self.action_ts.as_deref()
+  }
+}
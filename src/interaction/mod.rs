@@ -0,0 +1,251 @@
+//! # Interaction payloads
+//!
+//! [slack api docs 🔗]
+//!
+//! When a user interacts with your app (e.g. clicks a button, submits a modal),
+//! Slack sends an [interaction payload 🔗] to your app's Request URL.
+//!
+//! The payload is sent as the `payload` parameter of an
+//! `application/x-www-form-urlencoded` body, and contains JSON
+//! that can be deserialized into the models in this module.
+//!
+//! An endpoint that receives many kinds of interactions can deserialize
+//! the payload into an [`InteractionPayload`] and match on it.
+//!
+//! Before trusting a payload, verify that the request came from Slack;
+//! see the `verify` module (requires the `verify` feature).
+//!
+//! [slack api docs 🔗]: https://api.slack.com/interactivity/handling
+//! [interaction payload 🔗]: https://api.slack.com/reference/interaction-payloads
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+
+pub mod block_actions;
+#[doc(inline)]
+pub use block_actions::BlockActions;
+
+pub mod block_suggestion;
+#[doc(inline)]
+pub use block_suggestion::{BlockSuggestion, OptionsResponse};
+
+pub mod message_action;
+#[doc(inline)]
+pub use message_action::MessageAction;
+
+pub mod response_action;
+#[doc(inline)]
+pub use response_action::ResponseAction;
+
+pub mod router;
+#[doc(inline)]
+pub use router::Router;
+
+pub mod shortcut;
+#[doc(inline)]
+pub use shortcut::Shortcut;
+
+pub mod view_closed;
+#[doc(inline)]
+pub use view_closed::ViewClosed;
+
+pub mod view_state;
+#[doc(inline)]
+pub use view_state::{ViewState, ViewStateError};
+
+pub mod view_submission;
+#[doc(inline)]
+pub use view_submission::ViewSubmission;
+
+#[cfg(feature = "verify")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+pub mod verify;
+
+/// # Interaction Payload
+///
+/// [slack api docs 🔗]
+///
+/// Any interaction payload Slack may send to your app,
+/// dispatched on the payload's `type` field.
+///
+/// # Example
+/// ```
+/// use slack_blocks::interaction::InteractionPayload;
+///
+/// let payload: InteractionPayload = serde_json::from_value(serde_json::json!({
+///   "type": "shortcut",
+///   "team": { "id": "T9TK3CUKW", "domain": "example" },
+///   "user": { "id": "UA8RXUSPL" },
+///   "callback_id": "new_ticket",
+///   "trigger_id": "12345.98765.abcd2358fdea"
+/// })).unwrap();
+///
+/// match payload {
+///   | InteractionPayload::Shortcut(shortcut) => {
+///     assert_eq!(shortcut.callback_id(), "new_ticket")
+///   },
+///   | _ => panic!("expected a shortcut"),
+/// }
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InteractionPayload<'a> {
+  /// # [`BlockActions`]
+  BlockActions(BlockActions<'a>),
+
+  /// # [`BlockSuggestion`]
+  BlockSuggestion(BlockSuggestion<'a>),
+
+  /// # [`ViewSubmission`]
+  ViewSubmission(ViewSubmission<'a>),
+
+  /// # [`ViewClosed`]
+  ViewClosed(ViewClosed<'a>),
+
+  /// # [`Shortcut`]
+  Shortcut(Shortcut<'a>),
+
+  /// # [`MessageAction`]
+  MessageAction(MessageAction<'a>),
+
+  /// A kind of payload this crate doesn't model yet
+  #[serde(other)]
+  Unknown,
+}
+
+convert!(impl<'a> From<BlockActions<'a>> for InteractionPayload<'a> => |p| InteractionPayload::BlockActions(p));
+convert!(impl<'a> From<BlockSuggestion<'a>> for InteractionPayload<'a> => |p| InteractionPayload::BlockSuggestion(p));
+convert!(impl<'a> From<ViewSubmission<'a>> for InteractionPayload<'a> => |p| InteractionPayload::ViewSubmission(p));
+convert!(impl<'a> From<ViewClosed<'a>> for InteractionPayload<'a> => |p| InteractionPayload::ViewClosed(p));
+convert!(impl<'a> From<Shortcut<'a>> for InteractionPayload<'a> => |p| InteractionPayload::Shortcut(p));
+convert!(impl<'a> From<MessageAction<'a>> for InteractionPayload<'a> => |p| InteractionPayload::MessageAction(p));
+
+/// The workspace an interaction happened in
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Team<'a> {
+  id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  domain: Option<Cow<'a, str>>,
+}
+
+impl<'a> Team<'a> {
+  /// The team's ID, e.g. `T9TK3CUKW`
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// The team's domain, e.g. `example` for `example.slack.com`
+  pub fn domain(&self) -> Option<&str> {
+    self.domain.as_deref()
+  }
+}
+
+/// The user who interacted with your app
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct User<'a> {
+  id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  username: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> User<'a> {
+  /// The user's ID, e.g. `UA8RXUSPL`
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// The user's username
+  pub fn username(&self) -> Option<&str> {
+    self.username.as_deref()
+  }
+
+  /// The user's name
+  pub fn name(&self) -> Option<&str> {
+    self.name.as_deref()
+  }
+
+  /// The ID of the user's team
+  pub fn team_id(&self) -> Option<&str> {
+    self.team_id.as_deref()
+  }
+}
+
+/// The conversation an interaction happened in
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Channel<'a> {
+  id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Channel<'a> {
+  /// The conversation's ID, e.g. `CBR2V3XEX`
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// The conversation's name
+  pub fn name(&self) -> Option<&str> {
+    self.name.as_deref()
+  }
+}
+
+/// # Container
+///
+/// The surface containing the block an interaction happened in.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container<'a> {
+  /// A message
+  Message {
+    /// The `ts` of the message
+    message_ts: Cow<'a, str>,
+
+    /// The conversation the message is in
+    channel_id: Cow<'a, str>,
+
+    /// Whether the message is ephemeral
+    #[serde(default)]
+    is_ephemeral: bool,
+  },
+
+  /// A secondary attachment of a message
+  MessageAttachment {
+    /// The `ts` of the message
+    message_ts: Cow<'a, str>,
+
+    /// The 1-based index of the attachment in the message
+    attachment_id: u32,
+
+    /// The conversation the message is in
+    channel_id: Cow<'a, str>,
+
+    /// Whether the message is ephemeral
+    #[serde(default)]
+    is_ephemeral: bool,
+
+    /// Whether the attachment is an app unfurl
+    #[serde(default)]
+    is_app_unfurl: bool,
+  },
+
+  /// A modal or home tab view
+  View {
+    /// The ID of the view
+    view_id: Cow<'a, str>,
+  },
+}
@@ -0,0 +1,260 @@
+//! # Response Action
+//!
+//! [slack api docs 🔗]
+//!
+//! The body your app can respond with to a [`view_submission`](super::ViewSubmission)
+//! payload, to show validation errors, update or push a modal,
+//! or close the whole view stack.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/surfaces/modals/using#modifying
+
+use std::{borrow::Cow,
+          collections::{BTreeMap, BTreeSet},
+          fmt};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+use crate::{blocks::Block, convert, surfaces::Modal};
+
+/// # Response Action
+///
+/// [slack api docs 🔗]
+///
+/// The body your app can respond with to a [`view_submission`](super::ViewSubmission)
+/// payload.
+///
+/// # Example
+/// ```
+/// use slack_blocks::{blocks::Input,
+///                    elems::TextInput,
+///                    interaction::response_action::{InputErrors, ResponseAction},
+///                    surfaces::Modal};
+///
+/// let view = Modal::builder().title("Sign up")
+///                            .submit("Go")
+///                            .block(Input::builder().label("Email")
+///                                                   .element(TextInput::builder().action_id("email")
+///                                                                                .build())
+///                                                   .block_id("email")
+///                                                   .build())
+///                            .build();
+///
+/// let errors = InputErrors::for_view(&view).error("email", "That doesn't look like an email")
+///                                          .unwrap();
+/// let response: ResponseAction = errors.into();
+///
+/// assert_eq!(serde_json::to_value(&response).unwrap(),
+///            serde_json::json!({
+///              "response_action": "errors",
+///              "errors": { "email": "That doesn't look like an email" }
+///            }));
+///
+/// assert!(InputErrors::for_view(&view).error("name", "Required").is_err());
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/surfaces/modals/using#modifying
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+pub enum ResponseAction<'a> {
+  /// Keep the modal open, and show error messages
+  /// under some of its Input blocks.
+  Errors(InputErrors<'a>),
+
+  /// Replace the submitted modal with a new one
+  Update {
+    /// The new modal
+    view: Modal<'a>,
+  },
+
+  /// Push a new modal onto the view stack
+  Push {
+    /// The new modal
+    view: Modal<'a>,
+  },
+
+  /// Close all modals in the view stack
+  Clear,
+}
+
+convert!(impl<'a> From<InputErrors<'a>> for ResponseAction<'a> => |e| ResponseAction::Errors(e));
+
+impl<'a> ResponseAction<'a> {
+  /// Validate that this response agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If this is `Errors` and the errors are invalid (see [`InputErrors::validate`])
+  /// - If this is `Update` or `Push` and the modal is invalid (see [`Modal::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Block,
+  ///                    interaction::response_action::ResponseAction,
+  ///                    surfaces::Modal};
+  ///
+  /// let view = Modal::builder().title("This title is way too long")
+  ///                            .block(Block::Divider)
+  ///                            .build();
+  ///
+  /// assert!(matches!(ResponseAction::Push { view }.validate(), Err(_)));
+  /// assert_eq!(ResponseAction::Clear.validate(), Ok(()));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    match self {
+      | ResponseAction::Errors(errors) => errors.validate(),
+      | ResponseAction::Update { view } | ResponseAction::Push { view } => {
+        view.validate()
+      },
+      | ResponseAction::Clear => Ok(()),
+    }
+  }
+}
+
+/// # Input Errors
+///
+/// Error messages to show under Input blocks of a submitted modal,
+/// keyed by `block_id`.
+///
+/// Built from the submitted modal with [`InputErrors::for_view`],
+/// so that each error can be checked against the `block_id`s
+/// of the modal's Input blocks.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_messages")))]
+pub struct InputErrors<'a> {
+  errors: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+
+  #[serde(skip)]
+  block_ids: BTreeSet<Cow<'a, str>>,
+}
+
+impl<'a> InputErrors<'a> {
+  /// Start building errors for a modal,
+  /// collecting the `block_id`s of its Input blocks.
+  pub fn for_view(view: &Modal<'_>) -> Self {
+    Self::for_blocks(&view.blocks)
+  }
+
+  /// Start building errors for these blocks,
+  /// collecting the `block_id`s of the Input blocks.
+  pub(crate) fn for_blocks(blocks: &[Block<'_>]) -> Self {
+    let block_ids = blocks.iter()
+                          .filter_map(|block| match block {
+                            | Block::Input(input) => input.block_id.as_deref(),
+                            | _ => None,
+                          })
+                          .map(|block_id| Cow::Owned(block_id.to_string()))
+                          .collect();
+
+    Self { errors: BTreeMap::new(),
+           block_ids }
+  }
+
+  /// Add an error message to show under the Input block `block_id`
+  ///
+  /// # Errors
+  /// If the modal has no Input block with this `block_id`.
+  pub fn error(mut self,
+               block_id: impl Into<Cow<'a, str>>,
+               message: impl Into<Cow<'a, str>>)
+               -> Result<Self, UnknownBlockId> {
+    let block_id = block_id.into();
+
+    if !self.block_ids.contains(&block_id) {
+      return Err(UnknownBlockId { block_id: block_id.into_owned() });
+    }
+
+    self.errors.insert(block_id, message.into());
+    Ok(self)
+  }
+
+  /// Whether no errors have been added
+  pub fn is_empty(&self) -> bool {
+    self.errors.is_empty()
+  }
+
+  /// The error messages, keyed by `block_id`
+  pub fn errors(&self) -> &BTreeMap<Cow<'a, str>, Cow<'a, str>> {
+    &self.errors
+  }
+
+  /// Validate that these errors agree with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If any message is empty or longer than 150 chars
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Input,
+  ///                    elems::TextInput,
+  ///                    interaction::response_action::InputErrors,
+  ///                    surfaces::Modal};
+  ///
+  /// let view = Modal::builder().title("Sign up")
+  ///                            .submit("Go")
+  ///                            .block(Input::builder().label("Email")
+  ///                                                   .element(TextInput::builder().action_id("email")
+  ///                                                                                .build())
+  ///                                                   .block_id("email")
+  ///                                                   .build())
+  ///                            .build();
+  ///
+  /// let errors = InputErrors::for_view(&view).error("email", "").unwrap();
+  ///
+  /// assert!(matches!(errors.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_messages(errors: &InputErrors) -> ValidatorResult {
+  for (block_id, message) in &errors.errors {
+    let len = message.chars().count();
+
+    if !(1..=150).contains(&len) {
+      return Err(error("InputErrors.errors",
+                       format!("error for block `{}` must be 1 to 150 chars, got {}",
+                               block_id, len)));
+    }
+  }
+
+  Ok(())
+}
+
+/// Error yielded by [`InputErrors::error`] when the submitted modal
+/// has no Input block with the given `block_id`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnknownBlockId {
+  block_id: String,
+}
+
+impl UnknownBlockId {
+  /// The `block_id` that wasn't found
+  pub fn block_id(&self) -> &str {
+    &self.block_id
+  }
+}
+
+impl fmt::Display for UnknownBlockId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f,
+           "the submitted view has no Input block with block_id `{}`",
+           self.block_id)
+  }
+}
+
+impl std::error::Error for UnknownBlockId {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
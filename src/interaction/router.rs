@@ -0,0 +1,383 @@
+//! # Router
+//!
+//! Dispatch interaction payloads to handlers registered by
+//! `callback_id`, `block_id` and `action_id`,
+//! instead of matching on id strings by hand.
+//!
+//! ```
+//! use slack_blocks::interaction::{router::{Pattern, Route, Router},
+//!                                 InteractionPayload};
+//!
+//! let router = Router::new()
+//!   .on_action("approve", |_payload, _action| "approved".to_string())
+//!   .on_action(Route::new().block_id(Pattern::prefix("ticket_"))
+//!                          .action_id(Pattern::glob("close_*")),
+//!              |_payload, action| format!("closed {}", action.block_id()))
+//!   .on_view_submission("new_ticket", |_submission| "created".to_string());
+//!
+//! let payload: InteractionPayload = serde_json::from_value(serde_json::json!({
+//!   "type": "block_actions",
+//!   "team": { "id": "T9TK3CUKW" },
+//!   "user": { "id": "UA8RXUSPL" },
+//!   "actions": [{
+//!     "type": "button",
+//!     "block_id": "ticket_42",
+//!     "action_id": "close_resolved",
+//!     "value": "x"
+//!   }]
+//! })).unwrap();
+//!
+//! assert_eq!(router.dispatch(&payload), Some("closed ticket_42".to_string()));
+//! ```
+
+use std::fmt;
+
+use super::{block_actions::Action,
+            BlockActions,
+            BlockSuggestion,
+            InteractionPayload,
+            MessageAction,
+            Shortcut,
+            ViewClosed,
+            ViewSubmission};
+
+/// A pattern that ids are matched against
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Pattern {
+  /// Matches any id
+  Any,
+
+  /// Matches an id equal to this string
+  Exact(String),
+
+  /// Matches an id starting with this string
+  Prefix(String),
+
+  /// Matches an id against a glob, where `*` matches any run of characters
+  /// and `?` matches any single character
+  Glob(String),
+}
+
+impl Pattern {
+  /// Match an id equal to `id`
+  pub fn exact(id: impl ToString) -> Self {
+    Pattern::Exact(id.to_string())
+  }
+
+  /// Match an id starting with `prefix`
+  pub fn prefix(prefix: impl ToString) -> Self {
+    Pattern::Prefix(prefix.to_string())
+  }
+
+  /// Match an id against a glob like `ticket_*_close`
+  pub fn glob(glob: impl ToString) -> Self {
+    Pattern::Glob(glob.to_string())
+  }
+
+  /// Whether `id` matches this pattern
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::interaction::router::Pattern;
+  ///
+  /// assert!(Pattern::glob("ticket_*_close").matches("ticket_42_close"));
+  /// assert!(Pattern::glob("page_?").matches("page_2"));
+  /// assert!(!Pattern::glob("page_?").matches("page_10"));
+  /// assert!(Pattern::prefix("ticket_").matches("ticket_42"));
+  /// ```
+  pub fn matches(&self, id: &str) -> bool {
+    match self {
+      | Pattern::Any => true,
+      | Pattern::Exact(exact) => exact == id,
+      | Pattern::Prefix(prefix) => id.starts_with(prefix.as_str()),
+      | Pattern::Glob(glob) => glob_matches(glob, id),
+    }
+  }
+
+  /// Whether an id that may be missing matches this pattern;
+  /// only [`Pattern::Any`] matches a missing id
+  fn matches_opt(&self, id: Option<&str>) -> bool {
+    match id {
+      | Some(id) => self.matches(id),
+      | None => *self == Pattern::Any,
+    }
+  }
+}
+
+/// Strings always become [`Pattern::Exact`], even if they contain `*` or `?`;
+/// use [`Pattern::glob`] or [`Pattern::prefix`] to match many ids.
+impl From<&str> for Pattern {
+  fn from(s: &str) -> Self {
+    Pattern::exact(s)
+  }
+}
+
+fn glob_matches(glob: &str, id: &str) -> bool {
+  let glob: Vec<char> = glob.chars().collect();
+  let id: Vec<char> = id.chars().collect();
+
+  let (mut g, mut i) = (0, 0);
+  let mut backtrack: Option<(usize, usize)> = None;
+
+  while i < id.len() {
+    match glob.get(g) {
+      | Some('*') => {
+        backtrack = Some((g, i));
+        g += 1;
+      },
+      | Some(&c) if c == '?' || c == id[i] => {
+        g += 1;
+        i += 1;
+      },
+      | _ => match backtrack {
+        | Some((star, matched)) => {
+          g = star + 1;
+          i = matched + 1;
+          backtrack = Some((star, matched + 1));
+        },
+        | None => return false,
+      },
+    }
+  }
+
+  glob[g..].iter().all(|&c| c == '*')
+}
+
+/// Which ids a block element handler applies to.
+///
+/// Ids that aren't set match anything.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Route {
+  callback_id: Option<Pattern>,
+  block_id: Option<Pattern>,
+  action_id: Option<Pattern>,
+}
+
+impl Route {
+  /// A route that matches every element
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only match elements in a view whose `callback_id` matches `pattern`
+  pub fn callback_id(mut self, pattern: impl Into<Pattern>) -> Self {
+    self.callback_id = Some(pattern.into());
+    self
+  }
+
+  /// Only match elements in a block whose `block_id` matches `pattern`
+  pub fn block_id(mut self, pattern: impl Into<Pattern>) -> Self {
+    self.block_id = Some(pattern.into());
+    self
+  }
+
+  /// Only match elements whose `action_id` matches `pattern`
+  pub fn action_id(mut self, pattern: impl Into<Pattern>) -> Self {
+    self.action_id = Some(pattern.into());
+    self
+  }
+
+  fn matches(&self,
+             callback_id: Option<&str>,
+             block_id: &str,
+             action_id: &str)
+             -> bool {
+    self.callback_id.iter().all(|p| p.matches_opt(callback_id))
+    && self.block_id.iter().all(|p| p.matches(block_id))
+    && self.action_id.iter().all(|p| p.matches(action_id))
+  }
+}
+
+/// A route matching `action_id`s against a [`Pattern`]
+impl From<&str> for Route {
+  fn from(action_id: &str) -> Self {
+    Route::new().action_id(action_id)
+  }
+}
+
+/// A route matching `action_id`s against a [`Pattern`]
+impl From<Pattern> for Route {
+  fn from(action_id: Pattern) -> Self {
+    Route::new().action_id(action_id)
+  }
+}
+
+type ActionHandler<'h, R> =
+  Box<dyn Fn(&BlockActions<'_>, &Action<'_>) -> R + 'h>;
+type SuggestionHandler<'h, R> = Box<dyn Fn(&BlockSuggestion<'_>) -> R + 'h>;
+type SubmissionHandler<'h, R> = Box<dyn Fn(&ViewSubmission<'_>) -> R + 'h>;
+type ClosedHandler<'h, R> = Box<dyn Fn(&ViewClosed<'_>) -> R + 'h>;
+type ShortcutHandler<'h, R> = Box<dyn Fn(&Shortcut<'_>) -> R + 'h>;
+type MessageActionHandler<'h, R> = Box<dyn Fn(&MessageAction<'_>) -> R + 'h>;
+
+/// # Router
+///
+/// Routes interaction payloads to handlers.
+///
+/// Handlers are tried in the order they were registered,
+/// and the first matching handler is called.
+///
+/// See the [module docs](self) for an example.
+pub struct Router<'h, R> {
+  actions: Vec<(Route, ActionHandler<'h, R>)>,
+  suggestions: Vec<(Route, SuggestionHandler<'h, R>)>,
+  submissions: Vec<(Pattern, SubmissionHandler<'h, R>)>,
+  closes: Vec<(Pattern, ClosedHandler<'h, R>)>,
+  shortcuts: Vec<(Pattern, ShortcutHandler<'h, R>)>,
+  message_actions: Vec<(Pattern, MessageActionHandler<'h, R>)>,
+}
+
+impl<'h, R> Router<'h, R> {
+  /// Create a router with no handlers
+  pub fn new() -> Self {
+    Self { actions: vec![],
+           suggestions: vec![],
+           submissions: vec![],
+           closes: vec![],
+           shortcuts: vec![],
+           message_actions: vec![] }
+  }
+
+  /// Handle interactions with block elements (`block_actions`) matching `route`
+  pub fn on_action(mut self,
+                   route: impl Into<Route>,
+                   handler: impl Fn(&BlockActions<'_>, &Action<'_>) -> R + 'h)
+                   -> Self {
+    self.actions.push((route.into(), Box::new(handler)));
+    self
+  }
+
+  /// Handle external select option requests (`block_suggestion`) matching `route`
+  pub fn on_suggestion(mut self,
+                       route: impl Into<Route>,
+                       handler: impl Fn(&BlockSuggestion<'_>) -> R + 'h)
+                       -> Self {
+    self.suggestions.push((route.into(), Box::new(handler)));
+    self
+  }
+
+  /// Handle submissions of modals whose `callback_id` matches `callback_id`
+  pub fn on_view_submission(mut self,
+                            callback_id: impl Into<Pattern>,
+                            handler: impl Fn(&ViewSubmission<'_>) -> R + 'h)
+                            -> Self {
+    self.submissions
+        .push((callback_id.into(), Box::new(handler)));
+    self
+  }
+
+  /// Handle modals being closed whose `callback_id` matches `callback_id`
+  pub fn on_view_closed(mut self,
+                        callback_id: impl Into<Pattern>,
+                        handler: impl Fn(&ViewClosed<'_>) -> R + 'h)
+                        -> Self {
+    self.closes.push((callback_id.into(), Box::new(handler)));
+    self
+  }
+
+  /// Handle global shortcuts whose `callback_id` matches `callback_id`
+  pub fn on_shortcut(mut self,
+                     callback_id: impl Into<Pattern>,
+                     handler: impl Fn(&Shortcut<'_>) -> R + 'h)
+                     -> Self {
+    self.shortcuts.push((callback_id.into(), Box::new(handler)));
+    self
+  }
+
+  /// Handle message shortcuts whose `callback_id` matches `callback_id`
+  pub fn on_message_action(mut self,
+                           callback_id: impl Into<Pattern>,
+                           handler: impl Fn(&MessageAction<'_>) -> R + 'h)
+                           -> Self {
+    self.message_actions
+        .push((callback_id.into(), Box::new(handler)));
+    self
+  }
+
+  /// Route a payload to the first matching handler,
+  /// returning the handler's output.
+  ///
+  /// A `block_actions` payload is routed by its first action
+  /// that matches a handler.
+  ///
+  /// Yields `None` if no handler matched.
+  pub fn dispatch(&self, payload: &InteractionPayload<'_>) -> Option<R> {
+    match payload {
+      | InteractionPayload::BlockActions(p) => {
+        let callback_id = p.view().and_then(|v| v.callback_id());
+
+        p.actions().iter().find_map(|action| {
+                            self.actions
+                                .iter()
+                                .find(|(route, _)| {
+                                  route.matches(callback_id,
+                                                action.block_id(),
+                                                action.action_id())
+                                })
+                                .map(|(_, handle)| handle(p, action))
+                          })
+      },
+      | InteractionPayload::BlockSuggestion(p) => {
+        let callback_id = p.view().and_then(|v| v.callback_id());
+
+        self.suggestions
+            .iter()
+            .find(|(route, _)| {
+              route.matches(callback_id, p.block_id(), p.action_id())
+            })
+            .map(|(_, handle)| handle(p))
+      },
+      | InteractionPayload::ViewSubmission(p) => {
+        find(&self.submissions, p.view().callback_id()).map(|handle| handle(p))
+      },
+      | InteractionPayload::ViewClosed(p) => {
+        find(&self.closes, p.view().callback_id()).map(|handle| handle(p))
+      },
+      | InteractionPayload::Shortcut(p) => {
+        find(&self.shortcuts, Some(p.callback_id())).map(|handle| handle(p))
+      },
+      | InteractionPayload::MessageAction(p) => {
+        find(&self.message_actions, Some(p.callback_id())).map(|handle| {
+                                                            handle(p)
+                                                          })
+      },
+      | InteractionPayload::Unknown => None,
+    }
+  }
+}
+
+fn find<'r, H>(handlers: &'r [(Pattern, H)],
+               callback_id: Option<&str>)
+               -> Option<&'r H> {
+  handlers.iter()
+          .find(|(pattern, _)| pattern.matches_opt(callback_id))
+          .map(|(_, handle)| handle)
+}
+
+impl<'h, R> Default for Router<'h, R> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'h, R> fmt::Debug for Router<'h, R> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Router")
+     .field("actions",
+            &self.actions.iter().map(|(r, _)| r).collect::<Vec<_>>())
+     .field("suggestions",
+            &self.suggestions.iter().map(|(r, _)| r).collect::<Vec<_>>())
+     .field("submissions",
+            &self.submissions.iter().map(|(p, _)| p).collect::<Vec<_>>())
+     .field("closes",
+            &self.closes.iter().map(|(p, _)| p).collect::<Vec<_>>())
+     .field("shortcuts",
+            &self.shortcuts.iter().map(|(p, _)| p).collect::<Vec<_>>())
+     .field("message_actions",
+            &self.message_actions
+                 .iter()
+                 .map(|(p, _)| p)
+                 .collect::<Vec<_>>())
+     .finish()
+  }
+}
@@ -0,0 +1,77 @@
+//! # Shortcut payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received when a user invokes one of your app's [global shortcuts 🔗]
+//! from the shortcuts menu or search bar.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/shortcuts#global
+//! [global shortcuts 🔗]: https://api.slack.com/interactivity/shortcuts/using#global_shortcuts
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Team, User};
+
+/// # Shortcut payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user invokes one of your app's [global shortcuts 🔗].
+///
+/// Global shortcuts aren't tied to a conversation,
+/// so the usual response is to open a modal with the `trigger_id`.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/shortcuts#global
+/// [global shortcuts 🔗]: https://api.slack.com/interactivity/shortcuts/using#global_shortcuts
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Shortcut<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  callback_id: Cow<'a, str>,
+
+  trigger_id: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  action_ts: Option<Cow<'a, str>>,
+}
+
+impl<'a> Shortcut<'a> {
+  /// The workspace the shortcut was invoked in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user who invoked the shortcut
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// The `callback_id` configured for the shortcut in your app's settings
+  pub fn callback_id(&self) -> &str {
+    &self.callback_id
+  }
+
+  /// A short-lived ID that can be used to [open modals 🔗]
+  ///
+  /// [open modals 🔗]: https://api.slack.com/surfaces/modals/using#opening_modals
+  pub fn trigger_id(&self) -> &str {
+    &self.trigger_id
+  }
+
+  /// When the shortcut was invoked
+  pub fn action_ts(&self) -> Option<&str> {
+    self.action_ts.as_deref()
+  }
+}
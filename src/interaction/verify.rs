@@ -0,0 +1,218 @@
+//! # Verifying requests from Slack
+//!
+//! [slack api docs 🔗]
+//!
+//! Slack signs every request it sends to your app with your app's
+//! signing secret, so you can confirm the request really came from Slack
+//! before trusting its payload.
+//!
+//! The signature is sent in the `X-Slack-Signature` header, and is the
+//! HMAC-SHA256 of the base string `v0:<timestamp>:<body>`, where `timestamp`
+//! is the value of the `X-Slack-Request-Timestamp` header and `body`
+//! is the raw, unparsed request body.
+//!
+//! To protect against replay attacks, requests whose timestamp is more than
+//! a few minutes away from the current time are rejected as well.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/authentication/verifying-requests-from-slack
+
+use std::{fmt,
+          time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// How far a request's timestamp may be from the current time
+/// before it is rejected, if not set with [`SigningSecret::max_age`].
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// The version prefix of signatures this module understands
+const VERSION: &str = "v0";
+
+/// # Signing Secret
+///
+/// Your Slack app's signing secret, used to verify incoming requests.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use slack_blocks::interaction::verify::{SigningSecret, VerifyError};
+///
+/// let secret = SigningSecret::new("8f742231b10e8888abcd99yyyzzz85a5");
+///
+/// let body = b"payload=%7B%22type%22%3A%22shortcut%22%7D";
+/// let timestamp = "1531420618";
+/// let signature = secret.sign(timestamp, body);
+///
+/// let now = UNIX_EPOCH + Duration::from_secs(1531420620);
+///
+/// assert_eq!(secret.verify_at(timestamp, &signature, body, now), Ok(()));
+/// assert_eq!(secret.verify_at(timestamp, &signature, b"payload=forged", now),
+///            Err(VerifyError::Mismatch));
+/// ```
+#[derive(Clone)]
+pub struct SigningSecret {
+  secret: Vec<u8>,
+  max_age: Duration,
+}
+
+impl fmt::Debug for SigningSecret {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SigningSecret")
+     .field("max_age", &self.max_age)
+     .finish_non_exhaustive()
+  }
+}
+
+impl SigningSecret {
+  /// Create a signing secret, found in your app's "Basic Information" settings
+  pub fn new(secret: impl AsRef<[u8]>) -> Self {
+    Self { secret: secret.as_ref().to_vec(),
+           max_age: DEFAULT_MAX_AGE }
+  }
+
+  /// Set how far a request's timestamp may be from the current time
+  /// before it is rejected.
+  ///
+  /// Defaults to [`DEFAULT_MAX_AGE`] (5 minutes).
+  pub fn max_age(mut self, max_age: Duration) -> Self {
+    self.max_age = max_age;
+    self
+  }
+
+  fn mac(&self, timestamp: &str, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+      .expect("HMAC accepts keys of any length");
+    mac.update(VERSION.as_bytes());
+    mac.update(b":");
+    mac.update(timestamp.as_bytes());
+    mac.update(b":");
+    mac.update(body);
+    mac
+  }
+
+  /// Compute the `X-Slack-Signature` header Slack would send
+  /// for a request with this timestamp and body.
+  ///
+  /// Useful for testing your endpoints.
+  pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+    let digest = self.mac(timestamp, body).finalize().into_bytes();
+
+    format!("{}={}", VERSION, hex::encode(digest))
+  }
+
+  /// Verify a request using the current system time
+  ///
+  /// - `timestamp` is the value of the `X-Slack-Request-Timestamp` header
+  /// - `signature` is the value of the `X-Slack-Signature` header
+  /// - `body` is the raw request body, before any parsing
+  ///
+  /// # Errors
+  /// See [`SigningSecret::verify_at`].
+  pub fn verify(&self,
+                timestamp: &str,
+                signature: &str,
+                body: &[u8])
+                -> Result<(), VerifyError> {
+    self.verify_at(timestamp, signature, body, SystemTime::now())
+  }
+
+  /// Verify a request, as if the current time were `now`
+  ///
+  /// Signatures are compared in constant time.
+  ///
+  /// # Errors
+  /// - [`VerifyError::InvalidTimestamp`] if `timestamp` is not a unix timestamp
+  /// - [`VerifyError::Expired`] if `timestamp` is further than `max_age` from `now`
+  /// - [`VerifyError::UnsupportedVersion`] if `signature` is not a `v0` signature
+  /// - [`VerifyError::MalformedSignature`] if `signature` is not a hex-encoded HMAC-SHA256 digest
+  /// - [`VerifyError::Mismatch`] if `signature` was not produced by this secret
+  ///   for this timestamp and body
+  pub fn verify_at(&self,
+                   timestamp: &str,
+                   signature: &str,
+                   body: &[u8],
+                   now: SystemTime)
+                   -> Result<(), VerifyError> {
+    let sent_at = timestamp.trim()
+                           .parse::<u64>()
+                           .map_err(|_| VerifyError::InvalidTimestamp)?;
+    let now = now.duration_since(UNIX_EPOCH)
+                 .map(|d| d.as_secs())
+                 .unwrap_or(0);
+
+    if now.max(sent_at) - now.min(sent_at) > self.max_age.as_secs() {
+      return Err(VerifyError::Expired { timestamp: sent_at,
+                                        now });
+    }
+
+    let digest = match signature.trim().split_once('=') {
+      | Some((VERSION, digest)) => digest,
+      | Some(_) => return Err(VerifyError::UnsupportedVersion),
+      | None => return Err(VerifyError::MalformedSignature),
+    };
+
+    let digest =
+      hex::decode(digest).map_err(|_| VerifyError::MalformedSignature)?;
+
+    self.mac(timestamp, body)
+        .verify_slice(&digest)
+        .map_err(|_| VerifyError::Mismatch)
+  }
+}
+
+/// Error yielded when a request could not be verified as coming from Slack
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VerifyError {
+  /// The `X-Slack-Request-Timestamp` header is not a unix timestamp
+  InvalidTimestamp,
+
+  /// The request's timestamp is too far from the current time,
+  /// and may be a replay of an old request
+  Expired {
+    /// The request's timestamp, in seconds since the unix epoch
+    timestamp: u64,
+    /// The current time, in seconds since the unix epoch
+    now: u64,
+  },
+
+  /// The `X-Slack-Signature` header uses a version other than `v0`
+  UnsupportedVersion,
+
+  /// The `X-Slack-Signature` header is not of the form `v0=<hex digest>`
+  MalformedSignature,
+
+  /// The signature does not match the request
+  Mismatch,
+}
+
+impl fmt::Display for VerifyError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | VerifyError::InvalidTimestamp => {
+        write!(f, "request timestamp is not a unix timestamp")
+      },
+      | VerifyError::Expired { timestamp, now } => {
+        write!(f,
+               "request timestamp {} is too far from the current time {}",
+               timestamp, now)
+      },
+      | VerifyError::UnsupportedVersion => {
+        write!(f, "request signature version is not supported")
+      },
+      | VerifyError::MalformedSignature => {
+        write!(f, "request signature is malformed")
+      },
+      | VerifyError::Mismatch => {
+        write!(f, "request signature does not match")
+      },
+    }
+  }
+}
+
+impl std::error::Error for VerifyError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
@@ -0,0 +1,66 @@
+//! # View Closed payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received when a user dismisses a modal
+//! that was opened with `notify_on_close` set.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/views#view_closed
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Team, User};
+use crate::surfaces::Modal;
+
+/// # View Closed payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user dismisses a modal
+/// that was opened with `notify_on_close` set.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/views#view_closed
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ViewClosed<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  view: Modal<'a>,
+
+  #[serde(default)]
+  is_cleared: bool,
+}
+
+impl<'a> ViewClosed<'a> {
+  /// The workspace the modal was closed in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user who closed the modal
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// The modal that was closed
+  pub fn view(&self) -> &Modal<'a> {
+    &self.view
+  }
+
+  /// Whether the whole view stack was cleared,
+  /// rather than just the topmost modal being closed
+  pub fn is_cleared(&self) -> bool {
+    self.is_cleared
+  }
+}
@@ -0,0 +1,846 @@
+//! # View State
+//!
+//! [slack api docs 🔗]
+//!
+//! The values of the input elements in a modal,
+//! keyed by `block_id` and then `action_id`.
+//!
+//! Received in the `view.state` of a [`view_submission` 🔗] payload,
+//! as well as in `block_actions` payloads originating from a modal.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#fields
+//! [`view_submission` 🔗]: https://api.slack.com/reference/interaction-payloads/views#view_submission
+
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use super::response_action::InputErrors;
+use crate::{blocks::Block,
+            compose::{Opt, OptGroup},
+            elems::BlockElement};
+
+/// # View State
+///
+/// [slack api docs 🔗]
+///
+/// The values of the input elements in a modal,
+/// keyed by `block_id` and then `action_id`.
+///
+/// Instead of digging through `values[block_id][action_id]`,
+/// use the typed getters (e.g. [`ViewState::text`]) which yield
+/// a [`ViewStateError`] naming the missing or mismatched key.
+///
+/// # Example
+/// ```
+/// use slack_blocks::interaction::ViewState;
+///
+/// let state: ViewState = serde_json::from_value(serde_json::json!({
+///   "values": {
+///     "name": {
+///       "name_input": { "type": "plain_text_input", "value": "Jack" }
+///     },
+///     "due": {
+///       "due_picker": { "type": "datepicker", "selected_date": "2021-04-28" }
+///     }
+///   }
+/// })).unwrap();
+///
+/// assert_eq!(state.text("name", "name_input"), Ok(Some("Jack")));
+/// assert_eq!(state.selected_date("due", "due_picker"), Ok(Some("2021-04-28")));
+///
+/// let err = state.text("due", "due_picker").unwrap_err();
+/// assert_eq!(err.to_string(),
+///            "expected `values.due.due_picker` to be a plain_text_input, found datepicker");
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#fields
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ViewState<'a> {
+  #[serde(default)]
+  values: BTreeMap<Cow<'a, str>, BTreeMap<Cow<'a, str>, StateValue<'a>>>,
+}
+
+impl<'a> ViewState<'a> {
+  /// All values, keyed by `block_id` and then `action_id`.
+  pub fn values(
+    &self)
+    -> &BTreeMap<Cow<'a, str>, BTreeMap<Cow<'a, str>, StateValue<'a>>> {
+    &self.values
+  }
+
+  /// Get the value of the element `action_id` in the block `block_id`.
+  ///
+  /// # Errors
+  /// - [`ViewStateError::MissingBlock`] if there is no block `block_id`
+  /// - [`ViewStateError::MissingAction`] if the block has no element `action_id`
+  pub fn get(&self,
+             block_id: &str,
+             action_id: &str)
+             -> Result<&StateValue<'a>, ViewStateError> {
+    self.values
+        .get(block_id)
+        .ok_or_else(|| ViewStateError::MissingBlock { block_id:
+                                                        block_id.to_string() })?
+        .get(action_id)
+        .ok_or_else(|| ViewStateError::MissingAction { block_id:
+                                                         block_id.to_string(),
+                                                       action_id:
+                                                         action_id.to_string() })
+  }
+
+  /// Get the text entered into a [`TextInput`](crate::elems::TextInput).
+  ///
+  /// Yields `None` if the input was left empty.
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `plain_text_input`.
+  pub fn text(&self,
+              block_id: &str,
+              action_id: &str)
+              -> Result<Option<&str>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::TextInput { value } => Ok(value.as_deref()),
+      | other => Err(other.mismatch(block_id, action_id, "plain_text_input")),
+    }
+  }
+
+  /// Get the option chosen in a [`Radio`](crate::elems::Radio) button group,
+  /// a [static select menu](crate::elems::select::Static)
+  /// or an [external select menu](crate::elems::select::External).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not one of those elements.
+  pub fn selected_option(&self,
+                         block_id: &str,
+                         action_id: &str)
+                         -> Result<Option<&Opt<'a>>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::RadioButtons { selected_option }
+      | StateValue::SelectStatic { selected_option }
+      | StateValue::SelectExternal { selected_option } => {
+        Ok(selected_option.as_ref())
+      },
+      | other => {
+        Err(other.mismatch(block_id,
+                           action_id,
+                           "radio_buttons, static_select or external_select"))
+      },
+    }
+  }
+
+  /// Get the options chosen in a [`Checkboxes`](crate::elems::Checkboxes) group,
+  /// a [multi static select menu](crate::elems::select::multi::Static)
+  /// or a [multi external select menu](crate::elems::select::multi::External).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not one of those elements.
+  pub fn selected_options(&self,
+                          block_id: &str,
+                          action_id: &str)
+                          -> Result<&[Opt<'a>], ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::Checkboxes { selected_options }
+      | StateValue::MultiSelectStatic { selected_options }
+      | StateValue::MultiSelectExternal { selected_options } => {
+        Ok(selected_options)
+      },
+      | other => Err(other.mismatch(block_id,
+                                    action_id,
+                                    "checkboxes, multi_static_select or multi_external_select")),
+    }
+  }
+
+  /// Get the date picked in a [`DatePicker`](crate::elems::DatePicker),
+  /// formatted `YYYY-MM-DD`.
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `datepicker`.
+  pub fn selected_date(&self,
+                       block_id: &str,
+                       action_id: &str)
+                       -> Result<Option<&str>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::DatePicker { selected_date } => {
+        Ok(selected_date.as_deref())
+      },
+      | other => Err(other.mismatch(block_id, action_id, "datepicker")),
+    }
+  }
+
+  /// Get the ID of the user chosen in a [user select menu](crate::elems::select::User).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `users_select`.
+  pub fn selected_user(&self,
+                       block_id: &str,
+                       action_id: &str)
+                       -> Result<Option<&str>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::SelectUser { selected_user } => {
+        Ok(selected_user.as_deref())
+      },
+      | other => Err(other.mismatch(block_id, action_id, "users_select")),
+    }
+  }
+
+  /// Get the IDs of the users chosen in a [multi user select menu](crate::elems::select::multi::User).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `multi_users_select`.
+  pub fn selected_users(&self,
+                        block_id: &str,
+                        action_id: &str)
+                        -> Result<&[Cow<'a, str>], ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::MultiSelectUser { selected_users } => Ok(selected_users),
+      | other => Err(other.mismatch(block_id, action_id, "multi_users_select")),
+    }
+  }
+
+  /// Get the ID of the conversation chosen in a [conversation select menu](crate::elems::select::Conversation).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `conversations_select`.
+  pub fn selected_conversation(&self,
+                               block_id: &str,
+                               action_id: &str)
+                               -> Result<Option<&str>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::SelectConversation { selected_conversation, } => {
+        Ok(selected_conversation.as_deref())
+      },
+      | other => {
+        Err(other.mismatch(block_id, action_id, "conversations_select"))
+      },
+    }
+  }
+
+  /// Get the IDs of the conversations chosen in a [multi conversation select menu](crate::elems::select::multi::Conversation).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `multi_conversations_select`.
+  pub fn selected_conversations(&self,
+                                block_id: &str,
+                                action_id: &str)
+                                -> Result<&[Cow<'a, str>], ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::MultiSelectConversation { selected_conversations, } => {
+        Ok(selected_conversations)
+      },
+      | other => {
+        Err(other.mismatch(block_id, action_id, "multi_conversations_select"))
+      },
+    }
+  }
+
+  /// Get the ID of the channel chosen in a [public channel select menu](crate::elems::select::PublicChannel).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `channels_select`.
+  pub fn selected_channel(&self,
+                          block_id: &str,
+                          action_id: &str)
+                          -> Result<Option<&str>, ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::SelectPublicChannel { selected_channel } => {
+        Ok(selected_channel.as_deref())
+      },
+      | other => Err(other.mismatch(block_id, action_id, "channels_select")),
+    }
+  }
+
+  /// Get the IDs of the channels chosen in a [multi public channel select menu](crate::elems::select::multi::PublicChannel).
+  ///
+  /// # Errors
+  /// If the element is missing, or is not a `multi_channels_select`.
+  pub fn selected_channels(&self,
+                           block_id: &str,
+                           action_id: &str)
+                           -> Result<&[Cow<'a, str>], ViewStateError> {
+    match self.get(block_id, action_id)? {
+      | StateValue::MultiSelectPublicChannel { selected_channels } => {
+        Ok(selected_channels)
+      },
+      | other => {
+        Err(other.mismatch(block_id, action_id, "multi_channels_select"))
+      },
+    }
+  }
+
+  /// Populate the initial value of every element in `blocks`
+  /// with the value the user entered, so that a modal can be re-rendered
+  /// (e.g. with `response_action: update`) without losing their input.
+  ///
+  /// Elements are matched by the `block_id` of their block and their `action_id`,
+  /// so only blocks with an explicit `block_id` are restored.
+  /// Elements with no value in this state are left as-is.
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Input,
+  ///                    elems::TextInput,
+  ///                    interaction::ViewState};
+  ///
+  /// let blocks = vec![Input::builder().label("Name")
+  ///                                   .block_id("name")
+  ///                                   .element(TextInput::builder().action_id("name")
+  ///                                                                .build())
+  ///                                   .build()];
+  ///
+  /// let state: ViewState = serde_json::from_value(serde_json::json!({
+  ///   "values": {
+  ///     "name": { "name": { "type": "plain_text_input", "value": "Jack" } }
+  ///   }
+  /// })).unwrap();
+  ///
+  /// let restored = state.restore(blocks);
+  ///
+  /// assert_eq!(serde_json::to_value(&restored).unwrap()[0]["element"]["initial_value"],
+  ///            "Jack");
+  /// ```
+  pub fn restore<'b, I>(&self, blocks: I) -> Vec<Block<'b>>
+    where I: IntoIterator,
+          I::Item: Into<Block<'b>>
+  {
+    blocks.into_iter()
+          .map(Into::into)
+          .map(|mut block| {
+            self.restore_block(&mut block);
+            block
+          })
+          .collect()
+  }
+
+  /// Check the values in this state against the definitions of the elements
+  /// in `blocks`, which should be the blocks your app sent to Slack
+  /// (not the blocks of the submitted view, which come from the client).
+  ///
+  /// Slack's clients enforce elements' constraints before submitting,
+  /// but a forged payload may not. This checks the value of each Input block's element:
+  /// - that Input blocks not marked `optional` have a non-empty value
+  /// - text inputs' `min_length` and `max_length`, unless left blank in an `optional` Input
+  /// - that selected options are among the element's `options` or `option_groups`
+  /// - multi-selects' `max_selected_items`
+  /// - that the value came from the same kind of element
+  ///
+  /// Input blocks without a `block_id` are **not** checked: Slack keys their
+  /// values by ids it generates, so they can't be matched to the blocks in
+  /// `blocks`. Give every Input block whose value you rely on a `block_id`.
+  ///
+  /// # Errors
+  /// An error message for each Input block with a value that breaks its
+  /// element's constraints, to respond to the `view_submission` with.
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Input,
+  ///                    elems::TextInput,
+  ///                    interaction::{ResponseAction, ViewState}};
+  ///
+  /// let blocks = vec![Input::builder().label("Name")
+  ///                                   .block_id("name")
+  ///                                   .element(TextInput::builder().action_id("name")
+  ///                                                                .length(..=10)
+  ///                                                                .build())
+  ///                                   .build()
+  ///                                   .into()];
+  ///
+  /// let state: ViewState = serde_json::from_value(serde_json::json!({
+  ///   "values": {
+  ///     "name": { "name": { "type": "plain_text_input", "value": "Jack Jackson Jr." } }
+  ///   }
+  /// })).unwrap();
+  ///
+  /// let errors = state.check(&blocks).unwrap_err();
+  ///
+  /// assert_eq!(serde_json::to_value(&ResponseAction::from(errors)).unwrap(),
+  ///            serde_json::json!({
+  ///              "response_action": "errors",
+  ///              "errors": { "name": "Must be at most 10 characters" }
+  ///            }));
+  /// ```
+  pub fn check(&self,
+               blocks: &[Block<'_>])
+               -> Result<(), InputErrors<'static>> {
+    let errors =
+      blocks.iter()
+            .filter_map(|block| match block {
+              | Block::Input(input) => {
+                input.block_id.as_deref().map(|id| (id, input))
+              },
+              | _ => None,
+            })
+            .filter_map(|(block_id, input)| {
+              let element = &input.element.0;
+              let optional = input.optional == Some(true);
+              let value =
+                element.action_id()
+                       .and_then(|id| self.values.get(block_id)?.get(id));
+
+              let msg = match value {
+                | Some(value) if optional || !value.is_empty() => {
+                  check_element(element, value)
+                },
+                | _ if optional => None,
+                | _ => Some(REQUIRED.to_string()),
+              }?;
+
+              Some((block_id.to_string(), msg))
+            })
+            .fold(InputErrors::for_blocks(blocks),
+                  |errors, (block_id, msg)| {
+                    errors.error(block_id, msg)
+                          .expect("block_id is of an Input block in `blocks`")
+                  });
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  fn restore_block(&self, block: &mut Block<'_>) {
+    let (block_id, elements): (_, Vec<&mut BlockElement>) = match block {
+      | Block::Input(input) => {
+        (input.block_id.as_deref(), vec![&mut input.element.0])
+      },
+      | Block::Actions(actions) => {
+        (actions.block_id.as_deref(),
+         actions.elements.iter_mut().map(|el| &mut el.0).collect())
+      },
+      | Block::Section(section) => {
+        (section.block_id.as_deref(), section.accessory.iter_mut().collect())
+      },
+      | _ => return,
+    };
+
+    let actions = match block_id.and_then(|id| self.values.get(id)) {
+      | Some(actions) => actions,
+      | None => return,
+    };
+
+    for element in elements {
+      if let Some(value) = element.action_id().and_then(|id| actions.get(id)) {
+        restore_element(element, value);
+      }
+    }
+  }
+}
+
+/// Check `value` against the definition of `element`,
+/// yielding an error message if it breaks any of its constraints.
+fn check_element(element: &BlockElement<'_>,
+                 value: &StateValue<'_>)
+                 -> Option<String> {
+  use BlockElement as El;
+  use StateValue as Val;
+
+  fn all_among<'o, T: 'o, U: 'o, S, U2>(selected: &[Opt<'_, S, U2>],
+                                        options: impl Iterator<Item = &'o Opt<'o, T, U>>
+                                          + Clone)
+                                        -> Option<String> {
+    if selected.iter()
+               .all(|s| options.clone().any(|opt| opt.value() == s.value()))
+    {
+      None
+    } else {
+      Some(NOT_AN_OPTION.to_string())
+    }
+  }
+
+  fn at_most(max: Option<u32>, selected: usize) -> Option<String> {
+    max.filter(|&max| selected > max as usize).map(|max| {
+                                                match max {
+         | 1 => "Select at most 1 item".to_string(),
+         | max => format!("Select at most {} items", max),
+       }
+                                              })
+  }
+
+  match (element, value) {
+    | (El::TextInput(el), Val::TextInput { value }) => {
+      let len = value.as_deref().map(|v| v.chars().count()).unwrap_or(0);
+
+      // Only optional inputs get here when blank, and those can be left blank
+      match (el.min_length, el.max_length) {
+        | _ if len == 0 => None,
+        | (Some(min), _) if len < min as usize => {
+          Some(format!("Must be at least {} characters", min))
+        },
+        | (_, Some(max)) if len > max as usize => {
+          Some(format!("Must be at most {} characters", max))
+        },
+        | _ => None,
+      }
+    },
+    | (El::Checkboxes(el), Val::Checkboxes { selected_options }) => {
+      all_among(selected_options, el.options.iter())
+    },
+    | (El::RadioButtons(el), Val::RadioButtons { selected_option }) => {
+      all_among(selected_option.as_slice(), el.options.iter())
+    },
+    | (El::SelectStatic(el), Val::SelectStatic { selected_option }) => {
+      all_among(selected_option.as_slice(),
+                static_options(&el.options, &el.option_groups))
+    },
+    | (El::MultiSelectStatic(el), Val::MultiSelectStatic { selected_options }) => {
+      all_among(selected_options, static_options(&el.options, &el.option_groups))
+        .or_else(|| at_most(el.max_selected_items, selected_options.len()))
+    },
+    | (El::MultiSelectExternal(el),
+       Val::MultiSelectExternal { selected_options }) => {
+      at_most(el.max_selected_items, selected_options.len())
+    },
+    | (El::MultiSelectUser(el), Val::MultiSelectUser { selected_users }) => {
+      at_most(el.max_selected_items, selected_users.len())
+    },
+    | (El::MultiSelectConversation(el),
+       Val::MultiSelectConversation { selected_conversations }) => {
+      at_most(el.max_selected_items, selected_conversations.len())
+    },
+    | (El::MultiSelectPublicChannel(el),
+       Val::MultiSelectPublicChannel { selected_channels }) => {
+      at_most(el.max_selected_items, selected_channels.len())
+    },
+    | (El::DatePicker(_), Val::DatePicker { .. })
+    | (El::SelectExternal(_), Val::SelectExternal { .. })
+    | (El::SelectUser(_), Val::SelectUser { .. })
+    | (El::SelectConversation(_), Val::SelectConversation { .. })
+    | (El::SelectPublicChannel(_), Val::SelectPublicChannel { .. }) => None,
+    | _ => Some("Unexpected value".to_string()),
+  }
+}
+
+/// Error message for a selected option that isn't one of the element's options
+const NOT_AN_OPTION: &str = "Select one of the options given";
+const REQUIRED: &str = "This field is required";
+
+/// All options of a static select, including those in option groups
+fn static_options<'o, T, U>(
+  options: &'o Option<Vec<Opt<'o, T, U>>>,
+  groups: &'o Option<Vec<OptGroup<'o, T, U>>>)
+  -> impl Iterator<Item = &'o Opt<'o, T, U>> + Clone {
+  options.iter().flatten().chain(groups.iter()
+                                       .flatten()
+                                       .flat_map(|group| group.options.iter()))
+}
+
+/// Set the initial value of `element` to `value`,
+/// if `value` came from the same kind of element.
+fn restore_element(element: &mut BlockElement<'_>, value: &StateValue<'_>) {
+  use BlockElement as El;
+  use StateValue as Val;
+
+  fn owned<'b>(s: &Cow<'_, str>) -> Cow<'b, str> {
+    Cow::Owned(s.to_string())
+  }
+
+  fn some_if_any<T>(items: Vec<T>) -> Option<Vec<T>> {
+    Some(items).filter(|items| !items.is_empty())
+  }
+
+  match (element, value) {
+    | (El::TextInput(el), Val::TextInput { value }) => {
+      el.initial_value = value.as_ref().map(owned);
+    },
+    | (El::Checkboxes(el), Val::Checkboxes { selected_options }) => {
+      el.initial_options =
+        some_if_any(selected_options.iter().map(Opt::cast).collect()).map(Cow::Owned);
+    },
+    | (El::RadioButtons(el), Val::RadioButtons { selected_option }) => {
+      el.initial_option = selected_option.as_ref().map(Opt::cast);
+    },
+    | (El::DatePicker(el), Val::DatePicker { selected_date }) => {
+      el.initial_date = selected_date.as_ref().map(|date| date.to_string());
+    },
+    | (El::SelectStatic(el), Val::SelectStatic { selected_option }) => {
+      el.initial_option = selected_option.as_ref().map(|o| o.cast().into());
+    },
+    | (El::SelectExternal(el), Val::SelectExternal { selected_option }) => {
+      el.initial_option = selected_option.as_ref().map(|o| o.cast().into());
+    },
+    | (El::SelectUser(el), Val::SelectUser { selected_user }) => {
+      el.initial_user = selected_user.as_ref().map(owned);
+    },
+    | (El::SelectConversation(el),
+       Val::SelectConversation { selected_conversation, }) => {
+      el.initial_channel = selected_conversation.as_ref().map(owned);
+    },
+    | (El::SelectPublicChannel(el),
+       Val::SelectPublicChannel { selected_channel }) => {
+      el.initial_channel = selected_channel.as_ref().map(owned);
+    },
+    | (El::MultiSelectStatic(el),
+       Val::MultiSelectStatic { selected_options }) => {
+      el.initial_options =
+        some_if_any(selected_options.iter().map(|o| o.cast().into()).collect())
+          .map(Cow::Owned);
+    },
+    | (El::MultiSelectExternal(el),
+       Val::MultiSelectExternal { selected_options }) => {
+      el.initial_options =
+        some_if_any(selected_options.iter().map(|o| o.cast().into()).collect())
+          .map(Cow::Owned);
+    },
+    | (El::MultiSelectUser(el), Val::MultiSelectUser { selected_users }) => {
+      el.initial_users =
+        some_if_any(selected_users.iter().map(owned).collect());
+    },
+    | (El::MultiSelectConversation(el),
+       Val::MultiSelectConversation { selected_conversations, }) => {
+      el.initial_channels =
+        some_if_any(selected_conversations.iter()
+                                          .map(|c| c.to_string())
+                                          .collect()).map(Cow::Owned);
+    },
+    | (El::MultiSelectPublicChannel(el),
+       Val::MultiSelectPublicChannel { selected_channels }) => {
+      el.initial_channels =
+        some_if_any(selected_channels.iter().map(|c| c.to_string()).collect())
+          .map(Cow::Owned);
+    },
+    | _ => (),
+  }
+}
+
+/// # State Value
+///
+/// The value of a single input element in a [`ViewState`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StateValue<'a> {
+  /// The value of a [`TextInput`](crate::elems::TextInput)
+  #[serde(rename = "plain_text_input")]
+  TextInput {
+    /// The entered text, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<Cow<'a, str>>,
+  },
+
+  /// The value of a [`Checkboxes`](crate::elems::Checkboxes) group
+  Checkboxes {
+    /// All checked options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// The value of a [`Radio`](crate::elems::Radio) button group
+  RadioButtons {
+    /// The chosen option, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// The value of a [`DatePicker`](crate::elems::DatePicker)
+  #[serde(rename = "datepicker")]
+  DatePicker {
+    /// The picked date formatted `YYYY-MM-DD`, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_date: Option<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::Static`](crate::elems::select::Static) menu
+  #[serde(rename = "static_select")]
+  SelectStatic {
+    /// The chosen option, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// The value of a [`select::External`](crate::elems::select::External) menu
+  #[serde(rename = "external_select")]
+  SelectExternal {
+    /// The chosen option, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_option: Option<Opt<'a>>,
+  },
+
+  /// The value of a [`select::User`](crate::elems::select::User) menu
+  #[serde(rename = "users_select")]
+  SelectUser {
+    /// The ID of the chosen user, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_user: Option<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::Conversation`](crate::elems::select::Conversation) menu
+  #[serde(rename = "conversations_select")]
+  SelectConversation {
+    /// The ID of the chosen conversation, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_conversation: Option<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::PublicChannel`](crate::elems::select::PublicChannel) menu
+  #[serde(rename = "channels_select")]
+  SelectPublicChannel {
+    /// The ID of the chosen channel, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_channel: Option<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::multi::Static`](crate::elems::select::multi::Static) menu
+  #[serde(rename = "multi_static_select")]
+  MultiSelectStatic {
+    /// All chosen options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// The value of a [`select::multi::External`](crate::elems::select::multi::External) menu
+  #[serde(rename = "multi_external_select")]
+  MultiSelectExternal {
+    /// All chosen options
+    #[serde(default)]
+    selected_options: Vec<Opt<'a>>,
+  },
+
+  /// The value of a [`select::multi::User`](crate::elems::select::multi::User) menu
+  #[serde(rename = "multi_users_select")]
+  MultiSelectUser {
+    /// The IDs of all chosen users
+    #[serde(default)]
+    selected_users: Vec<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::multi::Conversation`](crate::elems::select::multi::Conversation) menu
+  #[serde(rename = "multi_conversations_select")]
+  MultiSelectConversation {
+    /// The IDs of all chosen conversations
+    #[serde(default)]
+    selected_conversations: Vec<Cow<'a, str>>,
+  },
+
+  /// The value of a [`select::multi::PublicChannel`](crate::elems::select::multi::PublicChannel) menu
+  #[serde(rename = "multi_channels_select")]
+  MultiSelectPublicChannel {
+    /// The IDs of all chosen channels
+    #[serde(default)]
+    selected_channels: Vec<Cow<'a, str>>,
+  },
+
+  /// An element this crate doesn't model yet
+  #[serde(other)]
+  Unknown,
+}
+
+impl<'a> StateValue<'a> {
+  /// The `type` of element this value came from, e.g. `"plain_text_input"`
+  pub fn kind(&self) -> &'static str {
+    use StateValue::*;
+
+    match self {
+      | TextInput { .. } => "plain_text_input",
+      | Checkboxes { .. } => "checkboxes",
+      | RadioButtons { .. } => "radio_buttons",
+      | DatePicker { .. } => "datepicker",
+      | SelectStatic { .. } => "static_select",
+      | SelectExternal { .. } => "external_select",
+      | SelectUser { .. } => "users_select",
+      | SelectConversation { .. } => "conversations_select",
+      | SelectPublicChannel { .. } => "channels_select",
+      | MultiSelectStatic { .. } => "multi_static_select",
+      | MultiSelectExternal { .. } => "multi_external_select",
+      | MultiSelectUser { .. } => "multi_users_select",
+      | MultiSelectConversation { .. } => "multi_conversations_select",
+      | MultiSelectPublicChannel { .. } => "multi_channels_select",
+      | Unknown => "unknown",
+    }
+  }
+
+  /// Whether the user left this element blank
+  fn is_empty(&self) -> bool {
+    use StateValue::*;
+
+    match self {
+      | TextInput { value } => value.as_deref().map_or(true, str::is_empty),
+      | Checkboxes { selected_options }
+      | MultiSelectStatic { selected_options }
+      | MultiSelectExternal { selected_options } => selected_options.is_empty(),
+      | RadioButtons { selected_option }
+      | SelectStatic { selected_option }
+      | SelectExternal { selected_option } => selected_option.is_none(),
+      | DatePicker { selected_date: id }
+      | SelectUser { selected_user: id }
+      | SelectConversation { selected_conversation: id, }
+      | SelectPublicChannel { selected_channel: id, } => id.is_none(),
+      | MultiSelectUser { selected_users: ids, }
+      | MultiSelectConversation { selected_conversations: ids, }
+      | MultiSelectPublicChannel { selected_channels: ids, } => ids.is_empty(),
+      | Unknown => false,
+    }
+  }
+
+  fn mismatch(&self,
+              block_id: &str,
+              action_id: &str,
+              expected: &'static str)
+              -> ViewStateError {
+    ViewStateError::Mismatched { block_id: block_id.to_string(),
+                                 action_id: action_id.to_string(),
+                                 expected,
+                                 found: self.kind() }
+  }
+}
+
+/// Error yielded by the typed getters on [`ViewState`]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ViewStateError {
+  /// There is no block with this `block_id` in the state
+  MissingBlock {
+    /// The `block_id` that was looked up
+    block_id: String,
+  },
+
+  /// The block has no element with this `action_id`
+  MissingAction {
+    /// The `block_id` that was looked up
+    block_id: String,
+    /// The `action_id` that was looked up
+    action_id: String,
+  },
+
+  /// The element is not the kind that was asked for
+  Mismatched {
+    /// The `block_id` that was looked up
+    block_id: String,
+    /// The `action_id` that was looked up
+    action_id: String,
+    /// The kind(s) of element that were expected
+    expected: &'static str,
+    /// The kind of element that was found
+    found: &'static str,
+  },
+}
+
+impl fmt::Display for ViewStateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    use ViewStateError::*;
+
+    match self {
+      | MissingBlock { block_id } => {
+        write!(f, "no block `{}` in view state values", block_id)
+      },
+      | MissingAction { block_id,
+                        action_id, } => {
+        write!(f, "no element `{}` in block `{}`", action_id, block_id)
+      },
+      | Mismatched { block_id,
+                     action_id,
+                     expected,
+                     found, } => {
+        write!(f,
+               "expected `values.{}.{}` to be a {}, found {}",
+               block_id, action_id, expected, found)
+      },
+    }
+  }
+}
+
+impl std::error::Error for ViewStateError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
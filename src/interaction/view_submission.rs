@@ -0,0 +1,139 @@
+//! # View Submission payload
+//!
+//! [slack api docs 🔗]
+//!
+//! Received when a user submits a modal.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/views#view_submission
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Team, User, ViewState};
+use crate::surfaces::Modal;
+
+/// # View Submission payload
+///
+/// [slack api docs 🔗]
+///
+/// Received when a user submits a modal.
+///
+/// The values the user entered can be read from [`ViewSubmission::state`].
+///
+/// # Example
+/// ```
+/// use slack_blocks::interaction::ViewSubmission;
+///
+/// let payload: ViewSubmission = serde_json::from_value(serde_json::json!({
+///   "type": "view_submission",
+///   "team": { "id": "T9TK3CUKW", "domain": "example" },
+///   "user": { "id": "UA8RXUSPL" },
+///   "view": {
+///     "id": "VNHU13V36",
+///     "type": "modal",
+///     "title": { "type": "plain_text", "text": "Feedback" },
+///     "blocks": [],
+///     "state": {
+///       "values": {
+///         "feedback": {
+///           "feedback_input": { "type": "plain_text_input", "value": "Great!" }
+///         }
+///       }
+///     }
+///   }
+/// })).unwrap();
+///
+/// let state = payload.state().unwrap();
+/// assert_eq!(state.text("feedback", "feedback_input"), Ok(Some("Great!")));
+/// ```
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/interaction-payloads/views#view_submission
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ViewSubmission<'a> {
+  team: Team<'a>,
+
+  user: User<'a>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  api_app_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  trigger_id: Option<Cow<'a, str>>,
+
+  view: Modal<'a>,
+
+  #[serde(default)]
+  response_urls: Vec<ResponseUrl<'a>>,
+}
+
+impl<'a> ViewSubmission<'a> {
+  /// The workspace the modal was submitted in
+  pub fn team(&self) -> &Team<'a> {
+    &self.team
+  }
+
+  /// The user who submitted the modal
+  pub fn user(&self) -> &User<'a> {
+    &self.user
+  }
+
+  /// The ID of your app
+  pub fn api_app_id(&self) -> Option<&str> {
+    self.api_app_id.as_deref()
+  }
+
+  /// A short-lived ID that can be used to [open modals 🔗]
+  ///
+  /// [open modals 🔗]: https://api.slack.com/surfaces/modals/using#opening_modals
+  pub fn trigger_id(&self) -> Option<&str> {
+    self.trigger_id.as_deref()
+  }
+
+  /// The modal that was submitted
+  pub fn view(&self) -> &Modal<'a> {
+    &self.view
+  }
+
+  /// The values the user entered into the modal's input elements
+  pub fn state(&self) -> Option<&ViewState<'a>> {
+    self.view.state()
+  }
+
+  /// The `response_url`s generated for any conversation select menus
+  /// with `response_url_enabled` in the modal
+  pub fn response_urls(&self) -> &[ResponseUrl<'a>] {
+    &self.response_urls
+  }
+}
+
+/// A `response_url` generated for a conversation select menu in a modal
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct ResponseUrl<'a> {
+  block_id: Cow<'a, str>,
+  action_id: Cow<'a, str>,
+  channel_id: Cow<'a, str>,
+  response_url: Cow<'a, str>,
+}
+
+impl<'a> ResponseUrl<'a> {
+  /// The `block_id` of the select menu's block
+  pub fn block_id(&self) -> &str {
+    &self.block_id
+  }
+
+  /// The `action_id` of the select menu
+  pub fn action_id(&self) -> &str {
+    &self.action_id
+  }
+
+  /// The conversation chosen in the select menu
+  pub fn channel_id(&self) -> &str {
+    &self.channel_id
+  }
+
+  /// The URL that can be used to post a message to the conversation
+  pub fn response_url(&self) -> &str {
+    &self.response_url
+  }
+}
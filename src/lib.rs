@@ -91,9 +91,13 @@ extern crate validator_derive;
 #[cfg_attr(docsrs, doc(cfg(feature = "blox")))]
 pub mod blox;
 
+pub mod api;
 pub mod blocks;
 pub mod compose;
 pub mod elems;
+pub mod form;
+pub mod interaction;
+pub mod surfaces;
 
 mod build;
 #[cfg(feature = "validation")]
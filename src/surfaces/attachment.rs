@@ -0,0 +1,596 @@
+//! # Secondary Message Attachment
+//!
+//! [slack api docs 🔗]
+//!
+//! Attachments let you add secondary content to a message,
+//! displayed below the message's main content with a colored bar along the side.
+//!
+//! Attachments are a legacy feature, and Slack recommends using [layout blocks 🔗]
+//! in the message itself instead - but they're still the only way to get that colored bar.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/messaging/attachments
+//! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use crate::blocks::Block;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Secondary Message Attachment
+///
+/// [slack api docs 🔗]
+///
+/// Attachments let you add secondary content to a message,
+/// displayed below the message's main content with a colored bar along the side.
+///
+/// An attachment can contain up to 50 [layout blocks 🔗],
+/// alongside (or instead of) the legacy attachment fields.
+///
+/// Attach one to a message with [`MessageBuilder::attachment`](super::message::build::MessageBuilder::attachment).
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/messaging/attachments
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Attachment<'a> {
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  #[cfg_attr(feature = "validation", validate(length(max = 50)))]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::blocks"))]
+  #[cfg_attr(feature = "validation", validate)]
+  blocks: Vec<Block<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::color"))]
+  color: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  fallback: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pretext: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  author_name: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  author_link: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  author_icon: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  title: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  title_link: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  text: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  fields: Vec<Field<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  image_url: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  thumb_url: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  footer: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  footer_icon: Option<Cow<'a, str>>,
+
+  #[serde(default,
+          skip_serializing_if = "Option::is_none",
+          deserialize_with = "ts::deserialize")]
+  ts: Option<i64>,
+
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  mrkdwn_in: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Attachment<'a> {
+  /// Build a new Attachment.
+  ///
+  /// For example, see docs for [`build::AttachmentBuilder`].
+  pub fn builder() -> build::AttachmentBuilderInit<'a> {
+    build::AttachmentBuilderInit::new()
+  }
+
+  /// Validate that this Attachment agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `blocks` contains more than 50 blocks
+  /// - If `blocks` can't be used in an attachment (see [`Block::validate_for`])
+  /// - If `color` is not `good`, `warning`, `danger` or a hex color code like `#439FE0`
+  /// - If any block in `blocks` is invalid (see [`Block::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::surfaces::Attachment;
+  ///
+  /// let att = Attachment::builder().text("Disk usage is high")
+  ///                                .color("orange")
+  ///                                .build();
+  ///
+  /// assert!(matches!(att.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// # Attachment Field
+///
+/// A legacy attachment field, displayed as a table inside the attachment.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Field<'a> {
+  title: Cow<'a, str>,
+  value: Cow<'a, str>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  short: Option<bool>,
+}
+
+impl<'a> Field<'a> {
+  /// Create a new attachment field
+  ///
+  /// # Arguments
+  /// - `title` - Shown as a bold heading displayed in the field object.
+  /// - `value` - The text value displayed in the field object.
+  ///   Can be formatted as plain text, or with [`mrkdwn` 🔗]
+  ///   if `"fields"` is included in the attachment's `mrkdwn_in`.
+  ///
+  /// [`mrkdwn` 🔗]: https://api.slack.com/reference/surfaces/formatting#basics
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::surfaces::attachment::Field;
+  ///
+  /// let field = Field::new("Priority", "High").with_short(true);
+  /// ```
+  pub fn new(title: impl Into<Cow<'a, str>>,
+             value: impl Into<Cow<'a, str>>)
+             -> Self {
+    Self { title: title.into(),
+           value: value.into(),
+           short: None }
+  }
+
+  /// Sets the `short` flag
+  ///
+  /// Indicates whether the field is short enough
+  /// to be displayed side-by-side with other fields.
+  pub fn with_short(mut self, short: bool) -> Self {
+    self.short = Some(short);
+    self
+  }
+}
+
+mod ts {
+  use serde::{de::Error, Deserialize, Deserializer};
+
+  /// Slack sends `ts` as an integer, a float, or a string like `"1623956385.000200"`
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Ts {
+    Int(i64),
+    Float(f64),
+    Str(String),
+  }
+
+  pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D)
+    -> Result<Option<i64>, D::Error> {
+    Option::<Ts>::deserialize(deserializer)?.map(|ts| match ts {
+                                              | Ts::Int(ts) => Ok(ts),
+                                              | Ts::Float(ts) => Ok(ts as i64),
+                                              | Ts::Str(ts) => {
+                                                ts.parse::<f64>()
+                                                  .map(|ts| ts as i64)
+                                                  .map_err(D::Error::custom)
+                                              },
+                                            })
+                                            .transpose()
+  }
+}
+
+/// Attachment builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// AttachmentBuilder.block, AttachmentBuilder.blocks,
+    /// AttachmentBuilder.text or AttachmentBuilder.fallback
+    #[derive(Clone, Copy, Debug)]
+    pub struct contents;
+  }
+
+  /// Initial state for `AttachmentBuilder`
+  pub type AttachmentBuilderInit<'a> =
+    AttachmentBuilder<'a, RequiredMethodNotCalled<method::contents>>;
+
+  /// Build an Attachment
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `AttachmentBuilder::build()` is only available if one of these methods has been called:
+  ///  - `block` or `blocks`
+  ///  - `text`
+  ///  - `fallback`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Section,
+  ///                    surfaces::{attachment::Field, Attachment, Message},
+  ///                    text::ToSlackMarkdown};
+  ///
+  /// let alert =
+  ///   Attachment::builder().color("danger")
+  ///                        .fallback("CPU usage above 90% on web-1")
+  ///                        .block(Section::builder().text("*CPU usage above 90%* on `web-1`".markdown())
+  ///                                                 .build())
+  ///                        .field(Field::new("Host", "web-1").with_short(true))
+  ///                        .footer("alertbot")
+  ///                        .ts(1623956385)
+  ///                        .build();
+  ///
+  /// let msg = Message::builder().attachment(alert).build();
+  /// ```
+  #[derive(Debug)]
+  pub struct AttachmentBuilder<'a, Contents> {
+    blocks: Vec<Block<'a>>,
+    color: Option<Cow<'a, str>>,
+    fallback: Option<Cow<'a, str>>,
+    pretext: Option<Cow<'a, str>>,
+    author_name: Option<Cow<'a, str>>,
+    author_link: Option<Cow<'a, str>>,
+    author_icon: Option<Cow<'a, str>>,
+    title: Option<Cow<'a, str>>,
+    title_link: Option<Cow<'a, str>>,
+    text: Option<Cow<'a, str>>,
+    fields: Vec<Field<'a>>,
+    image_url: Option<Cow<'a, str>>,
+    thumb_url: Option<Cow<'a, str>>,
+    footer: Option<Cow<'a, str>>,
+    footer_icon: Option<Cow<'a, str>>,
+    ts: Option<i64>,
+    mrkdwn_in: Vec<Cow<'a, str>>,
+    state: PhantomData<Contents>,
+  }
+
+  impl<'a, C> AttachmentBuilder<'a, C> {
+    /// Create a new AttachmentBuilder
+    pub fn new() -> Self {
+      Self { blocks: vec![],
+             color: None,
+             fallback: None,
+             pretext: None,
+             author_name: None,
+             author_link: None,
+             author_icon: None,
+             title: None,
+             title_link: None,
+             text: None,
+             fields: vec![],
+             image_url: None,
+             thumb_url: None,
+             footer: None,
+             footer_icon: None,
+             ts: None,
+             mrkdwn_in: vec![],
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type param to some other arbitrary marker type param
+    fn cast_state<C2>(self) -> AttachmentBuilder<'a, C2> {
+      AttachmentBuilder { blocks: self.blocks,
+                          color: self.color,
+                          fallback: self.fallback,
+                          pretext: self.pretext,
+                          author_name: self.author_name,
+                          author_link: self.author_link,
+                          author_icon: self.author_icon,
+                          title: self.title,
+                          title_link: self.title_link,
+                          text: self.text,
+                          fields: self.fields,
+                          image_url: self.image_url,
+                          thumb_url: self.thumb_url,
+                          footer: self.footer,
+                          footer_icon: self.footer_icon,
+                          ts: self.ts,
+                          mrkdwn_in: self.mrkdwn_in,
+                          state: PhantomData::<_> }
+    }
+
+    /// Add a `block` (this, `text` or `fallback` is **Required**, can be called many times)
+    ///
+    /// A [layout block 🔗] to display in the attachment.
+    ///
+    /// There is a maximum of 50 blocks in each attachment.
+    ///
+    /// [layout block 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn block(mut self,
+                 block: impl Into<Block<'a>>)
+                 -> AttachmentBuilder<'a, Set<method::contents>> {
+      self.blocks.push(block.into());
+      self.cast_state()
+    }
+
+    /// Set `blocks` (this, `text` or `fallback` is **Required**)
+    ///
+    /// Replaces any blocks added so far with the [layout blocks 🔗] in `blocks`.
+    ///
+    /// There is a maximum of 50 blocks in each attachment.
+    ///
+    /// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn blocks<I>(mut self,
+                     blocks: I)
+                     -> AttachmentBuilder<'a, Set<method::contents>>
+      where I: IntoIterator,
+            I::Item: Into<Block<'a>>
+    {
+      self.blocks = blocks.into_iter().map(Into::into).collect();
+      self.cast_state()
+    }
+
+    /// Set `text` (this, `block`/`blocks` or `fallback` is **Required**)
+    ///
+    /// The main body text of the attachment, displayed below
+    /// the `title` and above any `fields`.
+    ///
+    /// Formatted as plain text, or with [`mrkdwn` 🔗]
+    /// if `"text"` is included in `mrkdwn_in`.
+    ///
+    /// [`mrkdwn` 🔗]: https://api.slack.com/reference/surfaces/formatting#basics
+    pub fn text(mut self,
+                text: impl Into<Cow<'a, str>>)
+                -> AttachmentBuilder<'a, Set<method::contents>> {
+      self.text = Some(text.into());
+      self.cast_state()
+    }
+
+    /// Set `fallback` (this, `block`/`blocks` or `text` is **Required**)
+    ///
+    /// A plain text summary of the attachment,
+    /// used in clients that don't show formatted text (e.g. notifications).
+    pub fn fallback(mut self,
+                    fallback: impl Into<Cow<'a, str>>)
+                    -> AttachmentBuilder<'a, Set<method::contents>> {
+      self.fallback = Some(fallback.into());
+      self.cast_state()
+    }
+
+    /// Set `color` (Optional)
+    ///
+    /// Changes the color of the border on the left side of this attachment.
+    ///
+    /// Can either be one of `good` (green), `warning` (yellow) or `danger` (red),
+    /// or any hex color code (e.g. `#439FE0`).
+    pub fn color(mut self, color: impl Into<Cow<'a, str>>) -> Self {
+      self.color = Some(color.into());
+      self
+    }
+
+    /// Set `pretext` (Optional)
+    ///
+    /// Text that appears above the attachment.
+    pub fn pretext(mut self, pretext: impl Into<Cow<'a, str>>) -> Self {
+      self.pretext = Some(pretext.into());
+      self
+    }
+
+    /// Set `author_name` (Optional)
+    ///
+    /// Small text used to display the author's name.
+    pub fn author_name(mut self, author_name: impl Into<Cow<'a, str>>) -> Self {
+      self.author_name = Some(author_name.into());
+      self
+    }
+
+    /// Set `author_link` (Optional)
+    ///
+    /// A valid URL that will hyperlink the `author_name` text.
+    ///
+    /// Will only work if `author_name` is present.
+    pub fn author_link(mut self, author_link: impl Into<Cow<'a, str>>) -> Self {
+      self.author_link = Some(author_link.into());
+      self
+    }
+
+    /// Set `author_icon` (Optional)
+    ///
+    /// A valid URL that displays a small 16px by 16px image
+    /// to the left of the `author_name` text.
+    ///
+    /// Will only work if `author_name` is present.
+    pub fn author_icon(mut self, author_icon: impl Into<Cow<'a, str>>) -> Self {
+      self.author_icon = Some(author_icon.into());
+      self
+    }
+
+    /// Set `title` (Optional)
+    ///
+    /// Large title text near the top of the attachment.
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+      self.title = Some(title.into());
+      self
+    }
+
+    /// Set `title_link` (Optional)
+    ///
+    /// A valid URL that turns the `title` text into a hyperlink.
+    pub fn title_link(mut self, title_link: impl Into<Cow<'a, str>>) -> Self {
+      self.title_link = Some(title_link.into());
+      self
+    }
+
+    /// Add a `field` (Optional, can be called many times)
+    ///
+    /// Fields are displayed in a table inside the attachment.
+    pub fn field(mut self, field: Field<'a>) -> Self {
+      self.fields.push(field);
+      self
+    }
+
+    /// Set `fields` (Optional)
+    ///
+    /// Replaces any fields added so far with `fields`.
+    pub fn fields(mut self,
+                  fields: impl IntoIterator<Item = Field<'a>>)
+                  -> Self {
+      self.fields = fields.into_iter().collect();
+      self
+    }
+
+    /// Set `image_url` (Optional)
+    ///
+    /// A valid URL to an image file that will be displayed
+    /// at the bottom of the attachment.
+    pub fn image_url(mut self, image_url: impl Into<Cow<'a, str>>) -> Self {
+      self.image_url = Some(image_url.into());
+      self
+    }
+
+    /// Set `thumb_url` (Optional)
+    ///
+    /// A valid URL to an image file that will be displayed
+    /// as a thumbnail on the right side of the attachment.
+    pub fn thumb_url(mut self, thumb_url: impl Into<Cow<'a, str>>) -> Self {
+      self.thumb_url = Some(thumb_url.into());
+      self
+    }
+
+    /// Set `footer` (Optional)
+    ///
+    /// Some brief text to help contextualize and identify the attachment.
+    ///
+    /// Limited to 300 characters, and may be truncated further when displayed
+    /// to users in environments with limited screen real estate.
+    pub fn footer(mut self, footer: impl Into<Cow<'a, str>>) -> Self {
+      self.footer = Some(footer.into());
+      self
+    }
+
+    /// Set `footer_icon` (Optional)
+    ///
+    /// A valid URL to an image file that will be displayed
+    /// beside the `footer` text.
+    ///
+    /// Will only work if `footer` is present.
+    pub fn footer_icon(mut self, footer_icon: impl Into<Cow<'a, str>>) -> Self {
+      self.footer_icon = Some(footer_icon.into());
+      self
+    }
+
+    /// Set `ts` (Optional)
+    ///
+    /// A Unix timestamp (in seconds) that is used to relate your attachment
+    /// to a specific time, displayed in the attachment footer.
+    pub fn ts(mut self, ts: i64) -> Self {
+      self.ts = Some(ts);
+      self
+    }
+
+    /// Set `mrkdwn_in` (Optional)
+    ///
+    /// The names of the legacy fields that should be formatted with [`mrkdwn` 🔗],
+    /// e.g. `["text", "pretext", "fields"]`.
+    ///
+    /// [`mrkdwn` 🔗]: https://api.slack.com/reference/surfaces/formatting#basics
+    pub fn mrkdwn_in<I>(mut self, fields: I) -> Self
+      where I: IntoIterator,
+            I::Item: Into<Cow<'a, str>>
+    {
+      self.mrkdwn_in = fields.into_iter().map(Into::into).collect();
+      self
+    }
+  }
+
+  impl<'a> AttachmentBuilder<'a, Set<method::contents>> {
+    /// All done building, now give me a darn attachment!
+    ///
+    /// > `no method name 'build' found for struct 'AttachmentBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `AttachmentBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::surfaces::Attachment;
+    ///
+    /// let foo = Attachment::builder().color("good").build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::surfaces::Attachment;
+    ///
+    /// let att = Attachment::builder().color("good")
+    ///                                .text("All systems operational")
+    ///                                .build();
+    /// ```
+    pub fn build(self) -> Attachment<'a> {
+      Attachment { blocks: self.blocks,
+                   color: self.color,
+                   fallback: self.fallback,
+                   pretext: self.pretext,
+                   author_name: self.author_name,
+                   author_link: self.author_link,
+                   author_icon: self.author_icon,
+                   title: self.title,
+                   title_link: self.title_link,
+                   text: self.text,
+                   fields: self.fields,
+                   image_url: self.image_url,
+                   thumb_url: self.thumb_url,
+                   footer: self.footer,
+                   footer_icon: self.footer_icon,
+                   ts: self.ts,
+                   mrkdwn_in: self.mrkdwn_in }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::{surfaces::{Surface, ValidateFor},
+              val_helpr::{error, ValidatorResult}};
+
+  pub(super) fn blocks(blocks: &[Block]) -> ValidatorResult {
+    blocks.validate_for(Surface::Attachment)
+          .map_err(|e| error("Attachment.blocks", e.to_string()))
+  }
+
+  pub(super) fn color(color: &Cow<str>) -> ValidatorResult {
+    let is_hex = |c: &str| {
+      c.strip_prefix('#')
+       .filter(|hex| hex.len() == 6)
+       .map(|hex| hex.chars().all(|ch| ch.is_ascii_hexdigit()))
+       .unwrap_or(false)
+    };
+
+    match color.as_ref() {
+      | "good" | "warning" | "danger" => Ok(()),
+      | c if is_hex(c) => Ok(()),
+      | c => Err(error("Attachment.color",
+                       format!("color must be `good`, `warning`, `danger` \
+                                or a hex color code, got {:?}",
+                               c))),
+    }
+  }
+}
@@ -0,0 +1,401 @@
+//! # Home Tab
+//!
+//! [slack api docs 🔗]
+//!
+//! The Home tab is a persistent, yet dynamic interface for apps
+//! that lives within the App Home.
+//!
+//! A home tab is the `view` payload sent with [`views.publish` 🔗],
+//! and can contain up to 100 [layout blocks 🔗].
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#home
+//! [`views.publish` 🔗]: https://api.slack.com/methods/views.publish
+//! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+
+use std::borrow::Cow;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Metadata;
+#[cfg(feature = "signing")]
+use crate::compose::{signing::{self, SigningKey},
+                     value};
+#[cfg(feature = "validation")]
+use crate::val_helpr::{ValidationResult, ValidatorResult};
+use crate::{blocks::Block, compose::value::ValueError};
+
+/// # Home Tab
+///
+/// [slack api docs 🔗]
+///
+/// The Home tab is a persistent, yet dynamic interface for apps
+/// that lives within the App Home.
+///
+/// A home tab is the `view` payload sent with [`views.publish` 🔗],
+/// and can contain up to 100 [layout blocks 🔗].
+///
+/// Views returned by Slack (e.g. in interaction payloads or API responses)
+/// carry some extra fields like `id` and `hash`, which can be accessed
+/// on a deserialized `HomeTab` but are never serialized.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#home
+/// [`views.publish` 🔗]: https://api.slack.com/methods/views.publish
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[serde(tag = "type", rename = "home")]
+pub struct HomeTab<'a> {
+  #[cfg_attr(feature = "validation", validate(length(max = 100)))]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::blocks"))]
+  #[cfg_attr(feature = "validation", validate)]
+  blocks: Vec<Block<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::private_metadata"))]
+  private_metadata: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::callback_id"))]
+  callback_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::external_id"))]
+  external_id: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing)]
+  id: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing)]
+  hash: Option<Cow<'a, str>>,
+}
+
+impl<'a> HomeTab<'a> {
+  /// Build a new HomeTab.
+  ///
+  /// For example, see docs for [`build::HomeTabBuilder`].
+  pub fn builder() -> build::HomeTabBuilderInit<'a> {
+    build::HomeTabBuilderInit::new()
+  }
+
+  /// The unique id of this view, assigned by Slack.
+  ///
+  /// Only present on views received from Slack.
+  pub fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
+
+  /// A unique value that changes each time this view is updated.
+  ///
+  /// Pass it to `views.publish` to protect against race conditions.
+  ///
+  /// Only present on views received from Slack.
+  pub fn hash(&self) -> Option<&str> {
+    self.hash.as_deref()
+  }
+
+  /// The `callback_id` this view was built with, if any.
+  pub fn callback_id(&self) -> Option<&str> {
+    self.callback_id.as_deref()
+  }
+
+  /// The `private_metadata` this view was built with, if any.
+  pub fn private_metadata(&self) -> Option<&str> {
+    self.private_metadata.as_deref()
+  }
+
+  /// Decode `private_metadata` set with
+  /// [`HomeTabBuilder::typed_private_metadata`](build::HomeTabBuilder::typed_private_metadata)
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this view has no `private_metadata`
+  /// - [`ValueError::Deserialize`] if the metadata is not the encoding of a `T`
+  pub fn typed_private_metadata<T: DeserializeOwned>(
+    &self)
+    -> Result<T, ValueError> {
+    self.private_metadata()
+        .ok_or(ValueError::Missing)
+        .and_then(Metadata::decode)
+        .map(Metadata::into_inner)
+  }
+
+  /// Verify and decode `private_metadata` set with
+  /// [`HomeTabBuilder::signed_private_metadata`](build::HomeTabBuilder::signed_private_metadata)
+  /// for the view `callback_id`
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this view has no `private_metadata`
+  /// - [`ValueError::BadSignature`] if the metadata was not sealed by `key` for `callback_id`
+  /// - [`ValueError::Deserialize`] if the metadata is not the JSON encoding of a `T`
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn verified_private_metadata<T: DeserializeOwned>(
+    &self,
+    key: &SigningKey,
+    callback_id: &str)
+    -> Result<T, ValueError> {
+    let context = signing::private_metadata_context(callback_id);
+
+    self.private_metadata()
+        .ok_or(ValueError::Missing)
+        .and_then(|meta| key.open_typed(&context, meta))
+  }
+
+  /// Validate that this HomeTab agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `blocks` contains more than 100 blocks
+  /// - If `private_metadata` longer than 3000 chars
+  /// - If `callback_id` longer than 255 chars
+  /// - If `external_id` longer than 255 chars
+  /// - If `blocks` can't be used in a home tab (see [`Block::validate_for`])
+  /// - If any block in `blocks` is invalid (see [`Block::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Block, surfaces::HomeTab};
+  ///
+  /// let home = HomeTab::builder().blocks((0..101).map(|_| Block::Divider))
+  ///                              .build();
+  ///
+  /// assert!(matches!(home.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// HomeTab builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// HomeTabBuilder.block or HomeTabBuilder.blocks
+    #[derive(Clone, Copy, Debug)]
+    pub struct blocks;
+  }
+
+  /// Initial state for `HomeTabBuilder`
+  pub type HomeTabBuilderInit<'a> =
+    HomeTabBuilder<'a, RequiredMethodNotCalled<method::blocks>>;
+
+  /// Build a HomeTab
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `HomeTabBuilder::build()` is only available if these methods have been called:
+  ///  - `block` or `blocks`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::{Actions, Header, Section},
+  ///                    elems::Button,
+  ///                    surfaces::HomeTab,
+  ///                    text::ToSlackMarkdown};
+  ///
+  /// let refresh = Button::builder().text("Refresh")
+  ///                                .action_id("refresh")
+  ///                                .build();
+  ///
+  /// let home =
+  ///   HomeTab::builder().block(Header::builder().text("Your tasks").build())
+  ///                     .block(Section::builder().text("*3* tasks due today".markdown())
+  ///                                              .build())
+  ///                     .block(Actions::builder().element(refresh).build())
+  ///                     .callback_id("home")
+  ///                     .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct HomeTabBuilder<'a, Blocks> {
+    blocks: Vec<Block<'a>>,
+    private_metadata: Option<Cow<'a, str>>,
+    callback_id: Option<Cow<'a, str>>,
+    external_id: Option<Cow<'a, str>>,
+    state: PhantomData<Blocks>,
+  }
+
+  impl<'a, B> HomeTabBuilder<'a, B> {
+    /// Create a new HomeTabBuilder
+    pub fn new() -> Self {
+      Self { blocks: vec![],
+             private_metadata: None,
+             callback_id: None,
+             external_id: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type param to some other arbitrary marker type param
+    fn cast_state<B2>(self) -> HomeTabBuilder<'a, B2> {
+      HomeTabBuilder { blocks: self.blocks,
+                       private_metadata: self.private_metadata,
+                       callback_id: self.callback_id,
+                       external_id: self.external_id,
+                       state: PhantomData::<_> }
+    }
+
+    /// Add a `block` (**Required**, can be called many times)
+    ///
+    /// A [layout block 🔗] to display in the home tab.
+    ///
+    /// There is a maximum of 100 blocks in each home tab.
+    ///
+    /// [layout block 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn block(mut self,
+                 block: impl Into<Block<'a>>)
+                 -> HomeTabBuilder<'a, Set<method::blocks>> {
+      self.blocks.push(block.into());
+      self.cast_state()
+    }
+
+    /// Set `blocks` (**Required**)
+    ///
+    /// Replaces any blocks added so far with the [layout blocks 🔗] in `blocks`.
+    ///
+    /// There is a maximum of 100 blocks in each home tab.
+    ///
+    /// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn blocks<I>(mut self,
+                     blocks: I)
+                     -> HomeTabBuilder<'a, Set<method::blocks>>
+      where I: IntoIterator,
+            I::Item: Into<Block<'a>>
+    {
+      self.blocks = blocks.into_iter().map(Into::into).collect();
+      self.cast_state()
+    }
+
+    /// Set `private_metadata` (Optional)
+    ///
+    /// A string that will be sent to your app in `block_actions` events.
+    ///
+    /// Maximum length of 3000 characters.
+    pub fn private_metadata(mut self,
+                            private_metadata: impl Into<Cow<'a, str>>)
+                            -> Self {
+      self.private_metadata = Some(private_metadata.into());
+      self
+    }
+
+    /// Set `private_metadata` to the encoding of a typed [`Metadata`] (Optional)
+    ///
+    /// Decode it with [`HomeTab::typed_private_metadata`].
+    ///
+    /// # Errors
+    /// If the metadata can't be serialized, or is longer than 3000 characters once encoded.
+    pub fn typed_private_metadata<M>(self,
+                                     metadata: &Metadata<M>)
+                                     -> Result<Self, ValueError>
+      where M: Serialize
+    {
+      metadata.encode().map(|meta| self.private_metadata(meta))
+    }
+
+    /// Set `private_metadata` to the compact JSON encoding of `metadata`,
+    /// sealed with `key` for the view `callback_id` (Optional)
+    ///
+    /// Verify and decode it with [`HomeTab::verified_private_metadata`].
+    ///
+    /// # Errors
+    /// If `metadata` can't be serialized, or is longer than 3000 characters once sealed.
+    #[cfg(feature = "signing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+    pub fn signed_private_metadata<M>(self,
+                                      key: &SigningKey,
+                                      callback_id: &str,
+                                      metadata: &M)
+                                      -> Result<Self, ValueError>
+      where M: Serialize + ?Sized
+    {
+      key.seal_typed(&signing::private_metadata_context(callback_id),
+                     metadata,
+                     value::PRIVATE_METADATA_MAX_LEN)
+         .map(|meta| self.private_metadata(meta))
+    }
+
+    /// Set `callback_id` (Optional)
+    ///
+    /// An identifier to recognize interactions and submissions of this particular view.
+    ///
+    /// Don't use this to store sensitive information (use `private_metadata` instead).
+    ///
+    /// Maximum length of 255 characters.
+    pub fn callback_id(mut self, callback_id: impl Into<Cow<'a, str>>) -> Self {
+      self.callback_id = Some(callback_id.into());
+      self
+    }
+
+    /// Set `external_id` (Optional)
+    ///
+    /// A custom identifier that must be unique for all views on a per-team basis.
+    ///
+    /// Maximum length of 255 characters.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+      self.external_id = Some(external_id.into());
+      self
+    }
+  }
+
+  impl<'a> HomeTabBuilder<'a, Set<method::blocks>> {
+    /// All done building, now give me a darn home tab!
+    ///
+    /// > `no method name 'build' found for struct 'HomeTabBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `HomeTabBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::surfaces::HomeTab;
+    ///
+    /// let foo = HomeTab::builder().build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{blocks::Block, surfaces::HomeTab};
+    ///
+    /// let home = HomeTab::builder().block(Block::Divider).build();
+    /// ```
+    pub fn build(self) -> HomeTab<'a> {
+      HomeTab { blocks: self.blocks,
+                private_metadata: self.private_metadata,
+                callback_id: self.callback_id,
+                external_id: self.external_id,
+                id: None,
+                hash: None }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::{surfaces::{Surface, ValidateFor},
+              val_helpr::{error, len}};
+
+  pub(super) fn blocks(blocks: &[Block]) -> ValidatorResult {
+    blocks.validate_for(Surface::HomeTab)
+          .map_err(|e| error("HomeTab.blocks", e.to_string()))
+  }
+
+  pub(super) fn private_metadata(meta: &Cow<str>) -> ValidatorResult {
+    len("HomeTab.private_metadata", ..=3000, meta)
+  }
+
+  pub(super) fn callback_id(id: &Cow<str>) -> ValidatorResult {
+    len("HomeTab.callback_id", ..=255, id)
+  }
+
+  pub(super) fn external_id(id: &Cow<str>) -> ValidatorResult {
+    len("HomeTab.external_id", ..=255, id)
+  }
+}
@@ -0,0 +1,368 @@
+//! # Message
+//!
+//! [slack api docs 🔗]
+//!
+//! App-published messages are dynamic yet transient spaces.
+//!
+//! They allow users to complete workflows among their Slack conversations,
+//! and can contain up to 50 [layout blocks 🔗].
+//!
+//! When a message contains blocks, its `text` is used as a fallback
+//! in notifications and in clients that can't render blocks.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/surfaces/messages
+//! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Attachment;
+use crate::blocks::Block;
+#[cfg(feature = "validation")]
+use crate::val_helpr::ValidationResult;
+
+/// # Message
+///
+/// [slack api docs 🔗]
+///
+/// App-published messages are dynamic yet transient spaces.
+///
+/// They allow users to complete workflows among their Slack conversations,
+/// and can contain up to 50 [layout blocks 🔗].
+///
+/// When a message contains blocks, its `text` is used as a fallback
+/// in notifications and in clients that can't render blocks.
+///
+/// [slack api docs 🔗]: https://api.slack.com/surfaces/messages
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+pub struct Message<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  text: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  #[cfg_attr(feature = "validation", validate(length(max = 50)))]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::blocks"))]
+  #[cfg_attr(feature = "validation", validate)]
+  blocks: Vec<Block<'a>>,
+
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  #[cfg_attr(feature = "validation", validate(length(max = 100)))]
+  #[cfg_attr(feature = "validation", validate)]
+  attachments: Vec<Attachment<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) thread_ts: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) reply_broadcast: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  mrkdwn: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  unfurl_links: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  unfurl_media: Option<bool>,
+}
+
+impl<'a> Message<'a> {
+  /// Build a new Message.
+  ///
+  /// For example, see docs for [`build::MessageBuilder`].
+  pub fn builder() -> build::MessageBuilderInit<'a> {
+    build::MessageBuilderInit::new()
+  }
+
+  /// Validate that this Message agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `blocks` contains more than 50 blocks
+  /// - If `blocks` can't be used in a message (see [`Block::validate_for`]),
+  ///   e.g. an Input block that does not set `dispatch_action`
+  /// - If any block in `blocks` is invalid (see [`Block::validate`])
+  /// - If `attachments` contains more than 100 attachments
+  /// - If any attachment in `attachments` is invalid (see [`Attachment::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Block, surfaces::Message};
+  ///
+  /// let msg = Message::builder().text("Too many dividers!")
+  ///                             .blocks((0..51).map(|_| Block::Divider))
+  ///                             .build();
+  ///
+  /// assert!(matches!(msg.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+
+  /// Whether none of this message's fields are set,
+  /// e.g. when it was flattened into a payload that has no message
+  pub(crate) fn is_empty(&self) -> bool {
+    self.text.is_none()
+    && self.blocks.is_empty()
+    && self.attachments.is_empty()
+    && self.thread_ts.is_none()
+    && self.reply_broadcast.is_none()
+    && self.mrkdwn.is_none()
+    && self.unfurl_links.is_none()
+    && self.unfurl_media.is_none()
+  }
+}
+
+/// Message builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// MessageBuilder.text, MessageBuilder.block, MessageBuilder.blocks,
+    /// MessageBuilder.attachment or MessageBuilder.attachments
+    #[derive(Clone, Copy, Debug)]
+    pub struct contents;
+  }
+
+  /// Initial state for `MessageBuilder`
+  pub type MessageBuilderInit<'a> =
+    MessageBuilder<'a, RequiredMethodNotCalled<method::contents>>;
+
+  /// Build a Message
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `MessageBuilder::build()` is only available if one of these methods has been called:
+  ///  - `text`
+  ///  - `block` or `blocks`
+  ///  - `attachment` or `attachments`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Section,
+  ///                    surfaces::Message,
+  ///                    text::ToSlackMarkdown};
+  ///
+  /// let msg = Message::builder().text("Deploy finished")
+  ///                             .block(Section::builder().text("*Deploy finished* :tada:".markdown())
+  ///                                                      .build())
+  ///                             .thread_ts("1623956385.000200")
+  ///                             .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct MessageBuilder<'a, Contents> {
+    text: Option<Cow<'a, str>>,
+    blocks: Vec<Block<'a>>,
+    attachments: Vec<Attachment<'a>>,
+    thread_ts: Option<Cow<'a, str>>,
+    reply_broadcast: Option<bool>,
+    mrkdwn: Option<bool>,
+    unfurl_links: Option<bool>,
+    unfurl_media: Option<bool>,
+    state: PhantomData<Contents>,
+  }
+
+  impl<'a, C> MessageBuilder<'a, C> {
+    /// Create a new MessageBuilder
+    pub fn new() -> Self {
+      Self { text: None,
+             blocks: vec![],
+             attachments: vec![],
+             thread_ts: None,
+             reply_broadcast: None,
+             mrkdwn: None,
+             unfurl_links: None,
+             unfurl_media: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type param to some other arbitrary marker type param
+    fn cast_state<C2>(self) -> MessageBuilder<'a, C2> {
+      MessageBuilder { text: self.text,
+                       blocks: self.blocks,
+                       attachments: self.attachments,
+                       thread_ts: self.thread_ts,
+                       reply_broadcast: self.reply_broadcast,
+                       mrkdwn: self.mrkdwn,
+                       unfurl_links: self.unfurl_links,
+                       unfurl_media: self.unfurl_media,
+                       state: PhantomData::<_> }
+    }
+
+    /// Set `text` (this, `block`/`blocks` or `attachment`/`attachments` is **Required**)
+    ///
+    /// The usage of this field changes depending on whether you're using `blocks` or not.
+    ///
+    /// If you are, this is used as a fallback string to display in notifications.
+    ///
+    /// If you aren't, this is the main body text of the message.
+    /// It can be formatted as plain text, or with [`mrkdwn` 🔗].
+    ///
+    /// [`mrkdwn` 🔗]: https://api.slack.com/reference/surfaces/formatting#basics
+    pub fn text(mut self,
+                text: impl Into<Cow<'a, str>>)
+                -> MessageBuilder<'a, Set<method::contents>> {
+      self.text = Some(text.into());
+      self.cast_state()
+    }
+
+    /// Add a `block` (this, `text` or `attachment`/`attachments` is **Required**, can be called many times)
+    ///
+    /// A [layout block 🔗] to display in the message.
+    ///
+    /// There is a maximum of 50 blocks in each message.
+    ///
+    /// [layout block 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn block(mut self,
+                 block: impl Into<Block<'a>>)
+                 -> MessageBuilder<'a, Set<method::contents>> {
+      self.blocks.push(block.into());
+      self.cast_state()
+    }
+
+    /// Set `blocks` (this, `text` or `attachment`/`attachments` is **Required**)
+    ///
+    /// Replaces any blocks added so far with the [layout blocks 🔗] in `blocks`.
+    ///
+    /// There is a maximum of 50 blocks in each message.
+    ///
+    /// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn blocks<I>(mut self,
+                     blocks: I)
+                     -> MessageBuilder<'a, Set<method::contents>>
+      where I: IntoIterator,
+            I::Item: Into<Block<'a>>
+    {
+      self.blocks = blocks.into_iter().map(Into::into).collect();
+      self.cast_state()
+    }
+
+    /// Add an `attachment` (this, `text` or `block`/`blocks` is **Required**, can be called many times)
+    ///
+    /// A legacy [secondary attachment 🔗] to display below the message's main content.
+    ///
+    /// [secondary attachment 🔗]: https://api.slack.com/reference/messaging/attachments
+    pub fn attachment(mut self,
+                      attachment: Attachment<'a>)
+                      -> MessageBuilder<'a, Set<method::contents>> {
+      self.attachments.push(attachment);
+      self.cast_state()
+    }
+
+    /// Set `attachments` (this, `text` or `block`/`blocks` is **Required**)
+    ///
+    /// Replaces any attachments added so far with the [secondary attachments 🔗] in `attachments`.
+    ///
+    /// [secondary attachments 🔗]: https://api.slack.com/reference/messaging/attachments
+    pub fn attachments(mut self,
+                       attachments: impl IntoIterator<Item = Attachment<'a>>)
+                       -> MessageBuilder<'a, Set<method::contents>> {
+      self.attachments = attachments.into_iter().collect();
+      self.cast_state()
+    }
+
+    /// Set `thread_ts` (Optional)
+    ///
+    /// The `ts` of another message to make this message a reply to.
+    ///
+    /// Avoid using a reply's `ts` value; use its parent's value instead.
+    pub fn thread_ts(mut self, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+      self.thread_ts = Some(thread_ts.into());
+      self
+    }
+
+    /// Set `reply_broadcast` (Optional)
+    ///
+    /// Used in conjunction with `thread_ts` and indicates whether
+    /// the reply should be made visible to everyone in the channel or conversation.
+    ///
+    /// Defaults to `false`.
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+      self.reply_broadcast = Some(reply_broadcast);
+      self
+    }
+
+    /// Set `mrkdwn` (Optional)
+    ///
+    /// Determines whether the `text` field is rendered
+    /// according to [`mrkdwn` formatting 🔗] or not.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`mrkdwn` formatting 🔗]: https://api.slack.com/reference/surfaces/formatting#basics
+    pub fn mrkdwn(mut self, mrkdwn: bool) -> Self {
+      self.mrkdwn = Some(mrkdwn);
+      self
+    }
+
+    /// Set `unfurl_links` (Optional)
+    ///
+    /// Pass `true` to enable unfurling of primarily text-based content.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+      self.unfurl_links = Some(unfurl_links);
+      self
+    }
+
+    /// Set `unfurl_media` (Optional)
+    ///
+    /// Pass `false` to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+      self.unfurl_media = Some(unfurl_media);
+      self
+    }
+  }
+
+  impl<'a> MessageBuilder<'a, Set<method::contents>> {
+    /// All done building, now give me a darn message!
+    ///
+    /// > `no method name 'build' found for struct 'MessageBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `MessageBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::surfaces::Message;
+    ///
+    /// let foo = Message::builder().build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::surfaces::Message;
+    ///
+    /// let msg = Message::builder().text("Hello, world!").build();
+    /// ```
+    pub fn build(self) -> Message<'a> {
+      Message { text: self.text,
+                blocks: self.blocks,
+                attachments: self.attachments,
+                thread_ts: self.thread_ts,
+                reply_broadcast: self.reply_broadcast,
+                mrkdwn: self.mrkdwn,
+                unfurl_links: self.unfurl_links,
+                unfurl_media: self.unfurl_media }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::{surfaces::{Surface, ValidateFor},
+              val_helpr::{error, ValidatorResult}};
+
+  pub(super) fn blocks(blocks: &[Block]) -> ValidatorResult {
+    blocks.validate_for(Surface::Message)
+          .map_err(|e| error("Message.blocks", e.to_string()))
+  }
+}
@@ -0,0 +1,177 @@
+//! # Typed Private Metadata
+//!
+//! Modals and home tabs can carry a `private_metadata` string of up to
+//! 3000 characters, which Slack sends back to your app in
+//! `view_submission` and `block_actions` payloads.
+//!
+//! [`Metadata`] wraps any `Serialize` type to store it in a view as compact JSON,
+//! checking the length limit when the view is built rather than when Slack
+//! rejects it. With the `compress` feature, large values can be
+//! deflated and base64-encoded to make the most of those 3000 characters.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use slack_blocks::{blocks::Block,
+//!                    surfaces::{metadata::Metadata, Modal}};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Onboarding {
+//!   step: u8,
+//!   team_name: Option<String>,
+//! }
+//!
+//! let state = Onboarding { step: 2,
+//!                          team_name: Some("Platform".into()) };
+//!
+//! let modal = Modal::builder().title("Onboarding")
+//!                             .block(Block::Divider)
+//!                             .typed_private_metadata(&Metadata::new(&state))
+//!                             .unwrap()
+//!                             .build();
+//!
+//! // ...later, on the modal received in a `view_submission` payload:
+//! assert_eq!(modal.typed_private_metadata::<Onboarding>(), Ok(state));
+//! ```
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{compose::value::{self, ValueError, PRIVATE_METADATA_MAX_LEN},
+            convert};
+
+/// Prefix marking metadata that was compressed.
+///
+/// JSON never starts with `~`, so this can't be confused
+/// with uncompressed metadata.
+#[cfg(feature = "compress")]
+const COMPRESSED_PREFIX: &str = "~z:";
+
+/// The most bytes of JSON that compressed metadata can inflate to.
+///
+/// `private_metadata` is sent back by the user's client, so without
+/// a limit a forged value could inflate into megabytes.
+#[cfg(feature = "compress")]
+const MAX_INFLATED_LEN: usize = 64 * 1024;
+
+/// # Typed Metadata
+///
+/// A value to be stored in a view's `private_metadata`.
+///
+/// See the [module docs](self) for an example.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Metadata<T> {
+  value: T,
+  compress: bool,
+}
+
+impl<T> Metadata<T> {
+  /// Wrap `value` to be stored as compact JSON
+  pub fn new(value: T) -> Self {
+    Self { value,
+           compress: false }
+  }
+
+  /// Store the value deflated and base64-encoded, rather than as plain JSON.
+  ///
+  /// Worth it for larger, repetitive values; small values
+  /// may come out longer than their plain JSON.
+  /// The plain JSON can't be longer than 64KiB.
+  #[cfg(feature = "compress")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "compress")))]
+  pub fn compressed(mut self) -> Self {
+    self.compress = true;
+    self
+  }
+
+  /// The wrapped value
+  pub fn value(&self) -> &T {
+    &self.value
+  }
+
+  /// Unwrap the value
+  pub fn into_inner(self) -> T {
+    self.value
+  }
+}
+
+impl<T: Serialize> Metadata<T> {
+  /// Encode the value into a `private_metadata` string
+  ///
+  /// # Errors
+  /// - [`ValueError::TooLong`] if the encoded value is longer than 3000 characters
+  ///   (or, if compressed, the JSON it inflates to is longer than 64KiB)
+  /// - [`ValueError::Serialize`] if the value could not be serialized
+  pub fn encode(&self) -> Result<String, ValueError> {
+    #[cfg(feature = "compress")]
+    if self.compress {
+      let json = value::encode(&self.value, usize::MAX)?;
+      if json.len() > MAX_INFLATED_LEN {
+        return Err(ValueError::TooLong { max: MAX_INFLATED_LEN,
+                                         len: json.len() });
+      }
+
+      return compress::deflate(&json).and_then(|b64| {
+               value::check_len(format!("{}{}", COMPRESSED_PREFIX, b64),
+                                PRIVATE_METADATA_MAX_LEN)
+             });
+    }
+
+    value::encode(&self.value, PRIVATE_METADATA_MAX_LEN)
+  }
+}
+
+impl<T: DeserializeOwned> Metadata<T> {
+  /// Decode a `private_metadata` string produced by [`Metadata::encode`]
+  ///
+  /// # Errors
+  /// [`ValueError::Deserialize`] if the metadata is not the encoding of a `T`
+  /// (or was compressed, and the `compress` feature is not enabled,
+  /// or it inflates to more than 64KiB).
+  pub fn decode(metadata: &str) -> Result<Self, ValueError> {
+    #[cfg(feature = "compress")]
+    if let Some(b64) = metadata.strip_prefix(COMPRESSED_PREFIX) {
+      return compress::inflate(b64).and_then(|json| value::decode(&json))
+                                   .map(|value| Self { value,
+                                                       compress: true });
+    }
+
+    value::decode(metadata).map(Self::new)
+  }
+}
+
+convert!(impl<T> From<T> for Metadata<T> => |value| Metadata::new(value));
+
+#[cfg(feature = "compress")]
+mod compress {
+  use std::io::{Read, Write};
+
+  use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+
+  use super::*;
+
+  pub(super) fn deflate(json: &str) -> Result<String, ValueError> {
+    let mut enc = DeflateEncoder::new(Vec::new(), Compression::best());
+    enc.write_all(json.as_bytes())
+       .and_then(|_| enc.finish())
+       .map(|bytes| base64::encode_config(bytes, base64::URL_SAFE_NO_PAD))
+       .map_err(|e| ValueError::Serialize(e.to_string()))
+  }
+
+  pub(super) fn inflate(b64: &str) -> Result<String, ValueError> {
+    let invalid = |e: String| ValueError::Deserialize(e);
+
+    let bytes = base64::decode_config(b64, base64::URL_SAFE_NO_PAD)
+      .map_err(|e| invalid(e.to_string()))?;
+
+    let mut json = String::new();
+    DeflateDecoder::new(&bytes[..]).take(MAX_INFLATED_LEN as u64 + 1)
+                                   .read_to_string(&mut json)
+                                   .map_err(|e| invalid(e.to_string()))?;
+
+    if json.len() > MAX_INFLATED_LEN {
+      return Err(invalid(format!("inflates to more than {} bytes",
+                                 MAX_INFLATED_LEN)));
+    }
+
+    Ok(json)
+  }
+}
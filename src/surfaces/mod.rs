@@ -0,0 +1,326 @@
+//! # Surfaces
+//!
+//! [slack api docs 🔗]
+//!
+//! Your app can use a variety of surfaces to communicate with users,
+//! each of which accepts a list of [layout blocks 🔗].
+//!
+//! Each surface has its own limits on the number and kinds of blocks it can hold;
+//! use each surface's `validate` method to check a surface before sending it to Slack.
+//!
+//! [slack api docs 🔗]: https://api.slack.com/surfaces
+//! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{blocks::Block,
+            compose::value::ValueError,
+            convert,
+            elems::BlockElement};
+
+pub mod message;
+#[doc(inline)]
+pub use message::Message;
+
+pub mod modal;
+#[doc(inline)]
+pub use modal::Modal;
+
+pub mod home_tab;
+#[doc(inline)]
+pub use home_tab::HomeTab;
+
+pub mod attachment;
+#[doc(inline)]
+pub use attachment::Attachment;
+
+pub mod metadata;
+#[doc(inline)]
+pub use metadata::Metadata;
+
+pub mod stack;
+#[doc(inline)]
+pub use stack::ModalStack;
+
+pub mod wizard;
+#[doc(inline)]
+pub use wizard::Wizard;
+
+/// # View
+///
+/// A view received from Slack, e.g. in an interaction payload;
+/// either a [`Modal`] or a [`HomeTab`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum View<'a> {
+  /// A modal view
+  Modal(Modal<'a>),
+
+  /// A home tab view
+  HomeTab(HomeTab<'a>),
+}
+
+impl<'a> View<'a> {
+  /// The unique id of this view, assigned by Slack.
+  pub fn id(&self) -> Option<&str> {
+    match self {
+      | View::Modal(modal) => modal.id(),
+      | View::HomeTab(home) => home.id(),
+    }
+  }
+
+  /// A unique value that changes each time this view is updated.
+  pub fn hash(&self) -> Option<&str> {
+    match self {
+      | View::Modal(modal) => modal.hash(),
+      | View::HomeTab(home) => home.hash(),
+    }
+  }
+
+  /// The `callback_id` this view was built with, if any.
+  pub fn callback_id(&self) -> Option<&str> {
+    match self {
+      | View::Modal(modal) => modal.callback_id(),
+      | View::HomeTab(home) => home.callback_id(),
+    }
+  }
+
+  /// The `private_metadata` this view was built with, if any.
+  pub fn private_metadata(&self) -> Option<&str> {
+    match self {
+      | View::Modal(modal) => modal.private_metadata(),
+      | View::HomeTab(home) => home.private_metadata(),
+    }
+  }
+
+  /// Decode `private_metadata` set with a `typed_private_metadata` builder method
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this view has no `private_metadata`
+  /// - [`ValueError::Deserialize`] if the metadata is not the encoding of a `T`
+  pub fn typed_private_metadata<T: DeserializeOwned>(
+    &self)
+    -> Result<T, ValueError> {
+    match self {
+      | View::Modal(modal) => modal.typed_private_metadata(),
+      | View::HomeTab(home) => home.typed_private_metadata(),
+    }
+  }
+}
+
+convert!(impl<'a> From<Modal<'a>> for View<'a> => |m| View::Modal(m));
+convert!(impl<'a> From<HomeTab<'a>> for View<'a> => |h| View::HomeTab(h));
+
+/// # Surface
+///
+/// The different places Block Kit [layout blocks 🔗] can be sent to.
+///
+/// Not every block (or block element) is available in every surface;
+/// use [`ValidateFor::validate_for`] to check that some blocks
+/// can be used in a surface before sending them to Slack.
+///
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Surface {
+  /// # [`Message`]
+  Message,
+
+  /// # [`Modal`]
+  Modal,
+
+  /// # [`HomeTab`]
+  HomeTab,
+
+  /// # [`Attachment`]
+  ///
+  /// Blocks in attachments follow the same rules as blocks in messages.
+  Attachment,
+}
+
+impl fmt::Display for Surface {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let kind = match self {
+      | Surface::Message => "Message",
+      | Surface::Modal => "Modal",
+      | Surface::HomeTab => "HomeTab",
+      | Surface::Attachment => "Attachment",
+    };
+
+    write!(f, "{}", kind)
+  }
+}
+
+impl Surface {
+  /// Whether blocks in this surface are subject to the rules for messages,
+  /// i.e. this is a [`Surface::Message`] or [`Surface::Attachment`].
+  pub(crate) fn follows_message_rules(self) -> bool {
+    matches!(self, Surface::Message | Surface::Attachment)
+  }
+}
+
+/// Check that a block, block element, or list of blocks
+/// can be used in a given [`Surface`].
+///
+/// # Example
+/// ```
+/// use slack_blocks::{blocks::{Block, File},
+///                    surfaces::{Surface, ValidateFor}};
+///
+/// let blocks: Vec<Block> =
+///   vec![Block::Divider,
+///        File::builder().external_id("abc").build().into()];
+///
+/// assert_eq!(blocks.validate_for(Surface::Message), Ok(()));
+/// assert!(matches!(blocks.validate_for(Surface::Modal), Err(_)));
+/// ```
+pub trait ValidateFor {
+  /// Check that `self` can be used in `surface`
+  ///
+  /// # Errors
+  /// A [`SurfaceError`] describing the first incompatibility found.
+  fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError>;
+}
+
+impl ValidateFor for Block<'_> {
+  fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError> {
+    Block::validate_for(self, surface)
+  }
+}
+
+impl ValidateFor for BlockElement<'_> {
+  fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError> {
+    BlockElement::validate_for(self, surface)
+  }
+}
+
+impl ValidateFor for [Block<'_>] {
+  fn validate_for(&self, surface: Surface) -> Result<(), SurfaceError> {
+    self.iter().enumerate().try_for_each(|(ix, block)| {
+                             block.validate_for(surface)
+                                  .map_err(|e| SurfaceError { block_index:
+                                                                Some(ix),
+                                                              ..e })
+                           })
+  }
+}
+
+/// Error yielded when a block or block element
+/// is used in a [`Surface`] that does not support it.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Serialize)]
+pub struct SurfaceError {
+  surface: Surface,
+  block_index: Option<usize>,
+  reason: SurfaceErrorReason,
+}
+
+impl SurfaceError {
+  pub(crate) fn new(surface: Surface, reason: SurfaceErrorReason) -> Self {
+    Self { surface,
+           block_index: None,
+           reason }
+  }
+
+  /// The surface that was validated against
+  pub fn surface(&self) -> Surface {
+    self.surface
+  }
+
+  /// When validating a list of blocks,
+  /// the index of the block that is not supported.
+  pub fn block_index(&self) -> Option<usize> {
+    self.block_index
+  }
+
+  /// Why the block or element is not supported
+  pub fn reason(&self) -> &SurfaceErrorReason {
+    &self.reason
+  }
+}
+
+impl fmt::Display for SurfaceError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "(In {}", self.surface)?;
+
+    if let Some(ix) = self.block_index {
+      write!(f, ", block {}", ix)?;
+    }
+
+    write!(f, ") {}", self.reason)
+  }
+}
+
+impl std::error::Error for SurfaceError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
+
+/// The reason a [`SurfaceError`] was yielded.
+///
+/// Serializes to an object with a snake_case `code` tag,
+/// e.g. `{"code": "block_not_supported", "block": "File"}`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum SurfaceErrorReason {
+  /// The block is not available in this surface
+  BlockNotSupported {
+    /// The kind of block, e.g. `"File"`
+    block: &'static str,
+  },
+
+  /// The block element is not available in this surface
+  ElementNotSupported {
+    /// The kind of block element, e.g. `"TextInput"`
+    element: &'static str,
+  },
+
+  /// The block element can't be used in this kind of block
+  ElementNotSupportedInBlock {
+    /// The kind of block element, e.g. `"TextInput"`
+    element: &'static str,
+    /// The kind of block, e.g. `"Section"`
+    block: &'static str,
+  },
+
+  /// Input blocks outside of modals must set `dispatch_action` to `true`,
+  /// since there is no submit button to send their values to your app.
+  InputRequiresDispatchAction,
+}
+
+impl SurfaceErrorReason {
+  /// A short, stable, snake_case identifier for this reason.
+  pub fn code(&self) -> &'static str {
+    use SurfaceErrorReason::*;
+
+    match self {
+      | BlockNotSupported { .. } => "block_not_supported",
+      | ElementNotSupported { .. } => "element_not_supported",
+      | ElementNotSupportedInBlock { .. } => "element_not_supported_in_block",
+      | InputRequiresDispatchAction => "input_requires_dispatch_action",
+    }
+  }
+}
+
+impl fmt::Display for SurfaceErrorReason {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    use SurfaceErrorReason::*;
+
+    match self {
+      | BlockNotSupported { block } => {
+        write!(f, "{} blocks are not supported", block)
+      },
+      | ElementNotSupported { element } => {
+        write!(f, "{} elements are not supported", element)
+      },
+      | ElementNotSupportedInBlock { element, block } => {
+        write!(f, "{} elements can't be used in {} blocks", element, block)
+      },
+      | InputRequiresDispatchAction => {
+        write!(f, "Input blocks must set `dispatch_action` to `true`")
+      },
+    }
+  }
+}
@@ -0,0 +1,596 @@
+//! # Modal
+//!
+//! [slack api docs 🔗]
+//!
+//! Modals provide focused spaces ideal for requesting and collecting data from users,
+//! or temporarily displaying dynamic and interactive information.
+//!
+//! A modal is the `view` payload sent with [`views.open` 🔗], [`views.push` 🔗]
+//! and [`views.update` 🔗], and can contain up to 100 [layout blocks 🔗].
+//!
+//! [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#modal
+//! [`views.open` 🔗]: https://api.slack.com/methods/views.open
+//! [`views.push` 🔗]: https://api.slack.com/methods/views.push
+//! [`views.update` 🔗]: https://api.slack.com/methods/views.update
+//! [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+
+use std::borrow::Cow;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+#[cfg(feature = "validation")]
+use validator::Validate;
+
+use super::Metadata;
+#[cfg(feature = "signing")]
+use crate::compose::{signing::{self, SigningKey},
+                     value};
+#[cfg(feature = "validation")]
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+use crate::{blocks::Block,
+            compose::value::ValueError,
+            interaction::ViewState,
+            text};
+
+/// # Modal
+///
+/// [slack api docs 🔗]
+///
+/// Modals provide focused spaces ideal for requesting and collecting data from users,
+/// or temporarily displaying dynamic and interactive information.
+///
+/// A modal is the `view` payload sent with [`views.open` 🔗], [`views.push` 🔗]
+/// and [`views.update` 🔗], and can contain up to 100 [layout blocks 🔗].
+///
+/// Views returned by Slack (e.g. in interaction payloads or API responses)
+/// carry some extra fields like `id` and `hash`, which can be accessed
+/// on a deserialized `Modal` but are never serialized.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/surfaces/views#modal
+/// [`views.open` 🔗]: https://api.slack.com/methods/views.open
+/// [`views.push` 🔗]: https://api.slack.com/methods/views.push
+/// [`views.update` 🔗]: https://api.slack.com/methods/views.update
+/// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "validation", derive(Validate))]
+#[cfg_attr(feature = "validation",
+           validate(schema(function = "validate_submit",
+                           skip_on_field_errors = false)))]
+#[serde(tag = "type", rename = "modal")]
+pub struct Modal<'a> {
+  #[cfg_attr(feature = "validation", validate(custom = "validate::title"))]
+  title: text::Text,
+
+  #[cfg_attr(feature = "validation", validate(length(max = 100)))]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::blocks"))]
+  #[cfg_attr(feature = "validation", validate)]
+  pub(crate) blocks: Vec<Block<'a>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation", validate(custom = "validate::close"))]
+  close: Option<text::Text>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::submit_text"))]
+  submit: Option<text::Text>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::private_metadata"))]
+  private_metadata: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::callback_id"))]
+  callback_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  clear_on_close: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  notify_on_close: Option<bool>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[cfg_attr(feature = "validation",
+             validate(custom = "validate::external_id"))]
+  external_id: Option<Cow<'a, str>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  submit_disabled: Option<bool>,
+
+  #[serde(default, skip_serializing)]
+  id: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing)]
+  hash: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing)]
+  state: Option<ViewState<'a>>,
+
+  #[serde(default, skip_serializing)]
+  root_view_id: Option<Cow<'a, str>>,
+
+  #[serde(default, skip_serializing)]
+  previous_view_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Modal<'a> {
+  /// Build a new Modal.
+  ///
+  /// For example, see docs for [`build::ModalBuilder`].
+  pub fn builder() -> build::ModalBuilderInit<'a> {
+    build::ModalBuilderInit::new()
+  }
+
+  /// The unique id of this view, assigned by Slack.
+  ///
+  /// Only present on views received from Slack.
+  pub fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
+
+  /// A unique value that changes each time this view is updated.
+  ///
+  /// Pass it to `views.update` to protect against race conditions.
+  ///
+  /// Only present on views received from Slack.
+  pub fn hash(&self) -> Option<&str> {
+    self.hash.as_deref()
+  }
+
+  /// The values of the input elements in this view, as submitted by the user.
+  ///
+  /// Only present on views received from Slack;
+  /// see [`ViewState`] for typed access to each value.
+  pub fn state(&self) -> Option<&ViewState<'a>> {
+    self.state.as_ref()
+  }
+
+  /// The id of the view at the bottom of the modal stack this view belongs to.
+  ///
+  /// Only present on views received from Slack.
+  pub fn root_view_id(&self) -> Option<&str> {
+    self.root_view_id.as_deref()
+  }
+
+  /// The id of the view beneath this view in the modal stack, if any.
+  ///
+  /// Only present on views received from Slack.
+  pub fn previous_view_id(&self) -> Option<&str> {
+    self.previous_view_id.as_deref()
+  }
+
+  /// The `callback_id` this view was built with, if any.
+  pub fn callback_id(&self) -> Option<&str> {
+    self.callback_id.as_deref()
+  }
+
+  /// The `private_metadata` this view was built with, if any.
+  pub fn private_metadata(&self) -> Option<&str> {
+    self.private_metadata.as_deref()
+  }
+
+  /// Decode `private_metadata` set with
+  /// [`ModalBuilder::typed_private_metadata`](build::ModalBuilder::typed_private_metadata)
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this view has no `private_metadata`
+  /// - [`ValueError::Deserialize`] if the metadata is not the encoding of a `T`
+  pub fn typed_private_metadata<T: DeserializeOwned>(
+    &self)
+    -> Result<T, ValueError> {
+    self.private_metadata()
+        .ok_or(ValueError::Missing)
+        .and_then(Metadata::decode)
+        .map(Metadata::into_inner)
+  }
+
+  /// Verify and decode `private_metadata` set with
+  /// [`ModalBuilder::signed_private_metadata`](build::ModalBuilder::signed_private_metadata)
+  /// for the view `callback_id`
+  ///
+  /// # Errors
+  /// - [`ValueError::Missing`] if this view has no `private_metadata`
+  /// - [`ValueError::BadSignature`] if the metadata was not sealed by `key` for `callback_id`
+  /// - [`ValueError::Deserialize`] if the metadata is not the JSON encoding of a `T`
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn verified_private_metadata<T: DeserializeOwned>(
+    &self,
+    key: &SigningKey,
+    callback_id: &str)
+    -> Result<T, ValueError> {
+    let context = signing::private_metadata_context(callback_id);
+
+    self.private_metadata()
+        .ok_or(ValueError::Missing)
+        .and_then(|meta| key.open_typed(&context, meta))
+  }
+
+  /// Validate that this Modal agrees with Slack's model requirements
+  ///
+  /// # Errors
+  /// - If `title` longer than 24 chars
+  /// - If `close` longer than 24 chars
+  /// - If `submit` longer than 24 chars
+  /// - If `blocks` contains more than 100 blocks
+  /// - If `blocks` contains an Input block and `submit` is not set
+  /// - If `private_metadata` longer than 3000 chars
+  /// - If `callback_id` longer than 255 chars
+  /// - If `external_id` longer than 255 chars
+  /// - If `blocks` can't be used in a modal (see [`Block::validate_for`])
+  /// - If any block in `blocks` is invalid (see [`Block::validate`])
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Input, elems::TextInput, surfaces::Modal};
+  ///
+  /// let input =
+  ///   Input::builder().label("Name")
+  ///                   .element(TextInput::builder().action_id("name").build())
+  ///                   .build();
+  ///
+  /// // Input blocks can only be submitted if the modal has a submit button
+  /// let modal = Modal::builder().title("Sign up").block(input).build();
+  ///
+  /// assert!(matches!(modal.validate(), Err(_)));
+  /// ```
+  #[cfg(feature = "validation")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+  pub fn validate(&self) -> ValidationResult {
+    Validate::validate(self)
+  }
+}
+
+/// Modal builder
+pub mod build {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::build::*;
+
+  /// Compile-time markers for builder methods
+  #[allow(non_camel_case_types)]
+  pub mod method {
+    /// ModalBuilder.title
+    #[derive(Clone, Copy, Debug)]
+    pub struct title;
+
+    /// ModalBuilder.block or ModalBuilder.blocks
+    #[derive(Clone, Copy, Debug)]
+    pub struct blocks;
+  }
+
+  /// Initial state for `ModalBuilder`
+  pub type ModalBuilderInit<'a> =
+    ModalBuilder<'a,
+                 RequiredMethodNotCalled<method::title>,
+                 RequiredMethodNotCalled<method::blocks>>;
+
+  /// Build a Modal
+  ///
+  /// Allows you to construct safely, with compile-time checks
+  /// on required setter methods.
+  ///
+  /// # Required Methods
+  /// `ModalBuilder::build()` is only available if these methods have been called:
+  ///  - `title`
+  ///  - `block` or `blocks`
+  ///
+  /// # Example
+  /// ```
+  /// use slack_blocks::{blocks::Input, elems::TextInput, surfaces::Modal};
+  ///
+  /// let input =
+  ///   Input::builder().label("Name")
+  ///                   .element(TextInput::builder().action_id("name").build())
+  ///                   .block_id("name")
+  ///                   .build();
+  ///
+  /// let modal = Modal::builder().title("Sign up")
+  ///                             .submit("Submit")
+  ///                             .close("Cancel")
+  ///                             .callback_id("sign_up")
+  ///                             .block(input)
+  ///                             .build();
+  /// ```
+  #[derive(Debug)]
+  pub struct ModalBuilder<'a, Title, Blocks> {
+    title: Option<text::Text>,
+    blocks: Vec<Block<'a>>,
+    close: Option<text::Text>,
+    submit: Option<text::Text>,
+    private_metadata: Option<Cow<'a, str>>,
+    callback_id: Option<Cow<'a, str>>,
+    clear_on_close: Option<bool>,
+    notify_on_close: Option<bool>,
+    external_id: Option<Cow<'a, str>>,
+    submit_disabled: Option<bool>,
+    state: PhantomData<(Title, Blocks)>,
+  }
+
+  impl<'a, T, B> ModalBuilder<'a, T, B> {
+    /// Create a new ModalBuilder
+    pub fn new() -> Self {
+      Self { title: None,
+             blocks: vec![],
+             close: None,
+             submit: None,
+             private_metadata: None,
+             callback_id: None,
+             clear_on_close: None,
+             notify_on_close: None,
+             external_id: None,
+             submit_disabled: None,
+             state: PhantomData::<_> }
+    }
+
+    /// Change the marker type params to some other arbitrary marker type params
+    fn cast_state<T2, B2>(self) -> ModalBuilder<'a, T2, B2> {
+      ModalBuilder { title: self.title,
+                     blocks: self.blocks,
+                     close: self.close,
+                     submit: self.submit,
+                     private_metadata: self.private_metadata,
+                     callback_id: self.callback_id,
+                     clear_on_close: self.clear_on_close,
+                     notify_on_close: self.notify_on_close,
+                     external_id: self.external_id,
+                     submit_disabled: self.submit_disabled,
+                     state: PhantomData::<_> }
+    }
+
+    /// Set `title` (**Required**)
+    ///
+    /// The title that appears in the top-left of the modal.
+    ///
+    /// Maximum length for the `text` in this field is 24 characters.
+    pub fn title(mut self,
+                 title: impl Into<text::Plain>)
+                 -> ModalBuilder<'a, Set<method::title>, B> {
+      self.title = Some(title.into().into());
+      self.cast_state()
+    }
+
+    /// Add a `block` (**Required**, can be called many times)
+    ///
+    /// A [layout block 🔗] to display in the modal.
+    ///
+    /// There is a maximum of 100 blocks in each modal.
+    ///
+    /// [layout block 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn block(mut self,
+                 block: impl Into<Block<'a>>)
+                 -> ModalBuilder<'a, T, Set<method::blocks>> {
+      self.blocks.push(block.into());
+      self.cast_state()
+    }
+
+    /// Set `blocks` (**Required**)
+    ///
+    /// Replaces any blocks added so far with the [layout blocks 🔗] in `blocks`.
+    ///
+    /// There is a maximum of 100 blocks in each modal.
+    ///
+    /// [layout blocks 🔗]: https://api.slack.com/reference/block-kit/blocks
+    pub fn blocks<I>(mut self,
+                     blocks: I)
+                     -> ModalBuilder<'a, T, Set<method::blocks>>
+      where I: IntoIterator,
+            I::Item: Into<Block<'a>>
+    {
+      self.blocks = blocks.into_iter().map(Into::into).collect();
+      self.cast_state()
+    }
+
+    /// Set `close` (Optional)
+    ///
+    /// The text that defines the button that closes the modal.
+    ///
+    /// Maximum length for the `text` in this field is 24 characters.
+    pub fn close(mut self, close: impl Into<text::Plain>) -> Self {
+      self.close = Some(close.into().into());
+      self
+    }
+
+    /// Set `submit` (Optional)
+    ///
+    /// The text that defines the submit button.
+    ///
+    /// Maximum length for the `text` in this field is 24 characters.
+    ///
+    /// `submit` is required when an `input` block is within the `blocks` array.
+    pub fn submit(mut self, submit: impl Into<text::Plain>) -> Self {
+      self.submit = Some(submit.into().into());
+      self
+    }
+
+    /// Set `private_metadata` (Optional)
+    ///
+    /// A string that will be sent to your app in
+    /// `view_submission` and `block_actions` events.
+    ///
+    /// Maximum length of 3000 characters.
+    pub fn private_metadata(mut self,
+                            private_metadata: impl Into<Cow<'a, str>>)
+                            -> Self {
+      self.private_metadata = Some(private_metadata.into());
+      self
+    }
+
+    /// Set `private_metadata` to the encoding of a typed [`Metadata`] (Optional)
+    ///
+    /// Decode it with [`Modal::typed_private_metadata`].
+    ///
+    /// # Errors
+    /// If the metadata can't be serialized, or is longer than 3000 characters once encoded.
+    pub fn typed_private_metadata<M>(self,
+                                     metadata: &Metadata<M>)
+                                     -> Result<Self, ValueError>
+      where M: Serialize
+    {
+      metadata.encode().map(|meta| self.private_metadata(meta))
+    }
+
+    /// Set `private_metadata` to the compact JSON encoding of `metadata`,
+    /// sealed with `key` for the view `callback_id` (Optional)
+    ///
+    /// Verify and decode it with [`Modal::verified_private_metadata`].
+    ///
+    /// # Errors
+    /// If `metadata` can't be serialized, or is longer than 3000 characters once sealed.
+    #[cfg(feature = "signing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+    pub fn signed_private_metadata<M>(self,
+                                      key: &SigningKey,
+                                      callback_id: &str,
+                                      metadata: &M)
+                                      -> Result<Self, ValueError>
+      where M: Serialize + ?Sized
+    {
+      key.seal_typed(&signing::private_metadata_context(callback_id),
+                     metadata,
+                     value::PRIVATE_METADATA_MAX_LEN)
+         .map(|meta| self.private_metadata(meta))
+    }
+
+    /// Set `callback_id` (Optional)
+    ///
+    /// An identifier to recognize interactions and submissions of this particular view.
+    ///
+    /// Don't use this to store sensitive information (use `private_metadata` instead).
+    ///
+    /// Maximum length of 255 characters.
+    pub fn callback_id(mut self, callback_id: impl Into<Cow<'a, str>>) -> Self {
+      self.callback_id = Some(callback_id.into());
+      self
+    }
+
+    /// Set `clear_on_close` (Optional)
+    ///
+    /// When set to `true`, clicking on the `close` button will clear all views
+    /// in a modal and close it.
+    ///
+    /// Defaults to `false`.
+    pub fn clear_on_close(mut self, clear_on_close: bool) -> Self {
+      self.clear_on_close = Some(clear_on_close);
+      self
+    }
+
+    /// Set `notify_on_close` (Optional)
+    ///
+    /// Indicates whether Slack will send your request URL
+    /// a `view_closed` event when a user clicks the `close` button.
+    ///
+    /// Defaults to `false`.
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+      self.notify_on_close = Some(notify_on_close);
+      self
+    }
+
+    /// Set `external_id` (Optional)
+    ///
+    /// A custom identifier that must be unique for all views on a per-team basis.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+      self.external_id = Some(external_id.into());
+      self
+    }
+
+    /// Set `submit_disabled` (Optional)
+    ///
+    /// When set to `true`, disables the submit button until the user
+    /// has completed one or more inputs.
+    ///
+    /// This property is for [configuration modals 🔗] only.
+    ///
+    /// [configuration modals 🔗]: https://api.slack.com/reference/workflows/configuration-view
+    pub fn submit_disabled(mut self, submit_disabled: bool) -> Self {
+      self.submit_disabled = Some(submit_disabled);
+      self
+    }
+  }
+
+  impl<'a> ModalBuilder<'a, Set<method::title>, Set<method::blocks>> {
+    /// All done building, now give me a darn modal!
+    ///
+    /// > `no method name 'build' found for struct 'ModalBuilder<...>'`?
+    /// Make sure all required setter methods have been called. See docs for `ModalBuilder`.
+    ///
+    /// ```compile_fail
+    /// use slack_blocks::surfaces::Modal;
+    ///
+    /// let foo = Modal::builder().build(); // Won't compile!
+    /// ```
+    ///
+    /// ```
+    /// use slack_blocks::{blocks::Block, surfaces::Modal};
+    ///
+    /// let modal = Modal::builder().title("Foo").block(Block::Divider).build();
+    /// ```
+    pub fn build(self) -> Modal<'a> {
+      Modal { title: self.title.unwrap(),
+              blocks: self.blocks,
+              close: self.close,
+              submit: self.submit,
+              private_metadata: self.private_metadata,
+              callback_id: self.callback_id,
+              clear_on_close: self.clear_on_close,
+              notify_on_close: self.notify_on_close,
+              external_id: self.external_id,
+              submit_disabled: self.submit_disabled,
+              id: None,
+              hash: None,
+              state: None,
+              root_view_id: None,
+              previous_view_id: None }
+    }
+  }
+}
+
+#[cfg(feature = "validation")]
+fn validate_submit(modal: &Modal) -> ValidatorResult {
+  let has_input = modal.blocks.iter().any(|b| matches!(b, Block::Input(_)));
+
+  if has_input && modal.submit.is_none() {
+    Err(error("Modal.submit",
+              "submit is required when blocks contains an Input block"))
+  } else {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "validation")]
+mod validate {
+  use super::*;
+  use crate::{surfaces::{Surface, ValidateFor},
+              val_helpr::len};
+
+  pub(super) fn blocks(blocks: &[Block]) -> ValidatorResult {
+    blocks.validate_for(Surface::Modal)
+          .map_err(|e| error("Modal.blocks", e.to_string()))
+  }
+
+  pub(super) fn title(text: &text::Text) -> ValidatorResult {
+    len("Modal.title", ..=24, text)
+  }
+
+  pub(super) fn close(text: &text::Text) -> ValidatorResult {
+    len("Modal.close", ..=24, text)
+  }
+
+  pub(super) fn submit_text(text: &text::Text) -> ValidatorResult {
+    len("Modal.submit", ..=24, text)
+  }
+
+  pub(super) fn private_metadata(meta: &Cow<str>) -> ValidatorResult {
+    len("Modal.private_metadata", ..=3000, meta)
+  }
+
+  pub(super) fn callback_id(id: &Cow<str>) -> ValidatorResult {
+    len("Modal.callback_id", ..=255, id)
+  }
+
+  pub(super) fn external_id(id: &Cow<str>) -> ValidatorResult {
+    len("Modal.external_id", ..=255, id)
+  }
+}
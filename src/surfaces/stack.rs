@@ -0,0 +1,214 @@
+//! # Modal Stack
+//!
+//! [slack api docs 🔗]
+//!
+//! Modals can be pushed on top of one another, up to 3 deep,
+//! with the user going back to the previous modal when the top one is closed.
+//!
+//! [`ModalStack`] reads the `id`, `hash`, `root_view_id` and `previous_view_id`
+//! of the modal in an interaction payload, and builds the requests to change
+//! the stack from there:
+//!  - [`ModalStack::push`] pushes a new modal, if there's room
+//!  - [`ModalStack::update`] replaces the modal, passing along its `hash` so that
+//!    Slack rejects the update if the modal changed in the meantime
+//!  - [`ModalStack::update_root`] replaces the modal at the bottom of the stack
+//!  - [`ModalStack::clear`] closes every modal in the stack
+//!
+//! ```
+//! use slack_blocks::{api::views::Push,
+//!                    blocks::Block,
+//!                    interaction::ViewSubmission,
+//!                    surfaces::{stack::ModalStack, Modal}};
+//!
+//! let submission: ViewSubmission = serde_json::from_value(serde_json::json!({
+//!   "team": { "id": "T1" },
+//!   "user": { "id": "U1" },
+//!   "trigger_id": "12345.98765.abcd2358fdea",
+//!   "view": {
+//!     "type": "modal",
+//!     "title": { "type": "plain_text", "text": "Order" },
+//!     "blocks": [],
+//!     "id": "V2",
+//!     "hash": "156772938.1827394",
+//!     "root_view_id": "V1",
+//!     "previous_view_id": "V1"
+//!   }
+//! })).unwrap();
+//!
+//! let stack = ModalStack::from_view(submission.view()).unwrap();
+//! assert_eq!(stack.depth(), 2);
+//!
+//! let confirm = Modal::builder().title("Confirm")
+//!                               .block(Block::Divider)
+//!                               .build();
+//! let req: Push = stack.push(submission.trigger_id().unwrap(), confirm)
+//!                      .unwrap();
+//! // POST https://slack.com/api/views.push
+//! ```
+//!
+//! [slack api docs 🔗]: https://api.slack.com/surfaces/modals/using#modifying
+
+use std::{borrow::Cow, fmt};
+
+use super::Modal;
+use crate::{api::views::{Push, Update},
+            interaction::ResponseAction};
+
+/// The number of modals Slack allows in a view stack
+pub const MAX_DEPTH: usize = 3;
+
+/// # Modal Stack
+///
+/// The position of a modal in its view stack,
+/// read from the modal in an interaction payload or Web API response.
+///
+/// See the [module docs](self) for an example.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModalStack {
+  view_id: String,
+  hash: Option<String>,
+  root_view_id: Option<String>,
+  previous_view_id: Option<String>,
+}
+
+impl ModalStack {
+  /// Read the stack of a modal received from Slack
+  ///
+  /// # Errors
+  /// [`StackError::NotOpen`] if the modal has no `id`, meaning
+  /// it was built by your app rather than received from Slack.
+  pub fn from_view(view: &Modal<'_>) -> Result<Self, StackError> {
+    let owned = |s: Option<&str>| s.map(str::to_string);
+
+    Ok(Self { view_id: owned(view.id()).ok_or(StackError::NotOpen)?,
+              hash: owned(view.hash()),
+              root_view_id: owned(view.root_view_id()),
+              previous_view_id: owned(view.previous_view_id()) })
+  }
+
+  /// Move to a modal received from Slack since this stack was read,
+  /// e.g. the `view` in the response to a `views.push` or `views.update`
+  /// request, so that the next update carries its `hash`.
+  ///
+  /// # Errors
+  /// [`StackError::NotOpen`] if the modal has no `id`.
+  /// The stack is left unchanged.
+  pub fn track(&mut self, view: &Modal<'_>) -> Result<(), StackError> {
+    *self = Self::from_view(view)?;
+    Ok(())
+  }
+
+  /// The id of the modal on top of the stack
+  pub fn view_id(&self) -> &str {
+    &self.view_id
+  }
+
+  /// The `hash` of the modal on top of the stack,
+  /// sent along with [`ModalStack::update`].
+  pub fn hash(&self) -> Option<&str> {
+    self.hash.as_deref()
+  }
+
+  /// The id of the modal at the bottom of the stack
+  pub fn root_view_id(&self) -> &str {
+    self.root_view_id.as_deref().unwrap_or(&self.view_id)
+  }
+
+  /// The id of the modal below the top of the stack,
+  /// if the top modal was pushed.
+  pub fn previous_view_id(&self) -> Option<&str> {
+    self.previous_view_id.as_deref()
+  }
+
+  /// The number of modals in the stack, from 1 to [`MAX_DEPTH`]
+  pub fn depth(&self) -> usize {
+    match self.previous_view_id() {
+      | None => 1,
+      | Some(previous) if previous == self.root_view_id() => 2,
+      | Some(_) => MAX_DEPTH,
+    }
+  }
+
+  /// Whether another modal can be pushed onto the stack
+  pub fn can_push(&self) -> bool {
+    self.depth() < MAX_DEPTH
+  }
+
+  /// Build a `views.push` request pushing `view` onto the stack
+  ///
+  /// `trigger_id` is the trigger of the interaction with the top modal.
+  ///
+  /// # Errors
+  /// [`StackError::Full`] if the stack already has [`MAX_DEPTH`] modals.
+  pub fn push<'a>(&self,
+                  trigger_id: impl Into<Cow<'a, str>>,
+                  view: Modal<'a>)
+                  -> Result<Push<'a>, StackError> {
+    if !self.can_push() {
+      return Err(StackError::Full);
+    }
+
+    Ok(Push::builder().trigger_id(trigger_id).view(view).build())
+  }
+
+  /// Build a `views.update` request replacing the top modal with `view`
+  ///
+  /// The request carries the top modal's `hash`, so that Slack rejects it
+  /// if the modal was updated since this stack was read.
+  pub fn update<'a>(&self, view: Modal<'a>) -> Update<'a> {
+    let update = Update::builder().view_id(self.view_id.clone()).view(view);
+
+    match &self.hash {
+      | Some(hash) => update.hash(hash.clone()).build(),
+      | None => update.build(),
+    }
+  }
+
+  /// Build a `views.update` request replacing the modal
+  /// at the bottom of the stack with `view`
+  ///
+  /// Only the top modal's `hash` is known, so the request doesn't carry one
+  /// unless the top modal is the root.
+  pub fn update_root<'a>(&self, view: Modal<'a>) -> Update<'a> {
+    if self.depth() == 1 {
+      return self.update(view);
+    }
+
+    Update::builder().view_id(self.root_view_id().to_string())
+                     .view(view)
+                     .build()
+  }
+
+  /// Respond to a `view_submission` of the top modal
+  /// by closing every modal in the stack.
+  pub fn clear(&self) -> ResponseAction<'static> {
+    ResponseAction::Clear
+  }
+}
+
+/// Error yielded when a modal stack can't be read or changed
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StackError {
+  /// The modal has no `id`, so it isn't open in Slack
+  NotOpen,
+
+  /// The stack already has [`MAX_DEPTH`] modals
+  Full,
+}
+
+impl fmt::Display for StackError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | StackError::NotOpen => write!(f, "modal has no view id"),
+      | StackError::Full => {
+        write!(f, "view stack already has {} modals", MAX_DEPTH)
+      },
+    }
+  }
+}
+
+impl std::error::Error for StackError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    None
+  }
+}
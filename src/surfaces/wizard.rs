@@ -0,0 +1,446 @@
+//! # Wizard
+//!
+//! A multi-step modal, where each step is a view built from [`Block`]s
+//! and the answers collected so far travel with the modal
+//! in its `private_metadata` (see [`Metadata`]).
+//!
+//! Each step's submit button moves to the next step, answering the
+//! `view_submission` with a [`ResponseAction::Update`] (or [`ResponseAction::Push`],
+//! see [`Wizard::push`]). The last step's submit button finishes the wizard,
+//! handing all of the collected answers back to your app.
+//!
+//! `private_metadata` is sent by the user's client, so unless the wizard is
+//! [signed](Wizard::signed) a workspace member can edit it to skip steps
+//! or forge answers. Don't trust the answers of an unsigned wizard
+//! more than you would trust the user.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use slack_blocks::{blocks::{Input, Section},
+//!                    elems::TextInput,
+//!                    interaction::{ResponseAction, ViewSubmission},
+//!                    surfaces::wizard::{Wizard, WizardOutcome},
+//!                    text::ToSlackPlaintext};
+//!
+//! #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+//! struct Answers {
+//!   team: String,
+//! }
+//!
+//! let wizard =
+//!   Wizard::new("onboarding", "Welcome!")
+//!     .step(|_: &Answers| {
+//!             vec![Input::builder().label("Team name")
+//!                                  .block_id("team")
+//!                                  .element(TextInput::builder().action_id("team").build())
+//!                                  .build()
+//!                                  .into()]
+//!           },
+//!           |answers, view| {
+//!             let state = view.state().expect("submitted views have state");
+//!             answers.team = state.text("team", "team")
+//!                                 .ok()
+//!                                 .flatten()
+//!                                 .unwrap_or_default()
+//!                                 .to_string();
+//!             Ok(())
+//!           })
+//!     .step(|answers: &Answers| {
+//!             vec![Section::builder().text(format!("Create {}?", answers.team).plaintext())
+//!                                    .build()
+//!                                    .into()]
+//!           },
+//!           |_, _| Ok(()));
+//!
+//! // Open the first step with `views.open`
+//! let first = wizard.open(Answers::default()).unwrap();
+//!
+//! // ...the user fills in the first step and submits it:
+//! let mut view = serde_json::to_value(&first).unwrap();
+//! view["state"] =
+//!   serde_json::json!({ "values": { "team": { "team": { "type": "plain_text_input", "value": "Platform" } } } });
+//! let submission: ViewSubmission =
+//!   serde_json::from_value(serde_json::json!({ "team": { "id": "T1" },
+//!                                              "user": { "id": "U1" },
+//!                                              "view": view }))
+//!     .unwrap();
+//!
+//! match wizard.submit(&submission).unwrap() {
+//!   | WizardOutcome::Respond(ResponseAction::Update { view }) => {
+//!     // Respond to the submission with the second step
+//!     assert_eq!(view.typed_private_metadata::<serde_json::Value>().unwrap()["answers"]["team"],
+//!                "Platform");
+//!   },
+//!   | other => panic!("{:?}", other),
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{stack::MAX_DEPTH, Metadata, Modal};
+#[cfg(feature = "signing")]
+use crate::compose::signing::SigningKey;
+use crate::{blocks::{Actions, Block},
+            compose::value::ValueError,
+            elems::Button,
+            interaction::{response_action::InputErrors,
+                          BlockActions,
+                          ResponseAction,
+                          ViewSubmission}};
+
+/// The `action_id` of the Back button added to every step but the first
+///
+/// Route `block_actions` with this `action_id` to [`Wizard::back`].
+pub const BACK_ACTION_ID: &str = "wizard_back";
+
+type RenderStep<'h, S> = Box<dyn Fn(&S) -> Vec<Block<'static>> + 'h>;
+type CollectStep<'h, S> =
+  Box<dyn Fn(&mut S, &Modal<'_>) -> Result<(), InputErrors<'static>> + 'h>;
+
+/// The progress of a wizard, stored in each step's `private_metadata`
+#[derive(Deserialize, Serialize)]
+struct Progress<S> {
+  step: usize,
+  answers: S,
+}
+
+/// # Wizard
+///
+/// A multi-step modal, collecting answers of type `S`.
+///
+/// See the [module docs](self) for an example.
+pub struct Wizard<'h, S> {
+  callback_id: String,
+  title: String,
+  next: String,
+  submit: String,
+  back: String,
+  push: bool,
+  #[cfg(feature = "signing")]
+  key: Option<SigningKey>,
+  steps: Vec<(RenderStep<'h, S>, CollectStep<'h, S>)>,
+}
+
+impl<'h, S> Wizard<'h, S> {
+  /// Create a wizard with no steps
+  ///
+  /// Every step is a modal with this `callback_id` and `title`.
+  pub fn new(callback_id: impl ToString, title: impl ToString) -> Self {
+    Self { callback_id: callback_id.to_string(),
+           title: title.to_string(),
+           next: "Next".into(),
+           submit: "Submit".into(),
+           back: "Back".into(),
+           push: false,
+           #[cfg(feature = "signing")]
+           key: None,
+           steps: vec![] }
+  }
+
+  /// Add a step
+  ///
+  /// - `render` builds the step's blocks from the answers collected so far
+  /// - `collect` reads the submitted step into the answers,
+  ///   or rejects the submission with errors to show on its Input blocks
+  pub fn step(mut self,
+              render: impl Fn(&S) -> Vec<Block<'static>> + 'h,
+              collect: impl Fn(&mut S, &Modal<'_>) -> Result<(), InputErrors<'static>>
+                + 'h)
+              -> Self {
+    self.steps.push((Box::new(render), Box::new(collect)));
+    self
+  }
+
+  /// Push each step onto the view stack, rather than replacing
+  /// the previous step.
+  ///
+  /// Each step's close button then goes back to the previous step
+  /// without a round trip to your app, but Slack only allows 3 modals
+  /// in a view stack, so a pushed wizard can have at most 3 steps
+  /// (see [`Wizard::open`]).
+  pub fn push(mut self) -> Self {
+    self.push = true;
+    self
+  }
+
+  /// Seal every step's `private_metadata` with `key` for the wizard's
+  /// `callback_id`, so that the step and answers can't be forged
+  /// by the user's client.
+  ///
+  /// Steps whose metadata wasn't sealed by `key` are rejected
+  /// with [`ValueError::BadSignature`].
+  #[cfg(feature = "signing")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "signing")))]
+  pub fn signed(mut self, key: SigningKey) -> Self {
+    self.key = Some(key);
+    self
+  }
+
+  /// Set the text of the submit button on every step but the last.
+  ///
+  /// Defaults to "Next".
+  pub fn next_text(mut self, next: impl ToString) -> Self {
+    self.next = next.to_string();
+    self
+  }
+
+  /// Set the text of the submit button on the last step.
+  ///
+  /// Defaults to "Submit".
+  pub fn submit_text(mut self, submit: impl ToString) -> Self {
+    self.submit = submit.to_string();
+    self
+  }
+
+  /// Set the text of the button going back to the previous step.
+  ///
+  /// Defaults to "Back".
+  pub fn back_text(mut self, back: impl ToString) -> Self {
+    self.back = back.to_string();
+    self
+  }
+
+  /// The `callback_id` of every step's modal
+  pub fn callback_id(&self) -> &str {
+    &self.callback_id
+  }
+
+  /// The number of steps in this wizard
+  pub fn len(&self) -> usize {
+    self.steps.len()
+  }
+
+  /// Whether this wizard has no steps
+  pub fn is_empty(&self) -> bool {
+    self.steps.is_empty()
+  }
+}
+
+impl<'h, S: Serialize + DeserializeOwned> Wizard<'h, S> {
+  /// Build the first step, to be opened with `views.open`
+  ///
+  /// # Errors
+  /// - [`WizardError::UnknownStep`] if the wizard has no steps
+  /// - [`WizardError::StackFull`] if the wizard pushes its steps,
+  ///   and has more steps than fit in a view stack
+  /// - [`WizardError::Metadata`] if `answers` don't fit in `private_metadata`
+  pub fn open(&self, answers: S) -> Result<Modal<'static>, WizardError> {
+    if self.push && self.steps.len() > MAX_DEPTH {
+      return Err(WizardError::StackFull);
+    }
+
+    self.render(0, &answers)
+  }
+
+  /// Handle the submission of one of this wizard's steps
+  ///
+  /// # Errors
+  /// - [`WizardError::OtherView`] if the modal's `callback_id` is not this wizard's
+  /// - [`WizardError::Metadata`] if the modal's `private_metadata` was not set
+  ///   (or, if the wizard is signed, sealed) by this wizard
+  /// - [`WizardError::UnknownStep`] if the modal's step is not one of this wizard's steps
+  /// - [`WizardError::StackFull`] if the next step can't be pushed onto the view stack
+  pub fn submit(&self,
+                submission: &ViewSubmission<'_>)
+                -> Result<WizardOutcome<'static, S>, WizardError> {
+    let view = submission.view();
+    let Progress { step, mut answers } = self.progress(view)?;
+    let (_, collect) = self.steps
+                           .get(step)
+                           .ok_or(WizardError::UnknownStep { step })?;
+
+    if let Err(errors) = collect(&mut answers, view) {
+      return Ok(WizardOutcome::Respond(errors.into()));
+    }
+
+    if step + 1 == self.steps.len() {
+      return Ok(WizardOutcome::Finished(answers));
+    }
+
+    let view = self.render(step + 1, &answers)?;
+    let action = if self.push {
+      ResponseAction::Push { view }
+    } else {
+      ResponseAction::Update { view }
+    };
+
+    Ok(WizardOutcome::Respond(action))
+  }
+
+  /// Handle a click of the Back button ([`BACK_ACTION_ID`]),
+  /// building the previous step to replace the current one with `views.update`.
+  ///
+  /// Answers given in the current step since it was opened are discarded.
+  ///
+  /// # Errors
+  /// - [`WizardError::OtherView`] if the view's `callback_id` is not this wizard's
+  /// - [`WizardError::Metadata`] if the payload has no view,
+  ///   or the view's `private_metadata` was not set
+  ///   (or, if the wizard is signed, sealed) by this wizard
+  /// - [`WizardError::UnknownStep`] if the view is the first step
+  pub fn back(&self,
+              actions: &BlockActions<'_>)
+              -> Result<Modal<'static>, WizardError> {
+    let Progress { step, answers } = match actions.view() {
+      | Some(super::View::Modal(view)) => self.progress(view)?,
+      | _ => return Err(WizardError::Metadata(ValueError::Missing)),
+    };
+
+    let previous = step.checked_sub(1)
+                       .ok_or(WizardError::UnknownStep { step })?;
+
+    self.render(previous, &answers)
+  }
+
+  fn progress(&self, view: &Modal<'_>) -> Result<Progress<S>, WizardError> {
+    if view.callback_id() != Some(&self.callback_id) {
+      let callback_id = view.callback_id().map(str::to_string);
+      return Err(WizardError::OtherView { callback_id });
+    }
+
+    #[cfg(feature = "signing")]
+    if let Some(key) = &self.key {
+      return view.verified_private_metadata(key, &self.callback_id)
+                 .map_err(WizardError::Metadata);
+    }
+
+    view.typed_private_metadata().map_err(WizardError::Metadata)
+  }
+
+  fn render(&self,
+            step: usize,
+            answers: &S)
+            -> Result<Modal<'static>, WizardError> {
+    let (render, _) = self.steps
+                          .get(step)
+                          .ok_or(WizardError::UnknownStep { step })?;
+
+    if self.push && step >= MAX_DEPTH {
+      return Err(WizardError::StackFull);
+    }
+
+    let mut blocks = render(answers);
+
+    if step > 0 && !self.push {
+      let back = Button::builder().text(self.back.clone())
+                                  .action_id(BACK_ACTION_ID)
+                                  .build();
+      blocks.push(Actions::builder().element(back).build().into());
+    }
+
+    let last = step + 1 == self.steps.len();
+    let progress = Progress { step, answers };
+
+    let modal = Modal::builder().title(self.title.clone())
+                                .blocks(blocks)
+                                .callback_id(self.callback_id.clone())
+                                .submit(if last {
+                                          self.submit.clone()
+                                        } else {
+                                          self.next.clone()
+                                        });
+
+    #[cfg(feature = "signing")]
+    let modal = match &self.key {
+      | Some(key) => {
+        modal.signed_private_metadata(key, &self.callback_id, &progress)
+      },
+      | None => modal.typed_private_metadata(&Metadata::new(progress)),
+    };
+    #[cfg(not(feature = "signing"))]
+    let modal = modal.typed_private_metadata(&Metadata::new(progress));
+
+    let mut modal = modal.map_err(WizardError::Metadata)?;
+
+    if step > 0 && self.push {
+      modal = modal.close(self.back.clone());
+    }
+
+    Ok(modal.build())
+  }
+}
+
+impl<'h, S> fmt::Debug for Wizard<'h, S> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Wizard")
+     .field("callback_id", &self.callback_id)
+     .field("title", &self.title)
+     .field("push", &self.push)
+     .field("steps", &self.steps.len())
+     .finish()
+  }
+}
+
+/// The result of a wizard step being submitted
+#[derive(Clone, Debug, PartialEq)]
+pub enum WizardOutcome<'a, S> {
+  /// Respond to the `view_submission` with this response action;
+  /// either errors on the submitted step, or the next step.
+  Respond(ResponseAction<'a>),
+
+  /// The last step was submitted, and these are the collected answers.
+  ///
+  /// Unless the wizard is [signed](Wizard::signed), these come from
+  /// `private_metadata` that the user's client can edit; don't trust them.
+  ///
+  /// Respond to the `view_submission` with an empty body to close the
+  /// modal, or with [`ResponseAction::Clear`] to close a pushed wizard.
+  Finished(S),
+}
+
+/// Error yielded when a wizard can't handle a payload
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum WizardError {
+  /// The view's `callback_id` is not the wizard's,
+  /// so it is not one of the wizard's steps.
+  OtherView {
+    /// The `callback_id` of the view
+    callback_id: Option<String>,
+  },
+
+  /// The view's `private_metadata` is missing, was not set by a wizard
+  /// or was not sealed by a signed wizard's key,
+  /// or the answers are too big to fit in `private_metadata`.
+  Metadata(ValueError),
+
+  /// The wizard has no step with this index
+  UnknownStep {
+    /// The index of the step
+    step: usize,
+  },
+
+  /// The wizard pushes its steps, and has more steps than Slack allows
+  /// modals in a view stack.
+  StackFull,
+}
+
+impl fmt::Display for WizardError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | WizardError::OtherView { callback_id: Some(id), } => {
+        write!(f, "view `{}` is not a step of this wizard", id)
+      },
+      | WizardError::OtherView { callback_id: None } => {
+        write!(f, "view without a callback_id is not a step of this wizard")
+      },
+      | WizardError::Metadata(e) => write!(f, "wizard metadata: {}", e),
+      | WizardError::UnknownStep { step } => {
+        write!(f, "wizard has no step {}", step)
+      },
+      | WizardError::StackFull => {
+        write!(f, "wizard can't push more than {} steps", MAX_DEPTH)
+      },
+    }
+  }
+}
+
+impl std::error::Error for WizardError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      | WizardError::Metadata(e) => Some(e),
+      | _ => None,
+    }
+  }
+}
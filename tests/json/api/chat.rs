@@ -0,0 +1,92 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{api::chat::{PostEphemeral,
+                               PostMessage,
+                               ScheduleMessage,
+                               Update},
+                   blocks::Block,
+                   surfaces::Message};
+
+#[test]
+pub fn post_message_flattens_message() {
+  let msg = Message::builder().text("Deploy finished")
+                              .block(Block::Divider)
+                              .thread_ts("1623956385.000200")
+                              .build();
+
+  let req = PostMessage::builder().channel("C0123456")
+                                  .message(msg)
+                                  .username("deploybot")
+                                  .icon_emoji(":rocket:")
+                                  .link_names(true)
+                                  .build();
+
+  assert_eq!(PostMessage::METHOD, "chat.postMessage");
+  assert_eq!(serde_json::to_value(&req).unwrap(),
+             json!({
+               "channel": "C0123456",
+               "text": "Deploy finished",
+               "blocks": [{ "type": "divider" }],
+               "thread_ts": "1623956385.000200",
+               "username": "deploybot",
+               "icon_emoji": ":rocket:",
+               "link_names": true
+             }));
+  assert_eq!(req.validate(), Ok(()));
+}
+
+#[test]
+pub fn post_ephemeral_requires_user() {
+  let req = |user: &'static str| {
+    PostEphemeral::builder().channel("C0123456")
+                            .user(user)
+                            .message(Message::builder().text("psst").build())
+                            .build()
+  };
+
+  assert_eq!(serde_json::to_value(req("U0123456")).unwrap(),
+             json!({ "channel": "C0123456", "user": "U0123456", "text": "psst" }));
+  assert_eq!(req("U0123456").validate(), Ok(()));
+  assert!(req("").validate().is_err());
+}
+
+#[test]
+pub fn update_requires_ts_and_rejects_thread_ts() {
+  let ok = Update::builder().channel("C0123456")
+                            .ts("1623956385.000200")
+                            .message(Message::builder().text("edited").build())
+                            .build();
+
+  let no_ts =
+    Update::builder().channel("C0123456")
+                     .ts("")
+                     .message(Message::builder().text("edited").build())
+                     .build();
+
+  let threaded =
+    Update::builder().channel("C0123456")
+                     .ts("1623956385.000200")
+                     .message(Message::builder().text("edited")
+                                                .thread_ts("1623956385.000100")
+                                                .build())
+                     .build();
+
+  assert_eq!(serde_json::to_value(&ok).unwrap(),
+             json!({ "channel": "C0123456", "ts": "1623956385.000200", "text": "edited" }));
+  assert_eq!(ok.validate(), Ok(()));
+  assert!(no_ts.validate().is_err());
+  assert!(threaded.validate().is_err());
+}
+
+#[test]
+pub fn schedule_message_validates_nested_message() {
+  let req = ScheduleMessage::builder().channel("C0123456")
+                                      .post_at(1893456000)
+                                      .message(Message::builder().blocks((0..51).map(|_| Block::Divider))
+                                                                 .build())
+                                      .build();
+
+  assert_eq!(serde_json::to_value(&req).unwrap()["post_at"],
+             json!(1893456000));
+  assert!(req.validate().is_err());
+}
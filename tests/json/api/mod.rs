@@ -0,0 +1,14 @@
+pub mod chat;
+pub use chat::*;
+
+pub mod views;
+pub use views::*;
+
+pub mod response_url;
+pub use response_url::*;
+
+pub mod webhook;
+pub use webhook::*;
+
+pub mod unfurl;
+pub use unfurl::*;
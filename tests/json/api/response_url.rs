@@ -0,0 +1,83 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{api::response_url::{Reply, ResponseType},
+                   blocks::Block,
+                   surfaces::Message};
+
+#[test]
+pub fn in_channel_reply() {
+  let reply =
+    Reply::builder().message(Message::builder().text("Lunch is here!")
+                                               .block(Block::Divider)
+                                               .build())
+                    .response_type(ResponseType::InChannel)
+                    .build();
+
+  assert_eq!(serde_json::to_value(&reply).unwrap(),
+             json!({
+               "text": "Lunch is here!",
+               "blocks": [{ "type": "divider" }],
+               "response_type": "in_channel"
+             }));
+  assert_eq!(reply.validate(), Ok(()));
+}
+
+#[test]
+pub fn delete_original_reply() {
+  let reply = Reply::builder().delete_original(true).build();
+
+  assert_eq!(serde_json::to_value(&reply).unwrap(),
+             json!({ "delete_original": true }));
+  assert_eq!(reply.validate(), Ok(()));
+
+  let deserialized: Reply =
+    serde_json::from_value(serde_json::to_value(&reply).unwrap()).unwrap();
+
+  assert_eq!(deserialized, reply);
+  assert_eq!(deserialized.validate(), Ok(()));
+}
+
+#[test]
+pub fn message_reply_round_trips() {
+  let reply = Reply::builder().message(Message::builder().text("Approved")
+                                                         .build())
+                              .replace_original(true)
+                              .build();
+
+  let deserialized: Reply =
+    serde_json::from_value(serde_json::to_value(&reply).unwrap()).unwrap();
+
+  assert_eq!(deserialized, reply);
+}
+
+#[test]
+pub fn exclusive_flags_are_rejected() {
+  let msg = || Message::builder().text("Approved").build();
+
+  let replace_and_delete = Reply::builder().message(msg())
+                                           .replace_original(true)
+                                           .delete_original(true)
+                                           .build();
+  let delete_with_type = Reply::builder().delete_original(true)
+                                         .response_type(ResponseType::Ephemeral)
+                                         .build();
+  let replace = Reply::builder().message(msg())
+                                .replace_original(true)
+                                .build();
+
+  assert!(replace_and_delete.validate().is_err());
+  assert!(delete_with_type.validate().is_err());
+  assert_eq!(replace.validate(), Ok(()));
+}
+
+#[test]
+pub fn replies_that_do_nothing_are_rejected() {
+  let no_delete = Reply::builder().delete_original(false).build();
+  let delete_with_message =
+    Reply::builder().message(Message::builder().text("Bye").build())
+                    .delete_original(true)
+                    .build();
+
+  assert!(no_delete.validate().is_err());
+  assert!(delete_with_message.validate().is_err());
+}
@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{api::chat::Unfurl,
+                   blocks::{Block, Section},
+                   surfaces::Attachment,
+                   text::ToSlackMarkdown};
+
+#[test]
+pub fn serializes_url_map() {
+  let blocks_unfurl =
+    Attachment::builder().block(Section::builder().text("*INC-42*".markdown())
+                                                  .build())
+                         .build();
+  let legacy_unfurl = Attachment::builder().title("Runbook")
+                                           .text("How to put out fires")
+                                           .build();
+
+  let req =
+    Unfurl::builder().channel("C0123456")
+                     .ts("1623956385.000200")
+                     .unfurl("https://incidents.example.com/42", blocks_unfurl)
+                     .unfurl("https://wiki.example.com/runbook", legacy_unfurl)
+                     .user_auth_message("Please sign in")
+                     .user_auth_block(Block::Divider)
+                     .build();
+
+  assert_eq!(Unfurl::METHOD, "chat.unfurl");
+  assert_eq!(serde_json::to_value(&req).unwrap(),
+             json!({
+               "channel": "C0123456",
+               "ts": "1623956385.000200",
+               "unfurls": {
+                 "https://incidents.example.com/42": {
+                   "blocks": [
+                     {
+                       "type": "section",
+                       "text": { "type": "mrkdwn", "text": "*INC-42*" }
+                     }
+                   ]
+                 },
+                 "https://wiki.example.com/runbook": {
+                   "title": "Runbook",
+                   "text": "How to put out fires"
+                 }
+               },
+               "user_auth_message": "Please sign in",
+               "user_auth_blocks": [{ "type": "divider" }]
+             }));
+  assert_eq!(req.validate(), Ok(()));
+}
+
+#[test]
+pub fn validates_each_unfurl() {
+  let attachment = Attachment::builder().text("hi").color("blurple").build();
+  let req = Unfurl::builder().channel("C0123456")
+                             .ts("1623956385.000200")
+                             .unfurls(vec![("https://example.com", attachment)])
+                             .build();
+
+  assert!(req.validate().is_err());
+}
@@ -0,0 +1,83 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{api::views::{Open, Publish, Push, Update},
+                   blocks::Block,
+                   surfaces::{HomeTab, Modal}};
+
+fn modal() -> Modal<'static> {
+  Modal::builder().title("Hello")
+                  .block(Block::Divider)
+                  .build()
+}
+
+#[test]
+pub fn open_serializes() {
+  let req = Open::builder().trigger_id("12345.98765.abcd2358fdea")
+                           .view(modal())
+                           .build();
+
+  assert_eq!(Open::METHOD, "views.open");
+  assert_eq!(serde_json::to_value(&req).unwrap(),
+             json!({
+               "trigger_id": "12345.98765.abcd2358fdea",
+               "view": {
+                 "type": "modal",
+                 "title": { "type": "plain_text", "text": "Hello" },
+                 "blocks": [{ "type": "divider" }]
+               }
+             }));
+  assert_eq!(req.validate(), Ok(()));
+}
+
+#[test]
+pub fn open_and_push_require_one_trigger() {
+  let empty = Open::builder().trigger_id("").view(modal()).build();
+  let both = Push::builder().trigger_id("12345.98765.abcd2358fdea")
+                            .interactivity_pointer("12345.98765.abcd2358fdea")
+                            .view(modal())
+                            .build();
+  let pointer =
+    Push::builder().interactivity_pointer("12345.98765.abcd2358fdea")
+                   .view(modal())
+                   .build();
+
+  assert!(empty.validate().is_err());
+  assert!(both.validate().is_err());
+  assert_eq!(pointer.validate(), Ok(()));
+}
+
+#[test]
+pub fn update_carries_hash() {
+  let req = Update::builder().view_id("VMM512F2U")
+                             .hash("156772938.1827394")
+                             .view(modal())
+                             .build();
+
+  let json = serde_json::to_value(&req).unwrap();
+
+  assert_eq!(json["view_id"], json!("VMM512F2U"));
+  assert_eq!(json["hash"], json!("156772938.1827394"));
+  assert_eq!(json.get("external_id"), None);
+  assert_eq!(req.validate(), Ok(()));
+
+  let both = Update::builder().view_id("VMM512F2U")
+                              .external_id("my_view")
+                              .view(modal())
+                              .build();
+  assert!(both.validate().is_err());
+}
+
+#[test]
+pub fn publish_validates_home_tab() {
+  let req =
+    Publish::builder().user_id("U0123456")
+                      .view(HomeTab::builder().blocks((0..101).map(|_| {
+                                                                Block::Divider
+                                                              }))
+                                              .build())
+                      .build();
+
+  assert_eq!(serde_json::to_value(&req).unwrap()["view"]["type"],
+             json!("home"));
+  assert!(req.validate().is_err());
+}
@@ -0,0 +1,22 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{api::webhook::Webhook, blocks::Block, surfaces::Message};
+
+#[test]
+pub fn webhook_payload() {
+  let webhook: Webhook = Message::builder().text("Nightly build failed")
+                                           .block(Block::Divider)
+                                           .build()
+                                           .into();
+
+  assert_eq!(serde_json::to_value(&webhook).unwrap(),
+             json!({
+               "text": "Nightly build failed",
+               "blocks": [{ "type": "divider" }]
+             }));
+  assert_eq!(webhook.validate(), Ok(()));
+
+  let broadcast =
+    Webhook::new(Message::builder().text("hi").reply_broadcast(true).build());
+  assert!(broadcast.validate().is_err());
+}
@@ -21,3 +21,6 @@ pub use header::*;
 
 pub mod divider;
 pub use divider::*;
+
+pub mod rich_text;
+pub use rich_text::*;
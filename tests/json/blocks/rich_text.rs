@@ -0,0 +1,204 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::blocks::{rich_text::{inline::{Broadcast,
+                                                BroadcastRange,
+                                                Channel,
+                                                Color,
+                                                Date,
+                                                Emoji,
+                                                Inline,
+                                                Link,
+                                                Text,
+                                                User,
+                                                Usergroup},
+                                       list::ListStyle,
+                                       Element,
+                                       List,
+                                       Preformatted,
+                                       Quote,
+                                       Section},
+                           Block,
+                           RichText};
+
+fn authored() -> serde_json::Value {
+  json!({
+    "type": "rich_text",
+    "block_id": "Vrzsu",
+    "elements": [
+      {
+        "type": "rich_text_section",
+        "elements": [
+          { "type": "text", "text": "Hey " },
+          { "type": "user", "user_id": "U0123ABC" },
+          { "type": "text", "text": ", see ", "style": { "bold": true, "italic": true } },
+          { "type": "link", "url": "https://example.com", "text": "this" },
+          { "type": "emoji", "name": "wave", "unicode": "1f44b" },
+          { "type": "channel", "channel_id": "C0123ABC", "style": { "strike": true } },
+          { "type": "usergroup", "usergroup_id": "S0123ABC" },
+          { "type": "broadcast", "range": "here" },
+          { "type": "date", "timestamp": 1628633089, "format": "{date_short}", "fallback": "Aug 10" },
+          { "type": "color", "value": "#F405B3" }
+        ]
+      },
+      {
+        "type": "rich_text_list",
+        "style": "ordered",
+        "indent": 1,
+        "offset": 2,
+        "border": 1,
+        "elements": [
+          { "type": "rich_text_section", "elements": [{ "type": "text", "text": "one" }] },
+          { "type": "rich_text_section", "elements": [{ "type": "text", "text": "two", "style": { "code": true } }] }
+        ]
+      },
+      {
+        "type": "rich_text_quote",
+        "elements": [{ "type": "text", "text": "To be or not to be" }]
+      },
+      {
+        "type": "rich_text_preformatted",
+        "border": 0,
+        "elements": [{ "type": "text", "text": "fn main() {}" }]
+      }
+    ]
+  })
+}
+
+#[test]
+pub fn rich_text_round_trips() {
+  let block: Block = serde_json::from_value(authored()).unwrap();
+
+  assert_eq!(serde_json::to_value(&block).unwrap(), authored());
+}
+
+#[test]
+pub fn rich_text_tolerates_unknown_elements() {
+  let block: RichText =
+    serde_json::from_value(json!({
+                             "type": "rich_text",
+                             "elements": [
+                               {
+                                 "type": "rich_text_section",
+                                 "elements": [
+                                   { "type": "text", "text": "Ship it " },
+                                   { "type": "team", "team_id": "T0123ABC" }
+                                 ]
+                               },
+                               { "type": "rich_text_table", "rows": [] }
+                             ]
+                           })).unwrap();
+
+  match &block.elements()[0] {
+    | Element::Section(section) => {
+      assert_eq!(section.elements()[1], Inline::Unknown)
+    },
+    | other => panic!("{:?}", other),
+  }
+
+  assert_eq!(block.elements()[1], Element::Unknown);
+}
+
+#[test]
+pub fn rich_text_getters() {
+  let block: RichText = serde_json::from_value(authored()).unwrap();
+
+  let section = match &block.elements()[0] {
+    | Element::Section(section) => section,
+    | other => panic!("{:?}", other),
+  };
+
+  match &section.elements()[2] {
+    | Inline::Text(text) => {
+      assert_eq!(text.text(), ", see ");
+      assert!(text.style().is_bold() && text.style().is_italic());
+      assert!(!text.style().is_code());
+    },
+    | other => panic!("{:?}", other),
+  }
+
+  match &block.elements()[1] {
+    | Element::List(list) => {
+      assert_eq!(list.style(), ListStyle::Ordered);
+      assert_eq!(list.items().len(), 2);
+      assert_eq!((list.indent(), list.offset(), list.border()),
+                 (Some(1), Some(2), Some(1)));
+    },
+    | other => panic!("{:?}", other),
+  }
+
+  assert!(matches!(&block.elements()[2], Element::Quote(_)));
+  assert!(matches!(&block.elements()[3], Element::Preformatted(_)));
+}
+
+#[test]
+pub fn rich_text_builders() {
+  let section =
+    Section::builder().element("Hey ")
+                      .element(User::new("U0123ABC"))
+                      .element(Text::new(", see ").bold().italic())
+                      .element(Link::new("https://example.com").text("this"))
+                      .element(Emoji::new("wave").unicode("1f44b"))
+                      .element(Channel::new("C0123ABC").strike())
+                      .element(Usergroup::new("S0123ABC"))
+                      .element(Broadcast::new(BroadcastRange::Here))
+                      .element(Date::new(1628633089, "{date_short}").fallback("Aug 10"))
+                      .element(Color::new("#F405B3"))
+                      .build();
+
+  let list =
+    List::builder().style(ListStyle::Ordered)
+                   .item(Section::builder().element("one").build())
+                   .item(Section::builder().element(Text::new("two").code())
+                                           .build())
+                   .indent(1)
+                   .offset(2)
+                   .border(1)
+                   .build();
+
+  let block: Block =
+    RichText::builder().block_id("Vrzsu")
+                       .element(section)
+                       .element(list)
+                       .element(Quote::builder().element("To be or not to be")
+                                                .build())
+                       .element(Preformatted::builder().element("fn main() {}")
+                                                       .border(0)
+                                                       .build())
+                       .build()
+                       .into();
+
+  assert_eq!(serde_json::to_value(&block).unwrap(), authored());
+  assert_eq!(block.validate(), Ok(()));
+}
+
+#[test]
+pub fn rich_text_validation() {
+  let invalid = |el: Inline<'static>| {
+    let block = RichText::builder().element(Section::builder().element(el)
+                                                              .build())
+                                   .build();
+    block.validate().is_err()
+  };
+
+  assert!(invalid(Text::new("").into()));
+  assert!(invalid(Link::new("").into()));
+  assert!(invalid(Emoji::new("").into()));
+  assert!(invalid(User::new("").into()));
+  assert!(invalid(Date::new(0, "").into()));
+  assert!(invalid(Color::new("F405B3").into()));
+  assert!(invalid(Color::new("#F405BZ").into()));
+  assert!(!invalid(Color::new("#f405b3").into()));
+
+  let quote = Quote::builder().element("hi").border(2).build();
+  assert!(RichText::builder().element(quote)
+                             .build()
+                             .validate()
+                             .is_err());
+
+  let long_id = "a".repeat(256);
+  let block = RichText::builder().element(Section::builder().element("hi")
+                                                            .build())
+                                 .block_id(long_id)
+                                 .build();
+  assert!(block.validate().is_err());
+}
@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{compose::action_id::{ActionId, ActionIdError},
+                   elems::{select, Button, DatePicker, TextInput},
+                   interaction::BlockActions};
+
+#[derive(ActionId, Clone, Debug, PartialEq)]
+pub enum Review {
+  Approve {
+    ticket: u32,
+  },
+  #[slack(id = "reject")]
+  Deny,
+  AddNote(u32, String),
+}
+
+#[derive(ActionId, Debug, PartialEq)]
+pub enum Section {
+  TicketDetails,
+}
+
+#[test]
+pub fn action_id_encodes_variants() {
+  assert_eq!(Review::Approve { ticket: 42 }.to_action_id(), "approve:42");
+  assert_eq!(Review::Deny.to_action_id(), "reject");
+  assert_eq!(Review::AddNote(7, "a:b".into()).to_action_id(),
+             "add_note:7:a:b");
+  assert_eq!(Section::TicketDetails.to_action_id(), "ticket_details");
+}
+
+#[test]
+pub fn action_id_parses_variants() {
+  let ids = vec![Review::Approve { ticket: 42 },
+                 Review::Deny,
+                 Review::AddNote(7, "a:b".into())];
+
+  for id in ids {
+    assert_eq!(Review::from_action_id(&id.to_action_id()), Ok(id));
+  }
+
+  for bad in &["approve",
+               "approve:forty-two",
+               "Deny",
+               "deny",
+               "reject:1",
+               "add_note:7",
+               ""]
+  {
+    assert_eq!(Review::from_action_id(bad),
+               Err(ActionIdError::Unrecognized { id: bad.to_string() }));
+  }
+}
+
+#[test]
+pub fn action_id_can_be_passed_to_builders() {
+  let review = Review::Approve { ticket: 42 };
+
+  let button = Button::builder().text("Approve").action_id(&review).build();
+  let input = TextInput::builder().action_id(Review::Deny).build();
+  let picker = DatePicker::builder().action_id(review.clone()).build();
+  let select = select::Static::builder().placeholder("Pick")
+                                        .action_id(Review::Deny)
+                                        .options(vec![])
+                                        .build();
+
+  assert_eq!(serde_json::to_value(&button).unwrap()["action_id"],
+             json!("approve:42"));
+  assert_eq!(serde_json::to_value(&input).unwrap()["action_id"],
+             json!("reject"));
+  assert_eq!(serde_json::to_value(&picker).unwrap()["action_id"],
+             json!("approve:42"));
+  assert_eq!(serde_json::to_value(&select).unwrap()["action_id"],
+             json!("reject"));
+}
+
+#[test]
+pub fn action_id_length_is_checked() {
+  let note = Review::AddNote(1, "a".repeat(300));
+
+  assert_eq!(note.checked_action_id(),
+             Err(ActionIdError::TooLong { len: 311 }));
+  assert_eq!(Review::Deny.checked_action_id(), Ok("reject".to_string()));
+}
+
+#[test]
+pub fn action_id_parses_from_payload() {
+  let payload: BlockActions = serde_json::from_value(json!({
+    "team": { "id": "T9TK3CUKW" },
+    "user": { "id": "UA8RXUSPL" },
+    "actions": [{ "type": "button", "action_id": "approve:42", "block_id": "ticket_details" }]
+  })).unwrap();
+
+  let action = &payload.actions()[0];
+  assert_eq!(action.parse_action_id(), Ok(Review::Approve { ticket: 42 }));
+  assert_eq!(action.parse_block_id(), Ok(Section::TicketDetails));
+  assert!(action.parse_block_id::<Review>().is_err());
+}
@@ -1,3 +1,6 @@
+pub mod action_id;
+pub use action_id::*;
+
 pub mod confirm;
 pub use confirm::*;
 
@@ -9,3 +12,9 @@ pub use conversation_filter::*;
 
 pub mod text;
 pub use text::*;
+
+pub mod value;
+pub use value::*;
+
+pub mod signing;
+pub use signing::*;
@@ -0,0 +1,181 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use slack_blocks::{blocks::Block,
+                   compose::{signing::{action_id_context,
+                                       private_metadata_context,
+                                       value_context,
+                                       SigningKey},
+                             value::ValueError,
+                             Opt},
+                   elems::Button,
+                   interaction::{BlockActions, ViewSubmission},
+                   surfaces::Modal};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Refund {
+  order: u32,
+}
+
+fn key() -> SigningKey {
+  SigningKey::new("test signing key")
+}
+
+fn button_payload(action_id: &str, value: &str) -> BlockActions<'static> {
+  serde_json::from_value(json!({
+                           "team": { "id": "T9TK3CUKW" },
+                           "user": { "id": "UA8RXUSPL" },
+                           "actions": [{
+                             "type": "button",
+                             "action_id": action_id,
+                             "block_id": "b",
+                             "value": value
+                           }]
+                         })).unwrap()
+}
+
+#[test]
+pub fn button_signed_value_roundtrip() {
+  let refund = Refund { order: 7 };
+  let button = Button::builder().text("Refund")
+                                .action_id("refund")
+                                .signed_value(&key(), "refund", &refund)
+                                .unwrap()
+                                .build();
+
+  let value = serde_json::to_value(&button).unwrap()["value"].as_str()
+                                                             .unwrap()
+                                                             .to_string();
+  assert!(value.starts_with(r#"{"order":7}."#));
+
+  let payload = button_payload("refund", &value);
+  assert_eq!(payload.actions()[0].verified_value(&key(), "refund"),
+             Ok(Refund { order: 7 }));
+}
+
+#[test]
+pub fn signed_value_fails_closed() {
+  let refund = value_context("refund");
+  let sealed = key().seal(&refund, r#"{"order":7}"#);
+  let other = SigningKey::new("some other key");
+
+  let cases = vec![sealed.replace("7", "8"),
+                   r#"{"order":8}"#.to_string(),
+                   other.seal(&refund, r#"{"order":7}"#),
+                   key().seal(&value_context("approve"), r#"{"order":7}"#),
+                   format!("{}.", r#"{"order":7}"#),
+                   format!("{}AA", sealed),
+                   sealed[..sealed.len() - 1].to_string()];
+
+  for value in cases {
+    let payload = button_payload("refund", &value);
+    assert_eq!(payload.actions()[0].verified_value::<Refund>(&key(), "refund"),
+               Err(ValueError::BadSignature),
+               "{}",
+               value);
+  }
+}
+
+#[test]
+pub fn opt_signed_value_length_accounts_for_tag() {
+  assert_eq!(Opt::builder().text_plain("x")
+                           .signed_value(&key(), "x", &"a".repeat(51))
+                           .map(|_| ())
+                           .unwrap_err(),
+             ValueError::TooLong { max: 75, len: 76 });
+
+  let opt = Opt::builder().text_plain("x")
+                          .signed_value(&key(), "x", &"a".repeat(50))
+                          .unwrap()
+                          .build();
+
+  assert_eq!(opt.value().len(), 75);
+  assert_eq!(opt.verified_value(&key(), "x"), Ok("a".repeat(50)));
+  assert_eq!(opt.typed_value::<String>().is_err(), true);
+}
+
+#[test]
+pub fn action_id_can_be_sealed() {
+  let sealed = key().seal(&action_id_context("b"), "delete_account");
+  let payload = button_payload(&sealed, "");
+  assert_eq!(payload.actions()[0].verified_action_id(&key(), "b"),
+             Ok("delete_account"));
+
+  let payload = button_payload("delete_account", "");
+  assert_eq!(payload.actions()[0].verified_action_id(&key(), "b"),
+             Err(ValueError::BadSignature));
+}
+
+#[test]
+pub fn modal_signed_private_metadata_roundtrip() {
+  let modal = Modal::builder().title("Refund")
+                              .block(Block::Divider)
+                              .signed_private_metadata(&key(),
+                                                       "refund",
+                                                       &Refund { order: 7 })
+                              .unwrap()
+                              .build();
+
+  let mut view = serde_json::to_value(&modal).unwrap();
+  view["id"] = json!("V0PKB1ZFV");
+
+  let payload: ViewSubmission =
+    serde_json::from_value(json!({
+                             "team": { "id": "T9TK3CUKW" },
+                             "user": { "id": "UA8RXUSPL" },
+                             "view": view
+                           })).unwrap();
+
+  assert_eq!(payload.view().verified_private_metadata(&key(), "refund"),
+             Ok(Refund { order: 7 }));
+
+  let unsigned = Modal::builder().title("Refund")
+                                 .block(Block::Divider)
+                                 .private_metadata(r#"{"order":8}"#)
+                                 .build();
+  assert_eq!(unsigned.verified_private_metadata::<Refund>(&key(), "refund"),
+             Err(ValueError::BadSignature));
+  assert_eq!(Modal::builder().title("Refund")
+                             .block(Block::Divider)
+                             .build()
+                             .verified_private_metadata::<Refund>(&key(),
+                                                                  "refund"),
+             Err(ValueError::Missing));
+}
+
+#[test]
+pub fn sealed_values_do_not_open_in_another_context() {
+  let approve = key().seal(&value_context("approve"), "42");
+
+  assert_eq!(key().open(&value_context("approve"), &approve), Ok("42"));
+
+  let other_contexts = vec![value_context("reject"),
+                            action_id_context("approve"),
+                            private_metadata_context("approve"),
+                            String::new()];
+
+  for context in other_contexts {
+    assert_eq!(key().open(&context, &approve),
+               Err(ValueError::BadSignature),
+               "{:?}",
+               context);
+  }
+
+  let payload = button_payload("reject", &approve);
+  assert_eq!(payload.actions()[0].verified_action_id(&key(), "b"),
+             Err(ValueError::BadSignature));
+  assert_eq!(payload.actions()[0].verified_value::<u32>(&key(), "reject"),
+             Err(ValueError::BadSignature));
+  assert_eq!(payload.actions()[0].verified_value::<u32>(&key(), "approve"),
+             Ok(42));
+
+  let modal = Modal::builder().title("Refund")
+                              .block(Block::Divider)
+                              .signed_private_metadata(&key(), "refund", &7)
+                              .unwrap()
+                              .build();
+  assert_eq!(modal.verified_private_metadata::<u32>(&key(), "refund"),
+             Ok(7));
+  assert_eq!(modal.verified_private_metadata::<u32>(&key(), "delete"),
+             Err(ValueError::BadSignature));
+}
@@ -0,0 +1,80 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use slack_blocks::{compose::{value::ValueError, Opt},
+                   elems::Button,
+                   interaction::BlockActions};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum Decision {
+  Approve { ticket: u32 },
+  Deny,
+}
+
+#[test]
+pub fn button_typed_value_roundtrip() {
+  let button = Button::builder().text("Approve")
+                                .action_id("decide")
+                                .typed_value(&Decision::Approve { ticket: 42 })
+                                .unwrap()
+                                .build();
+
+  let value = serde_json::to_value(&button).unwrap()["value"].clone();
+  assert_eq!(value, json!(r#"{"Approve":{"ticket":42}}"#));
+
+  let payload: BlockActions =
+    serde_json::from_value(json!({
+                             "team": { "id": "T9TK3CUKW" },
+                             "user": { "id": "UA8RXUSPL" },
+                             "actions": [{
+                               "type": "button",
+                               "action_id": "decide",
+                               "block_id": "b",
+                               "value": value
+                             }]
+                           })).unwrap();
+
+  assert_eq!(payload.actions()[0].typed_value(),
+             Ok(Decision::Approve { ticket: 42 }));
+  assert!(payload.actions()[0].typed_value::<u32>().is_err());
+}
+
+#[test]
+pub fn select_typed_value_roundtrip() {
+  let opt = Opt::builder().text_plain("Deny")
+                          .typed_value(&Decision::Deny)
+                          .unwrap()
+                          .build();
+
+  let payload: BlockActions = serde_json::from_value(json!({
+    "team": { "id": "T9TK3CUKW" },
+    "user": { "id": "UA8RXUSPL" },
+    "actions": [
+      { "type": "static_select", "action_id": "a", "block_id": "b", "selected_option": opt },
+      { "type": "multi_users_select", "action_id": "c", "block_id": "b", "selected_users": [] }
+    ]
+  })).unwrap();
+
+  assert_eq!(payload.actions()[0].typed_value(), Ok(Decision::Deny));
+  assert_eq!(payload.actions()[1].typed_value::<Decision>(),
+             Err(ValueError::Missing));
+}
+
+#[test]
+pub fn typed_value_length_is_checked_when_building() {
+  let long = "a".repeat(74);
+
+  assert_eq!(Opt::builder().text_plain("x")
+                           .typed_value(&long)
+                           .map(|_| ())
+                           .unwrap_err(),
+             ValueError::TooLong { max: 75, len: 76 });
+  assert!(Opt::builder().text_plain("x")
+                        .typed_value(&"a".repeat(73))
+                        .is_ok());
+
+  assert!(Button::builder().text("x")
+                           .action_id("x")
+                           .typed_value(&"a".repeat(1999))
+                           .is_err());
+}
@@ -46,3 +46,69 @@ pub fn multi_docs_ex_1() {
 
   assert_eq!(actual, expected);
 }
+
+#[test]
+pub fn initial_conversation() {
+  let sel: BlockElement = blox! {
+                            <select choose_from=conversations
+                                    action_id="text1234"
+                                    placeholder="Select an item"
+                                    initial_channel="C0123"
+                            />
+                          }.into();
+
+  let actual = serde_json::to_value(&sel).unwrap();
+  let expected = json!({
+    "action_id": "text1234",
+    "type": "conversations_select",
+    "placeholder": {
+      "type": "plain_text",
+      "text": "Select an item"
+    },
+    "initial_conversation": "C0123"
+  });
+
+  assert_eq!(actual, expected);
+  assert_eq!(serde_json::from_value::<BlockElement>(actual).unwrap(), sel);
+
+  let mut legacy = expected;
+  legacy["initial_channel"] = legacy["initial_conversation"].take();
+  legacy.as_object_mut()
+        .unwrap()
+        .remove("initial_conversation");
+  assert_eq!(serde_json::from_value::<BlockElement>(legacy).unwrap(), sel);
+}
+
+#[test]
+pub fn multi_initial_conversations() {
+  let sel: BlockElement =
+    blox! {
+      <select kind=multi
+              choose_from=conversations
+              action_id="text1234"
+              placeholder="Select conversations"
+              initial_channels=vec!["C0123".to_string(), "D0123".to_string()]
+      />
+    }.into();
+
+  let actual = serde_json::to_value(&sel).unwrap();
+  let expected = json!({
+    "action_id": "text1234",
+    "type": "multi_conversations_select",
+    "placeholder": {
+      "type": "plain_text",
+      "text": "Select conversations"
+    },
+    "initial_conversations": ["C0123", "D0123"]
+  });
+
+  assert_eq!(actual, expected);
+  assert_eq!(serde_json::from_value::<BlockElement>(actual).unwrap(), sel);
+
+  let mut legacy = expected;
+  legacy["initial_channels"] = legacy["initial_conversations"].take();
+  legacy.as_object_mut()
+        .unwrap()
+        .remove("initial_conversations");
+  assert_eq!(serde_json::from_value::<BlockElement>(legacy).unwrap(), sel);
+}
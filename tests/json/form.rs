@@ -0,0 +1,167 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::Input,
+                   form::{Date, FieldError, SlackChoice, SlackForm},
+                   interaction::{ViewState, ViewStateError},
+                   surfaces::Modal};
+
+#[derive(Debug, PartialEq, SlackChoice)]
+pub enum Priority {
+  Low,
+  #[slack(label = "Drop everything", value = "p0")]
+  High,
+}
+
+#[derive(Debug, PartialEq, SlackForm)]
+pub struct Ticket {
+  #[slack(hint = "What went wrong?", placeholder = "e.g. Printer on fire")]
+  title: String,
+  priority: Priority,
+  due_date: Option<Date>,
+  #[slack(label = "Email me updates")]
+  notify_me: bool,
+}
+
+#[test]
+pub fn form_blocks() {
+  let blocks = serde_json::to_value(Ticket::blocks()).unwrap();
+
+  assert_eq!(blocks,
+             json!([
+               {
+                 "type": "input",
+                 "block_id": "title",
+                 "label": { "type": "plain_text", "text": "Title" },
+                 "hint": { "type": "plain_text", "text": "What went wrong?" },
+                 "optional": false,
+                 "element": {
+                   "type": "plain_text_input",
+                   "action_id": "title",
+                   "placeholder": { "type": "plain_text", "text": "e.g. Printer on fire" }
+                 }
+               },
+               {
+                 "type": "input",
+                 "block_id": "priority",
+                 "label": { "type": "plain_text", "text": "Priority" },
+                 "optional": false,
+                 "element": {
+                   "type": "static_select",
+                   "action_id": "priority",
+                   "placeholder": { "type": "plain_text", "text": "Choose an option" },
+                   "options": [
+                     { "text": { "type": "plain_text", "text": "Low" }, "value": "Low" },
+                     { "text": { "type": "plain_text", "text": "Drop everything" }, "value": "p0" }
+                   ]
+                 }
+               },
+               {
+                 "type": "input",
+                 "block_id": "due_date",
+                 "label": { "type": "plain_text", "text": "Due date" },
+                 "optional": true,
+                 "element": { "type": "datepicker", "action_id": "due_date" }
+               },
+               {
+                 "type": "input",
+                 "block_id": "notify_me",
+                 "label": { "type": "plain_text", "text": "Email me updates" },
+                 "optional": true,
+                 "element": {
+                   "type": "checkboxes",
+                   "action_id": "notify_me",
+                   "options": [
+                     { "text": { "type": "plain_text", "text": "Email me updates" }, "value": "true" }
+                   ]
+                 }
+               }
+             ]));
+
+  let modal = Modal::builder().title("New ticket")
+                              .submit("Create")
+                              .blocks(Ticket::blocks())
+                              .build();
+  assert_eq!(modal.validate(), Ok(()));
+}
+
+#[test]
+pub fn parses_submission() {
+  let state: ViewState = serde_json::from_value(json!({
+    "values": {
+      "title": { "title": { "type": "plain_text_input", "value": "Printer on fire" } },
+      "priority": { "priority": {
+        "type": "static_select",
+        "selected_option": { "text": { "type": "plain_text", "text": "Drop everything" }, "value": "p0" }
+      } },
+      "due_date": { "due_date": { "type": "datepicker", "selected_date": "2021-04-28" } },
+      "notify_me": { "notify_me": {
+        "type": "checkboxes",
+        "selected_options": [{ "text": { "type": "plain_text", "text": "Email me updates" }, "value": "true" }]
+      } }
+    }
+  })).unwrap();
+
+  assert_eq!(Ticket::from_view_state(&state),
+             Ok(Ticket { title: "Printer on fire".into(),
+                         priority: Priority::High,
+                         due_date: Some(Date { year: 2021,
+                                               month: 4,
+                                               day: 28 }),
+                         notify_me: true }));
+}
+
+#[test]
+pub fn field_level_errors() {
+  let state: ViewState = serde_json::from_value(json!({
+    "values": {
+      "title": { "title": { "type": "plain_text_input", "value": null } },
+      "priority": { "priority": {
+        "type": "static_select",
+        "selected_option": { "text": { "type": "plain_text", "text": "Meh" }, "value": "p5" }
+      } },
+      "due_date": { "due_date": { "type": "datepicker", "selected_date": null } }
+    }
+  })).unwrap();
+
+  let errors = Ticket::from_view_state(&state).unwrap_err();
+
+  assert_eq!(errors.errors().len(), 3);
+  assert_eq!(errors.get("title"), Some(&FieldError::Required));
+  assert_eq!(errors.get("priority"),
+             Some(&FieldError::Invalid("`p5` is not one of the choices".into())));
+  assert_eq!(errors.get("notify_me"),
+             Some(&FieldError::State(ViewStateError::MissingBlock { block_id: "notify_me".into() })));
+  assert_eq!(errors.get("due_date"), None);
+
+  let modal = Modal::builder().title("New ticket")
+                              .submit("Create")
+                              .blocks(Ticket::blocks())
+                              .build();
+  let input_errors = errors.input_errors(&modal).unwrap();
+  assert_eq!(input_errors.errors().get("title").map(|m| m.as_ref()),
+             Some("This field is required"));
+
+  let other = Modal::builder().title("Other")
+                              .submit("Go")
+                              .blocks(Vec::<Input>::new())
+                              .build();
+  assert!(errors.input_errors(&other).is_err());
+}
+
+#[test]
+pub fn impossible_dates_are_rejected() {
+  for date in vec!["2021-02-29",
+                   "2021-02-31",
+                   "2021-04-31",
+                   "1900-02-29",
+                   "2021-00-10",
+                   "2021-01-32"]
+  {
+    assert_eq!(date.parse::<Date>(),
+               Err(FieldError::Invalid(format!("`{}` is not a date", date))));
+  }
+
+  for date in vec!["2020-02-29", "2000-02-29", "2021-12-31"] {
+    assert_eq!(date.parse::<Date>().unwrap().to_string(), date);
+  }
+}
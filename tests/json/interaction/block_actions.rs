@@ -0,0 +1,218 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{compose::Opt,
+                   interaction::{block_actions::ActionKind,
+                                 BlockActions,
+                                 Container},
+                   surfaces::View};
+
+#[test]
+pub fn block_actions_from_message() {
+  let payload = json!({
+    "type": "block_actions",
+    "team": { "id": "T9TK3CUKW", "domain": "example" },
+    "user": {
+      "id": "UA8RXUSPL",
+      "username": "jtorrance",
+      "name": "jtorrance",
+      "team_id": "T9TK3CUKW"
+    },
+    "api_app_id": "AABA1ABCD",
+    "token": "9s8d9as89d8as9d8as989",
+    "container": {
+      "type": "message",
+      "message_ts": "1548426417.840180",
+      "channel_id": "CBR2V3XEX",
+      "is_ephemeral": false
+    },
+    "trigger_id": "12345.98765.abcd2358fdea",
+    "channel": { "id": "CBR2V3XEX", "name": "review-updates" },
+    "message": {
+      "type": "message",
+      "bot_id": "BAH5CA16Z",
+      "text": "This content can't be displayed.",
+      "ts": "1548261231.000200",
+      "blocks": [{ "type": "divider", "block_id": "div" }]
+    },
+    "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+    "actions": [
+      {
+        "type": "button",
+        "action_id": "approve",
+        "block_id": "request",
+        "text": { "type": "plain_text", "text": "Approve", "emoji": true },
+        "value": "req_42",
+        "action_ts": "1548426417.840180"
+      },
+      {
+        "type": "static_select",
+        "action_id": "priority",
+        "block_id": "request",
+        "selected_option": {
+          "text": { "type": "plain_text", "text": "High" },
+          "value": "high"
+        },
+        "action_ts": "1548426417.840181"
+      },
+      {
+        "type": "multi_users_select",
+        "action_id": "reviewers",
+        "block_id": "request",
+        "selected_users": ["U061F7AUR", "U0G9QF9C6"],
+        "action_ts": "1548426417.840182"
+      },
+      {
+        "type": "datepicker",
+        "action_id": "due",
+        "block_id": "request",
+        "selected_date": "2021-04-28",
+        "initial_date": "2021-04-01",
+        "action_ts": "1548426417.840183"
+      },
+      {
+        "type": "some_future_element",
+        "action_id": "future",
+        "block_id": "request"
+      }
+    ]
+  });
+
+  let payload: BlockActions = serde_json::from_value(payload).unwrap();
+
+  assert_eq!(payload.team().id(), "T9TK3CUKW");
+  assert_eq!(payload.user().username(), Some("jtorrance"));
+  assert_eq!(payload.trigger_id(), Some("12345.98765.abcd2358fdea"));
+  assert_eq!(payload.channel().map(|c| c.id()), Some("CBR2V3XEX"));
+  assert!(payload.message().is_some());
+  assert!(payload.view().is_none());
+  assert!(payload.response_url().is_some());
+  assert_eq!(payload.container(),
+             Some(&Container::Message { message_ts:
+                                          "1548426417.840180".into(),
+                                        channel_id: "CBR2V3XEX".into(),
+                                        is_ephemeral: false }));
+
+  let actions = payload.actions();
+  assert_eq!(actions.len(), 5);
+  assert!(actions.iter().all(|a| a.block_id() == "request"));
+
+  assert_eq!(actions[0].action_id(), "approve");
+  match actions[0].kind() {
+    | ActionKind::Button { value, .. } => {
+      assert_eq!(value.as_deref(), Some("req_42"))
+    },
+    | other => panic!("expected button, got {:?}", other),
+  }
+
+  let high: Opt = Opt::builder().text_plain("High")
+                                .value("high")
+                                .build()
+                                .into();
+  assert_eq!(actions[1].kind(),
+             &ActionKind::SelectStatic { selected_option: Some(high) });
+
+  assert_eq!(actions[2].kind(),
+             &ActionKind::MultiSelectUser { selected_users:
+                                              vec!["U061F7AUR".into(),
+                                                   "U0G9QF9C6".into()] });
+
+  assert_eq!(actions[3].kind(),
+             &ActionKind::DatePicker { selected_date:
+                                         Some("2021-04-28".into()) });
+
+  assert_eq!(actions[4].kind(), &ActionKind::Unknown);
+}
+
+#[test]
+pub fn block_actions_from_modal() {
+  let payload = json!({
+    "type": "block_actions",
+    "team": { "id": "T9TK3CUKW", "domain": "example" },
+    "user": { "id": "UA8RXUSPL", "team_id": "T9TK3CUKW" },
+    "api_app_id": "AABA1ABCD",
+    "container": { "type": "view", "view_id": "V0PKB1ZFV" },
+    "trigger_id": "12345.98765.abcd2358fdea",
+    "view": {
+      "id": "V0PKB1ZFV",
+      "hash": "156663117.cd33ad1f",
+      "type": "modal",
+      "title": { "type": "plain_text", "text": "Request" },
+      "blocks": [],
+      "callback_id": "request"
+    },
+    "actions": [
+      {
+        "type": "checkboxes",
+        "action_id": "flags",
+        "block_id": "opts",
+        "selected_options": [
+          { "text": { "type": "plain_text", "text": "Urgent" }, "value": "urgent" }
+        ]
+      }
+    ]
+  });
+
+  let payload: BlockActions = serde_json::from_value(payload).unwrap();
+
+  assert!(payload.channel().is_none());
+  assert!(payload.response_url().is_none());
+  assert_eq!(payload.container(),
+             Some(&Container::View { view_id: "V0PKB1ZFV".into() }));
+
+  let view = payload.view().unwrap();
+  assert!(matches!(view, View::Modal(_)));
+  assert_eq!(view.id(), Some("V0PKB1ZFV"));
+  assert_eq!(view.hash(), Some("156663117.cd33ad1f"));
+
+  let urgent: Opt = Opt::builder().text_plain("Urgent")
+                                  .value("urgent")
+                                  .build()
+                                  .into();
+  assert_eq!(payload.actions()[0].kind(),
+             &ActionKind::Checkboxes { selected_options: vec![urgent] });
+}
+
+#[test]
+pub fn block_actions_tolerates_unknown_blocks() {
+  let payload = json!({
+    "type": "block_actions",
+    "team": { "id": "T9TK3CUKW" },
+    "user": { "id": "UA8RXUSPL" },
+    "container": {
+      "type": "message",
+      "message_ts": "1548426417.840180",
+      "channel_id": "CBR2V3XEX"
+    },
+    "message": {
+      "type": "message",
+      "text": "Watch this",
+      "blocks": [
+        { "type": "divider" },
+        {
+          "type": "video",
+          "title": { "type": "plain_text", "text": "How to use Slack." },
+          "video_url": "https://www.youtube.com/embed/RRxQQxiM7AA",
+          "thumbnail_url": "https://i.ytimg.com/vi/RRxQQxiM7AA/hqdefault.jpg",
+          "alt_text": "How to use Slack?"
+        },
+        { "type": "call", "call_id": "R01234" }
+      ]
+    },
+    "actions": [{
+      "type": "button",
+      "action_id": "watched",
+      "block_id": "video",
+      "value": "yes"
+    }]
+  });
+
+  let payload: BlockActions = serde_json::from_value(payload).unwrap();
+
+  assert_eq!(payload.actions()[0].value(), Some("yes"));
+
+  let message = serde_json::to_value(payload.message().unwrap()).unwrap();
+  assert_eq!(message["blocks"],
+             json!([{ "type": "divider" },
+                    { "type": "unknown" },
+                    { "type": "unknown" }]));
+}
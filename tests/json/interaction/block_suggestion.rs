@@ -0,0 +1,107 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{compose::{Opt, OptGroup},
+                   interaction::{BlockSuggestion,
+                                 Container,
+                                 OptionsResponse}};
+
+#[test]
+pub fn block_suggestion_from_modal() {
+  let payload: BlockSuggestion = serde_json::from_value(json!({
+    "type": "block_suggestion",
+    "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+    "container": { "type": "view", "view_id": "VNHU13V36" },
+    "api_app_id": "AABA1ABCD",
+    "token": "9s8d9as89d8as9d8as989",
+    "action_id": "pick_ticket",
+    "block_id": "ticket",
+    "value": "JIRA-12",
+    "team": { "id": "T9TK3CUKW", "domain": "example" },
+    "view": {
+      "id": "VNHU13V36",
+      "type": "modal",
+      "title": { "type": "plain_text", "text": "Link a ticket" },
+      "blocks": []
+    }
+  })).unwrap();
+
+  assert_eq!(payload.action_id(), "pick_ticket");
+  assert_eq!(payload.block_id(), "ticket");
+  assert_eq!(payload.value(), "JIRA-12");
+  assert_eq!(payload.container(),
+             Some(&Container::View { view_id: "VNHU13V36".into() }));
+  assert_eq!(payload.view().and_then(|v| v.id()), Some("VNHU13V36"));
+  assert!(payload.channel().is_none());
+
+  assert!(payload.meets_min_query_length(None));
+  assert!(payload.meets_min_query_length(Some(7)));
+  assert!(!payload.meets_min_query_length(Some(8)));
+}
+
+#[test]
+pub fn options_response_serializes_options_or_groups() {
+  let apple = || Opt::builder().text_plain("Apple").value("apple").build();
+
+  assert_eq!(serde_json::to_value(OptionsResponse::options(vec![apple()])).unwrap(),
+             json!({
+               "options": [
+                 { "text": { "type": "plain_text", "text": "Apple" }, "value": "apple" }
+               ]
+             }));
+
+  let fruit = OptGroup::builder().label("Fruit").option(apple()).build();
+  assert_eq!(serde_json::to_value(OptionsResponse::option_groups(vec![fruit])).unwrap(),
+             json!({
+               "option_groups": [{
+                 "label": { "type": "plain_text", "text": "Fruit" },
+                 "options": [
+                   { "text": { "type": "plain_text", "text": "Apple" }, "value": "apple" }
+                 ]
+               }]
+             }));
+
+  assert_eq!(serde_json::to_value(OptionsResponse::empty()).unwrap(),
+             json!({ "options": [] }));
+}
+
+#[test]
+pub fn options_response_limits() {
+  let opt = |i: usize| {
+    Opt::builder().text_plain(i.to_string())
+                  .value(i.to_string())
+                  .build()
+  };
+
+  assert_eq!(OptionsResponse::options((0..100).map(opt)).validate(),
+             Ok(()));
+  assert!(OptionsResponse::options((0..101).map(opt)).validate()
+                                                     .is_err());
+
+  let group = |i: usize, n: usize| {
+    OptGroup::builder().label(i.to_string())
+                       .options((0..n).map(opt))
+                       .build()
+  };
+
+  assert_eq!(OptionsResponse::option_groups((0..100).map(|i| group(i, 1))).validate(),
+             Ok(()));
+  assert!(OptionsResponse::option_groups((0..101).map(|i| group(i, 1))).validate()
+                                                                        .is_err());
+  assert!(OptionsResponse::option_groups(vec![group(0, 101)]).validate()
+                                                             .is_err());
+}
+
+#[test]
+pub fn options_response_validates_each_option() {
+  let long_text = Opt::builder().text_plain("a".repeat(76)).value("a").build();
+  let long_value = Opt::builder().text_plain("a").value("a".repeat(76)).build();
+
+  for opt in vec![long_text, long_value] {
+    assert!(OptionsResponse::options(vec![opt.clone()]).validate()
+                                                       .is_err());
+
+    let group = OptGroup::builder().label("Group").option(opt).build();
+    assert!(OptionsResponse::option_groups(vec![group]).validate()
+                                                       .is_err());
+  }
+}
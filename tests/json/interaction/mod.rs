@@ -0,0 +1,20 @@
+pub mod block_actions;
+pub use block_actions::*;
+
+pub mod view_state;
+pub use view_state::*;
+
+pub mod block_suggestion;
+pub use block_suggestion::*;
+
+pub mod payload;
+pub use payload::*;
+
+pub mod response_action;
+pub use response_action::*;
+
+pub mod router;
+pub use router::*;
+
+pub mod verify;
+pub use verify::*;
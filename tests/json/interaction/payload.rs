@@ -0,0 +1,169 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::interaction::InteractionPayload;
+
+fn team() -> serde_json::Value {
+  json!({ "id": "T9TK3CUKW", "domain": "example" })
+}
+
+fn user() -> serde_json::Value {
+  json!({ "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" })
+}
+
+fn modal() -> serde_json::Value {
+  json!({
+    "id": "VNHU13V36",
+    "type": "modal",
+    "title": { "type": "plain_text", "text": "Feedback" },
+    "blocks": [],
+    "callback_id": "feedback",
+    "notify_on_close": true
+  })
+}
+
+#[test]
+pub fn dispatches_view_closed() {
+  let payload: InteractionPayload =
+    serde_json::from_value(json!({
+                             "type": "view_closed",
+                             "team": team(),
+                             "user": user(),
+                             "api_app_id": "AABA1ABCD",
+                             "view": modal(),
+                             "is_cleared": false
+                           })).unwrap();
+
+  match payload {
+    | InteractionPayload::ViewClosed(closed) => {
+      assert_eq!(closed.view().id(), Some("VNHU13V36"));
+      assert!(!closed.is_cleared());
+    },
+    | other => panic!("expected view_closed, got {:?}", other),
+  }
+}
+
+#[test]
+pub fn dispatches_shortcut() {
+  let payload: InteractionPayload = serde_json::from_value(json!({
+    "type": "shortcut",
+    "token": "XXXXXXXXXXXXX",
+    "action_ts": "1581106241.371594",
+    "team": team(),
+    "user": user(),
+    "callback_id": "shortcut_create_task",
+    "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+  })).unwrap();
+
+  match payload {
+    | InteractionPayload::Shortcut(shortcut) => {
+      assert_eq!(shortcut.callback_id(), "shortcut_create_task");
+      assert_eq!(shortcut.action_ts(), Some("1581106241.371594"));
+      assert_eq!(shortcut.user().id(), "UA8RXUSPL");
+    },
+    | other => panic!("expected shortcut, got {:?}", other),
+  }
+}
+
+#[test]
+pub fn dispatches_message_action() {
+  let payload: InteractionPayload = serde_json::from_value(json!({
+    "type": "message_action",
+    "callback_id": "create_ticket",
+    "trigger_id": "12345.98765.abcd2358fdea",
+    "response_url": "https://hooks.slack.com/app-actions/T9TK3CUKW/123/abc",
+    "message_ts": "1548261231.000200",
+    "team": team(),
+    "user": user(),
+    "channel": { "id": "CBR2V3XEX", "name": "review-updates" },
+    "message": {
+      "type": "message",
+      "user": "U061F7AUR",
+      "text": "The printer is on fire again",
+      "ts": "1548261231.000200"
+    }
+  })).unwrap();
+
+  match payload {
+    | InteractionPayload::MessageAction(action) => {
+      assert_eq!(action.callback_id(), "create_ticket");
+      assert_eq!(action.channel().id(), "CBR2V3XEX");
+      assert_eq!(action.message_ts(), "1548261231.000200");
+    },
+    | other => panic!("expected message_action, got {:?}", other),
+  }
+}
+
+#[test]
+pub fn message_action_tolerates_unknown_blocks() {
+  let payload: InteractionPayload = serde_json::from_value(json!({
+    "type": "message_action",
+    "callback_id": "create_ticket",
+    "trigger_id": "12345.98765.abcd2358fdea",
+    "response_url": "https://hooks.slack.com/app-actions/T9TK3CUKW/123/abc",
+    "message_ts": "1548261231.000200",
+    "team": team(),
+    "user": user(),
+    "channel": { "id": "CBR2V3XEX" },
+    "message": {
+      "type": "message",
+      "user": "U061F7AUR",
+      "text": "Join the huddle",
+      "ts": "1548261231.000200",
+      "blocks": [{ "type": "call", "call_id": "R01234" }]
+    }
+  })).unwrap();
+
+  assert!(matches!(payload, InteractionPayload::MessageAction(_)));
+}
+
+#[test]
+pub fn dispatches_other_payloads() {
+  let actions = json!({
+    "type": "block_actions",
+    "team": team(),
+    "user": user(),
+    "actions": []
+  });
+  let submission = json!({
+    "type": "view_submission",
+    "team": team(),
+    "user": user(),
+    "view": modal()
+  });
+  let suggestion = json!({
+    "type": "block_suggestion",
+    "team": team(),
+    "user": user(),
+    "action_id": "a",
+    "block_id": "b",
+    "value": "abc"
+  });
+
+  assert!(matches!(serde_json::from_value(actions).unwrap(),
+                   InteractionPayload::BlockActions(_)));
+  assert!(matches!(serde_json::from_value(submission).unwrap(),
+                   InteractionPayload::ViewSubmission(_)));
+  assert!(matches!(serde_json::from_value(suggestion).unwrap(),
+                   InteractionPayload::BlockSuggestion(_)));
+}
+
+#[test]
+pub fn unknown_payloads_deserialize() {
+  let payload: InteractionPayload =
+    serde_json::from_value(json!({ "type": "workflow_step_edit" })).unwrap();
+
+  assert_eq!(payload, InteractionPayload::Unknown);
+}
+
+#[test]
+pub fn serializes_type_tag() {
+  let payload: InteractionPayload = serde_json::from_value(json!({
+                                                             "type": "shortcut",
+                                                             "team": team(),
+                                                             "user": user(),
+                                                             "callback_id": "a",
+                                                             "trigger_id": "b"
+                                                           })).unwrap();
+
+  assert_eq!(serde_json::to_value(&payload).unwrap()["type"], "shortcut");
+}
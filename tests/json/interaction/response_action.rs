@@ -0,0 +1,77 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::{Block, Input, Section},
+                   elems::TextInput,
+                   interaction::response_action::{InputErrors,
+                                                  ResponseAction},
+                   surfaces::Modal,
+                   text::ToSlackMarkdown};
+
+fn view() -> Modal<'static> {
+  let input = |block_id: &'static str| {
+    Input::builder().label(block_id)
+                    .element(TextInput::builder().action_id(block_id).build())
+                    .block_id(block_id)
+                    .build()
+  };
+
+  Modal::builder().title("Sign up")
+                  .submit("Go")
+                  .blocks(vec![Section::builder().text("Hello".markdown())
+                                                 .block_id("intro")
+                                                 .build()
+                                                 .into(),
+                               Block::from(input("email")),
+                               Block::from(input("name"))])
+                  .build()
+}
+
+#[test]
+pub fn errors_reference_input_blocks() {
+  let errors = InputErrors::for_view(&view()).error("email", "Invalid email")
+                                             .unwrap()
+                                             .error("name", "Required")
+                                             .unwrap();
+
+  assert_eq!(serde_json::to_value(ResponseAction::from(errors)).unwrap(),
+             json!({
+               "response_action": "errors",
+               "errors": { "email": "Invalid email", "name": "Required" }
+             }));
+
+  let unknown = InputErrors::for_view(&view()).error("phone", "Required")
+                                              .unwrap_err();
+  assert_eq!(unknown.block_id(), "phone");
+
+  // Section blocks can't show errors
+  assert!(InputErrors::for_view(&view()).error("intro", "Nope")
+                                        .is_err());
+}
+
+#[test]
+pub fn error_message_length() {
+  let ok = InputErrors::for_view(&view()).error("email", "a".repeat(150))
+                                         .unwrap();
+  assert_eq!(ok.validate(), Ok(()));
+
+  let long = InputErrors::for_view(&view()).error("email", "a".repeat(151))
+                                           .unwrap();
+  assert!(long.validate().is_err());
+  assert!(ResponseAction::from(long).validate().is_err());
+}
+
+#[test]
+pub fn update_push_and_clear() {
+  let update = ResponseAction::Update { view: view() };
+  let json = serde_json::to_value(&update).unwrap();
+  assert_eq!(json["response_action"], "update");
+  assert_eq!(json["view"]["type"], "modal");
+  assert_eq!(update.validate(), Ok(()));
+
+  let push = ResponseAction::Push { view: view() };
+  assert_eq!(serde_json::to_value(&push).unwrap()["response_action"],
+             "push");
+
+  assert_eq!(serde_json::to_value(ResponseAction::Clear).unwrap(),
+             json!({ "response_action": "clear" }));
+}
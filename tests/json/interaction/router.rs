@@ -0,0 +1,157 @@
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+use slack_blocks::interaction::{router::{Pattern, Route, Router},
+                                InteractionPayload};
+
+fn block_actions(view: Option<Value>,
+                 block_id: &str,
+                 action_id: &str)
+                 -> InteractionPayload<'static> {
+  let mut payload = json!({
+    "type": "block_actions",
+    "team": { "id": "T9TK3CUKW" },
+    "user": { "id": "UA8RXUSPL" },
+    "actions": [{
+      "type": "button",
+      "block_id": block_id,
+      "action_id": action_id,
+      "value": "v"
+    }]
+  });
+
+  if let Some(view) = view {
+    payload["view"] = view;
+  }
+
+  serde_json::from_value(payload).unwrap()
+}
+
+fn modal(callback_id: &str) -> Value {
+  json!({
+    "id": "VNHU13V36",
+    "type": "modal",
+    "title": { "type": "plain_text", "text": "Ticket" },
+    "blocks": [],
+    "callback_id": callback_id
+  })
+}
+
+fn router() -> Router<'static, &'static str> {
+  Router::new().on_action(Route::new().callback_id("settings")
+                                      .action_id("save"),
+                          |_, _| "settings save")
+               .on_action("save", |_, _| "save")
+               .on_action(Route::new().block_id(Pattern::prefix("ticket_")),
+                          |_, _| "ticket")
+               .on_action(Pattern::glob("page_*"), |_, _| "page")
+               .on_suggestion(Pattern::glob("pick_*"), |_| "suggest")
+               .on_view_submission("new_ticket", |_| "submit")
+               .on_view_closed(Pattern::Any, |_| "closed")
+               .on_shortcut(Pattern::glob("create_*"), |_| "shortcut")
+               .on_message_action(Pattern::exact("file_bug"), |_| "file bug")
+}
+
+#[test]
+pub fn routes_actions_by_id() {
+  let router = router();
+
+  assert_eq!(router.dispatch(&block_actions(None, "b", "save")),
+             Some("save"));
+  assert_eq!(router.dispatch(&block_actions(Some(modal("settings")),
+                                            "b",
+                                            "save")),
+             Some("settings save"));
+  assert_eq!(router.dispatch(&block_actions(None, "ticket_42", "close")),
+             Some("ticket"));
+  assert_eq!(router.dispatch(&block_actions(None, "b", "page_3")),
+             Some("page"));
+  assert_eq!(router.dispatch(&block_actions(None, "b", "unknown")), None);
+}
+
+#[test]
+pub fn routes_views_and_shortcuts_by_callback_id() {
+  let router = router();
+
+  let submission = serde_json::from_value(json!({
+                                            "type": "view_submission",
+                                            "team": { "id": "T9TK3CUKW" },
+                                            "user": { "id": "UA8RXUSPL" },
+                                            "view": modal("new_ticket")
+                                          })).unwrap();
+  assert_eq!(router.dispatch(&submission), Some("submit"));
+
+  let other_submission = serde_json::from_value(json!({
+                                                  "type": "view_submission",
+                                                  "team": { "id": "T9TK3CUKW" },
+                                                  "user": { "id": "UA8RXUSPL" },
+                                                  "view": modal("settings")
+                                                })).unwrap();
+  assert_eq!(router.dispatch(&other_submission), None);
+
+  let closed = serde_json::from_value(json!({
+                                        "type": "view_closed",
+                                        "team": { "id": "T9TK3CUKW" },
+                                        "user": { "id": "UA8RXUSPL" },
+                                        "view": modal("anything")
+                                      })).unwrap();
+  assert_eq!(router.dispatch(&closed), Some("closed"));
+
+  let shortcut = serde_json::from_value(json!({
+                                          "type": "shortcut",
+                                          "team": { "id": "T9TK3CUKW" },
+                                          "user": { "id": "UA8RXUSPL" },
+                                          "callback_id": "create_task",
+                                          "trigger_id": "t"
+                                        })).unwrap();
+  assert_eq!(router.dispatch(&shortcut), Some("shortcut"));
+
+  let suggestion = serde_json::from_value(json!({
+                                            "type": "block_suggestion",
+                                            "team": { "id": "T9TK3CUKW" },
+                                            "user": { "id": "UA8RXUSPL" },
+                                            "block_id": "b",
+                                            "action_id": "pick_user",
+                                            "value": "jac"
+                                          })).unwrap();
+  assert_eq!(router.dispatch(&suggestion), Some("suggest"));
+
+  assert_eq!(router.dispatch(&InteractionPayload::Unknown), None);
+}
+
+#[test]
+pub fn glob_patterns() {
+  let glob = Pattern::glob("ticket_*_close");
+
+  assert!(glob.matches("ticket_42_close"));
+  assert!(glob.matches("ticket__close"));
+  assert!(!glob.matches("ticket_42_open"));
+  assert!(Pattern::glob("*").matches(""));
+  assert!(Pattern::glob("a*b*c").matches("axxbyyc"));
+  assert!(!Pattern::glob("a*b*c").matches("axxbyy"));
+  assert_eq!(Pattern::from("save"), Pattern::exact("save"));
+}
+
+#[test]
+pub fn str_patterns_are_always_exact() {
+  assert_eq!(Pattern::from("why?"), Pattern::exact("why?"));
+  assert_eq!(Pattern::from("page_*"), Pattern::exact("page_*"));
+  assert!(Pattern::from("why?").matches("why?"));
+  assert!(!Pattern::from("why?").matches("whyX"));
+
+  let router = Router::new().on_action("why?", |_, _| "why");
+
+  assert_eq!(router.dispatch(&block_actions(None, "b", "why?")),
+             Some("why"));
+  assert_eq!(router.dispatch(&block_actions(None, "b", "whyX")), None);
+}
+
+#[test]
+pub fn any_callback_id_matches_payloads_without_a_view() {
+  let any = Router::new().on_action(Route::new().callback_id(Pattern::Any),
+                                    |_, _| "any");
+  let settings = Router::new().on_action(Route::new().callback_id("settings"),
+                                         |_, _| "settings");
+
+  assert_eq!(any.dispatch(&block_actions(None, "b", "a")), Some("any"));
+  assert_eq!(settings.dispatch(&block_actions(None, "b", "a")), None);
+}
@@ -0,0 +1,79 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use pretty_assertions::assert_eq;
+use slack_blocks::interaction::verify::{SigningSecret, VerifyError};
+
+// https://api.slack.com/authentication/verifying-requests-from-slack
+const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+const TIMESTAMP: &str = "1531420618";
+const SIGNATURE: &str =
+  "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+
+fn at(secs: u64) -> std::time::SystemTime {
+  UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[test]
+pub fn verifies_slack_example_request() {
+  let secret = SigningSecret::new(SECRET);
+
+  assert_eq!(secret.sign(TIMESTAMP, BODY.as_bytes()), SIGNATURE);
+  assert_eq!(secret.verify_at(TIMESTAMP,
+                              SIGNATURE,
+                              BODY.as_bytes(),
+                              at(1531420618 + 60)),
+             Ok(()));
+}
+
+#[test]
+pub fn rejects_tampered_requests() {
+  let secret = SigningSecret::new(SECRET);
+  let now = at(1531420618);
+
+  let verify = |timestamp: &str, signature: &str, body: &str| {
+    secret.verify_at(timestamp, signature, body.as_bytes(), now)
+  };
+
+  assert_eq!(verify(TIMESTAMP, SIGNATURE, &BODY.replace("foobar", "foobaz")),
+             Err(VerifyError::Mismatch));
+  assert_eq!(verify("1531420619", SIGNATURE, BODY),
+             Err(VerifyError::Mismatch));
+  assert_eq!(verify(TIMESTAMP, &SIGNATURE[..SIGNATURE.len() - 2], BODY),
+             Err(VerifyError::Mismatch));
+  assert_eq!(verify(TIMESTAMP, &SIGNATURE.replace("v0=", "v1="), BODY),
+             Err(VerifyError::UnsupportedVersion));
+  assert_eq!(verify(TIMESTAMP, "a2114d57b48eac39", BODY),
+             Err(VerifyError::MalformedSignature));
+  assert_eq!(verify(TIMESTAMP, "v0=not hex", BODY),
+             Err(VerifyError::MalformedSignature));
+  assert_eq!(verify("yesterday", SIGNATURE, BODY),
+             Err(VerifyError::InvalidTimestamp));
+  assert_eq!(SigningSecret::new("wrong secret").verify_at(TIMESTAMP,
+                                                          SIGNATURE,
+                                                          BODY.as_bytes(),
+                                                          now),
+             Err(VerifyError::Mismatch));
+}
+
+#[test]
+pub fn rejects_requests_outside_replay_window() {
+  let secret = SigningSecret::new(SECRET);
+  let verify_at =
+    |now| secret.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), at(now));
+
+  assert_eq!(verify_at(1531420618 + 300), Ok(()));
+  assert_eq!(verify_at(1531420618 + 301),
+             Err(VerifyError::Expired { timestamp: 1531420618,
+                                        now: 1531420618 + 301 }));
+  assert_eq!(verify_at(1531420618 - 301),
+             Err(VerifyError::Expired { timestamp: 1531420618,
+                                        now: 1531420618 - 301 }));
+
+  let strict = SigningSecret::new(SECRET).max_age(Duration::from_secs(10));
+  assert!(matches!(strict.verify_at(TIMESTAMP,
+                                    SIGNATURE,
+                                    BODY.as_bytes(),
+                                    at(1531420618 + 11)),
+                   Err(VerifyError::Expired { .. })));
+}
@@ -0,0 +1,504 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{compose::Opt,
+                   interaction::{ViewState, ViewStateError, ViewSubmission}};
+
+fn state() -> ViewState<'static> {
+  serde_json::from_value(json!({
+    "values": {
+      "name": {
+        "name_input": { "type": "plain_text_input", "value": "Jack" },
+        "nickname_input": { "type": "plain_text_input", "value": null }
+      },
+      "prefs": {
+        "priority": {
+          "type": "static_select",
+          "selected_option": {
+            "text": { "type": "plain_text", "text": "High" },
+            "value": "high"
+          }
+        },
+        "flags": {
+          "type": "checkboxes",
+          "selected_options": [
+            { "text": { "type": "mrkdwn", "text": "*Urgent*" }, "value": "urgent" }
+          ]
+        },
+        "due": { "type": "datepicker", "selected_date": "2021-04-28" }
+      },
+      "people": {
+        "owner": { "type": "users_select", "selected_user": "U061F7AUR" },
+        "reviewers": {
+          "type": "multi_users_select",
+          "selected_users": ["U061F7AUR", "U0G9QF9C6"]
+        },
+        "convo": { "type": "conversations_select", "selected_conversation": null },
+        "convos": {
+          "type": "multi_conversations_select",
+          "selected_conversations": ["D0123"]
+        },
+        "channel": { "type": "channels_select", "selected_channel": "C0123" },
+        "channels": { "type": "multi_channels_select", "selected_channels": [] }
+      }
+    }
+  })).unwrap()
+}
+
+#[test]
+pub fn typed_getters() {
+  let state = state();
+
+  assert_eq!(state.text("name", "name_input"), Ok(Some("Jack")));
+  assert_eq!(state.text("name", "nickname_input"), Ok(None));
+
+  let high: Opt = Opt::builder().text_plain("High")
+                                .value("high")
+                                .build()
+                                .into();
+  assert_eq!(state.selected_option("prefs", "priority"), Ok(Some(&high)));
+
+  let urgent: Opt = Opt::builder().text_md("*Urgent*")
+                                  .value("urgent")
+                                  .build()
+                                  .into();
+  assert_eq!(state.selected_options("prefs", "flags"), Ok(&[urgent][..]));
+  assert_eq!(state.selected_date("prefs", "due"), Ok(Some("2021-04-28")));
+
+  assert_eq!(state.selected_user("people", "owner"),
+             Ok(Some("U061F7AUR")));
+  assert_eq!(state.selected_users("people", "reviewers").unwrap().len(),
+             2);
+  assert_eq!(state.selected_conversation("people", "convo"), Ok(None));
+  assert_eq!(state.selected_conversations("people", "convos").unwrap()[0],
+             "D0123");
+  assert_eq!(state.selected_channel("people", "channel"),
+             Ok(Some("C0123")));
+  assert_eq!(state.selected_channels("people", "channels").unwrap().len(),
+             0);
+}
+
+#[test]
+pub fn errors_name_the_key() {
+  let state = state();
+
+  assert_eq!(state.text("nope", "name_input"),
+             Err(ViewStateError::MissingBlock { block_id: "nope".into() }));
+  assert_eq!(state.text("name", "nope"),
+             Err(ViewStateError::MissingAction { block_id: "name".into(),
+                                                 action_id: "nope".into() }));
+
+  let err = state.selected_date("prefs", "priority").unwrap_err();
+  assert_eq!(err,
+             ViewStateError::Mismatched { block_id: "prefs".into(),
+                                          action_id: "priority".into(),
+                                          expected: "datepicker",
+                                          found: "static_select" });
+  assert_eq!(err.to_string(),
+             "expected `values.prefs.priority` to be a datepicker, found static_select");
+}
+
+#[test]
+pub fn view_submission_exposes_state() {
+  let payload: ViewSubmission = serde_json::from_value(json!({
+    "type": "view_submission",
+    "team": { "id": "T9TK3CUKW", "domain": "example" },
+    "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+    "api_app_id": "AABA1ABCD",
+    "trigger_id": "12345.98765.abcd2358fdea",
+    "view": {
+      "id": "VNHU13V36",
+      "type": "modal",
+      "title": { "type": "plain_text", "text": "Feedback" },
+      "submit": { "type": "plain_text", "text": "Send" },
+      "blocks": [],
+      "callback_id": "feedback",
+      "hash": "156663117.cd33ad1f",
+      "state": {
+        "values": {
+          "feedback": {
+            "feedback_input": { "type": "plain_text_input", "value": "Great!" }
+          }
+        }
+      }
+    },
+    "response_urls": [
+      {
+        "block_id": "channel",
+        "action_id": "channel_select",
+        "channel_id": "C0123",
+        "response_url": "https://hooks.slack.com/app/T9TK3CUKW/123/abc"
+      }
+    ]
+  })).unwrap();
+
+  assert_eq!(payload.user().id(), "UA8RXUSPL");
+  assert_eq!(payload.view().hash(), Some("156663117.cd33ad1f"));
+  assert_eq!(payload.state().unwrap().text("feedback", "feedback_input"),
+             Ok(Some("Great!")));
+  assert_eq!(payload.response_urls()[0].channel_id(), "C0123");
+}
+
+#[test]
+pub fn restore_sets_initial_values() {
+  use slack_blocks::blocks::Block;
+
+  let opt = |text: &str, value: &str| json!({ "text": { "type": "plain_text", "text": text }, "value": value });
+  let blocks: Vec<Block> = serde_json::from_value(json!([
+    {
+      "type": "input",
+      "block_id": "name",
+      "label": { "type": "plain_text", "text": "Name" },
+      "element": { "type": "plain_text_input", "action_id": "name_input" }
+    },
+    {
+      "type": "actions",
+      "block_id": "prefs",
+      "elements": [
+        {
+          "type": "static_select",
+          "action_id": "priority",
+          "placeholder": { "type": "plain_text", "text": "Priority" },
+          "options": [opt("High", "high"), opt("Low", "low")]
+        },
+        {
+          "type": "checkboxes",
+          "action_id": "flags",
+          "options": [{ "text": { "type": "mrkdwn", "text": "*Urgent*" }, "value": "urgent" }]
+        },
+        { "type": "datepicker", "action_id": "due" }
+      ]
+    },
+    {
+      "type": "actions",
+      "block_id": "people",
+      "elements": [
+        {
+          "type": "users_select",
+          "action_id": "owner",
+          "placeholder": { "type": "plain_text", "text": "Owner" }
+        },
+        {
+          "type": "multi_users_select",
+          "action_id": "reviewers",
+          "placeholder": { "type": "plain_text", "text": "Reviewers" }
+        },
+        {
+          "type": "multi_conversations_select",
+          "action_id": "convos",
+          "placeholder": { "type": "plain_text", "text": "Convos" }
+        },
+        {
+          "type": "channels_select",
+          "action_id": "channel",
+          "placeholder": { "type": "plain_text", "text": "Channel" }
+        },
+        {
+          "type": "multi_channels_select",
+          "action_id": "channels",
+          "placeholder": { "type": "plain_text", "text": "Channels" }
+        },
+        {
+          "type": "datepicker",
+          "action_id": "owner_since",
+          "initial_date": "2021-01-01"
+        }
+      ]
+    },
+    {
+      "type": "input",
+      "label": { "type": "plain_text", "text": "No block id" },
+      "element": { "type": "plain_text_input", "action_id": "name_input" }
+    }
+  ]))
+  .unwrap();
+
+  let restored = state().restore(blocks);
+
+  let expected = json!([
+    {
+      "type": "input",
+      "block_id": "name",
+      "label": { "type": "plain_text", "text": "Name" },
+      "element": {
+        "type": "plain_text_input",
+        "action_id": "name_input",
+        "initial_value": "Jack"
+      }
+    },
+    {
+      "type": "actions",
+      "block_id": "prefs",
+      "elements": [
+        {
+          "type": "static_select",
+          "action_id": "priority",
+          "placeholder": { "type": "plain_text", "text": "Priority" },
+          "options": [opt("High", "high"), opt("Low", "low")],
+          "initial_option": opt("High", "high")
+        },
+        {
+          "type": "checkboxes",
+          "action_id": "flags",
+          "options": [{ "text": { "type": "mrkdwn", "text": "*Urgent*" }, "value": "urgent" }],
+          "initial_options": [{ "text": { "type": "mrkdwn", "text": "*Urgent*" }, "value": "urgent" }]
+        },
+        { "type": "datepicker", "action_id": "due", "initial_date": "2021-04-28" }
+      ]
+    },
+    {
+      "type": "actions",
+      "block_id": "people",
+      "elements": [
+        {
+          "type": "users_select",
+          "action_id": "owner",
+          "placeholder": { "type": "plain_text", "text": "Owner" },
+          "initial_user": "U061F7AUR"
+        },
+        {
+          "type": "multi_users_select",
+          "action_id": "reviewers",
+          "placeholder": { "type": "plain_text", "text": "Reviewers" },
+          "initial_users": ["U061F7AUR", "U0G9QF9C6"]
+        },
+        {
+          "type": "multi_conversations_select",
+          "action_id": "convos",
+          "placeholder": { "type": "plain_text", "text": "Convos" },
+          "initial_conversations": ["D0123"]
+        },
+        {
+          "type": "channels_select",
+          "action_id": "channel",
+          "placeholder": { "type": "plain_text", "text": "Channel" },
+          "initial_channel": "C0123"
+        },
+        {
+          "type": "multi_channels_select",
+          "action_id": "channels",
+          "placeholder": { "type": "plain_text", "text": "Channels" }
+        },
+        {
+          "type": "datepicker",
+          "action_id": "owner_since",
+          "initial_date": "2021-01-01"
+        }
+      ]
+    },
+    {
+      "type": "input",
+      "label": { "type": "plain_text", "text": "No block id" },
+      "element": { "type": "plain_text_input", "action_id": "name_input" }
+    }
+  ]);
+
+  assert_eq!(serde_json::to_value(&restored).unwrap(), expected);
+}
+
+#[test]
+pub fn restore_ignores_mismatched_elements() {
+  use slack_blocks::blocks::Block;
+
+  let blocks: Vec<Block> =
+    serde_json::from_value(json!([{
+                             "type": "input",
+                             "block_id": "name",
+                             "label": { "type": "plain_text", "text": "Name" },
+                             "element": {
+                               "type": "datepicker",
+                               "action_id": "name_input",
+                               "initial_date": "2021-01-01"
+                             }
+                           }])).unwrap();
+
+  let before = serde_json::to_value(&blocks).unwrap();
+  let restored = state().restore(blocks);
+
+  assert_eq!(serde_json::to_value(&restored).unwrap(), before);
+}
+
+fn form() -> Vec<slack_blocks::blocks::Block<'static>> {
+  let opt = |value: &str| json!({ "text": { "type": "plain_text", "text": value }, "value": value });
+  let input = |block_id: &str, element: serde_json::Value| {
+    json!({
+      "type": "input",
+      "block_id": block_id,
+      "label": { "type": "plain_text", "text": block_id },
+      "element": element
+    })
+  };
+  let optional = |mut input: serde_json::Value| {
+    input["optional"] = json!(true);
+    input
+  };
+
+  serde_json::from_value(json!([
+    input("name", json!({
+      "type": "plain_text_input",
+      "action_id": "name",
+      "min_length": 2,
+      "max_length": 5
+    })),
+    input("size", json!({
+      "type": "static_select",
+      "action_id": "size",
+      "placeholder": { "type": "plain_text", "text": "Size" },
+      "option_groups": [
+        { "label": { "type": "plain_text", "text": "Small" }, "options": [opt("xs"), opt("s")] },
+        { "label": { "type": "plain_text", "text": "Large" }, "options": [opt("l")] }
+      ]
+    })),
+    input("toppings", json!({
+      "type": "multi_static_select",
+      "action_id": "toppings",
+      "placeholder": { "type": "plain_text", "text": "Toppings" },
+      "options": [opt("ham"), opt("egg"), opt("kale")],
+      "max_selected_items": 2
+    })),
+    optional(input("flags", json!({
+      "type": "checkboxes",
+      "action_id": "flags",
+      "options": [opt("urgent")]
+    }))),
+    optional(input("mood", json!({
+      "type": "radio_buttons",
+      "action_id": "mood",
+      "options": [opt("happy"), opt("sad")]
+    }))),
+    input("people", json!({
+      "type": "multi_users_select",
+      "action_id": "people",
+      "placeholder": { "type": "plain_text", "text": "People" },
+      "max_selected_items": 1
+    })),
+    input("due", json!({ "type": "datepicker", "action_id": "due" }))
+  ]))
+  .unwrap()
+}
+
+fn submitted(values: serde_json::Value) -> ViewState<'static> {
+  serde_json::from_value(json!({ "values": values })).unwrap()
+}
+
+#[test]
+pub fn check_accepts_valid_values() {
+  let opt = |value: &str| json!({ "text": { "type": "plain_text", "text": value }, "value": value });
+  let state = submitted(json!({
+                          "name": { "name": { "type": "plain_text_input", "value": "Jack" } },
+                          "size": { "size": { "type": "static_select", "selected_option": opt("l") } },
+                          "toppings": { "toppings": { "type": "multi_static_select", "selected_options": [opt("ham"), opt("egg")] } },
+                          "flags": { "flags": { "type": "checkboxes", "selected_options": [] } },
+                          "mood": { "mood": { "type": "radio_buttons", "selected_option": null } },
+                          "people": { "people": { "type": "multi_users_select", "selected_users": ["U1"] } },
+                          "due": { "due": { "type": "datepicker", "selected_date": "2021-04-28" } }
+                        }));
+
+  assert_eq!(state.check(&form()), Ok(()));
+}
+
+#[test]
+pub fn check_rejects_forged_values() {
+  let opt = |value: &str| json!({ "text": { "type": "plain_text", "text": value }, "value": value });
+  let state = submitted(json!({
+                          "name": { "name": { "type": "plain_text_input", "value": "J" } },
+                          "size": { "size": { "type": "static_select", "selected_option": opt("xxl") } },
+                          "toppings": { "toppings": { "type": "multi_static_select", "selected_options": [opt("ham"), opt("egg"), opt("kale")] } },
+                          "flags": { "flags": { "type": "checkboxes", "selected_options": [opt("admin")] } },
+                          "mood": { "mood": { "type": "radio_buttons", "selected_option": opt("angry") } },
+                          "people": { "people": { "type": "multi_users_select", "selected_users": ["U1", "U2"] } },
+                          "due": { "due": { "type": "plain_text_input", "value": "tomorrow" } }
+                        }));
+
+  let errors = state.check(&form()).unwrap_err();
+
+  assert_eq!(serde_json::to_value(&errors).unwrap(),
+             json!({
+               "errors": {
+                 "name": "Must be at least 2 characters",
+                 "size": "Select one of the options given",
+                 "toppings": "Select at most 2 items",
+                 "flags": "Select one of the options given",
+                 "mood": "Select one of the options given",
+                 "people": "Select at most 1 item",
+                 "due": "Unexpected value"
+               }
+             }));
+}
+
+#[test]
+pub fn check_rejects_text_too_long() {
+  let state = submitted(json!({
+                          "name": { "name": { "type": "plain_text_input", "value": "Jackson" } }
+                        }));
+
+  let errors = state.check(&form()[..1]).unwrap_err();
+
+  assert_eq!(errors.errors().len(), 1);
+  assert_eq!(errors.errors()["name"], "Must be at most 5 characters");
+}
+
+#[test]
+pub fn check_requires_values_for_required_inputs() {
+  let state = submitted(json!({
+                          "name": { "name": { "type": "plain_text_input", "value": "" } },
+                          "size": { "size": { "type": "static_select", "selected_option": null } },
+                          "people": { "people": { "type": "multi_users_select", "selected_users": [] } }
+                        }));
+
+  let errors = state.check(&form()).unwrap_err();
+
+  assert_eq!(serde_json::to_value(&errors).unwrap(),
+             json!({
+               "errors": {
+                 "name": "This field is required",
+                 "size": "This field is required",
+                 "toppings": "This field is required",
+                 "people": "This field is required",
+                 "due": "This field is required"
+               }
+             }));
+}
+
+#[test]
+pub fn check_allows_blank_optional_inputs() {
+  let blocks: Vec<slack_blocks::blocks::Block> =
+    serde_json::from_value(json!([{
+                             "type": "input",
+                             "block_id": "nickname",
+                             "optional": true,
+                             "label": { "type": "plain_text", "text": "Nickname" },
+                             "element": {
+                               "type": "plain_text_input",
+                               "action_id": "nickname",
+                               "min_length": 3
+                             }
+                           }])).unwrap();
+
+  let blank = |value: serde_json::Value| {
+    submitted(json!({
+                "nickname": { "nickname": { "type": "plain_text_input", "value": value } }
+              }))
+  };
+
+  assert_eq!(blank(json!("")).check(&blocks), Ok(()));
+  assert_eq!(blank(json!(null)).check(&blocks), Ok(()));
+  assert_eq!(submitted(json!({})).check(&blocks), Ok(()));
+
+  let errors = blank(json!("Jo")).check(&blocks).unwrap_err();
+  assert_eq!(errors.errors()["nickname"], "Must be at least 3 characters");
+}
+
+#[test]
+pub fn check_skips_inputs_without_block_id() {
+  let mut blocks = serde_json::to_value(&form()[..1]).unwrap();
+  blocks[0].as_object_mut().unwrap().remove("block_id");
+  let blocks: Vec<slack_blocks::blocks::Block> =
+    serde_json::from_value(blocks).unwrap();
+
+  let state = submitted(json!({
+                          "Vx9a": { "name": { "type": "plain_text_input", "value": "Jackson" } }
+                        }));
+
+  assert_eq!(state.check(&blocks), Ok(()));
+  assert_eq!(submitted(json!({})).check(&blocks), Ok(()));
+}
@@ -6,3 +6,15 @@ pub use elems::*;
 
 pub mod compose;
 pub use compose::*;
+
+pub mod surfaces;
+pub use surfaces::*;
+
+pub mod api;
+pub use api::*;
+
+pub mod interaction;
+pub use interaction::*;
+
+pub mod form;
+pub use form::*;
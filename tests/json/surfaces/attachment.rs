@@ -0,0 +1,133 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::{Block, Section},
+                   surfaces::{attachment::Field, Attachment, Message},
+                   text::ToSlackMarkdown};
+
+#[test]
+pub fn all_attrs() {
+  let att =
+    Attachment::builder().color("#36a64f")
+                         .fallback("Build passed")
+                         .pretext("CI results")
+                         .author_name("ci-bot")
+                         .author_link("https://ci.example.com")
+                         .author_icon("https://ci.example.com/icon.png")
+                         .title("Build #42")
+                         .title_link("https://ci.example.com/42")
+                         .text("All *42* checks passed")
+                         .field(Field::new("Branch", "main").with_short(true))
+                         .image_url("https://ci.example.com/graph.png")
+                         .thumb_url("https://ci.example.com/thumb.png")
+                         .footer("ci")
+                         .footer_icon("https://ci.example.com/f.png")
+                         .ts(1623956385)
+                         .mrkdwn_in(vec!["text"])
+                         .block(Block::Divider)
+                         .build();
+
+  let actual = serde_json::to_value(att).unwrap();
+  let expected = json!({
+    "blocks": [{ "type": "divider" }],
+    "color": "#36a64f",
+    "fallback": "Build passed",
+    "pretext": "CI results",
+    "author_name": "ci-bot",
+    "author_link": "https://ci.example.com",
+    "author_icon": "https://ci.example.com/icon.png",
+    "title": "Build #42",
+    "title_link": "https://ci.example.com/42",
+    "text": "All *42* checks passed",
+    "fields": [{ "title": "Branch", "value": "main", "short": true }],
+    "image_url": "https://ci.example.com/graph.png",
+    "thumb_url": "https://ci.example.com/thumb.png",
+    "footer": "ci",
+    "footer_icon": "https://ci.example.com/f.png",
+    "ts": 1623956385,
+    "mrkdwn_in": ["text"]
+  });
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn attaches_to_message() {
+  let att = Attachment::builder().color("danger")
+                                 .block(Section::builder().text("*Disk full* on `db-1`".markdown())
+                                                          .build())
+                                 .build();
+
+  let msg = Message::builder().text("Alert").attachment(att).build();
+
+  let actual = serde_json::to_value(&msg).unwrap();
+  let expected = json!({
+    "text": "Alert",
+    "attachments": [
+      {
+        "color": "danger",
+        "blocks": [
+          {
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": "*Disk full* on `db-1`" }
+          }
+        ]
+      }
+    ]
+  });
+
+  assert_eq!(actual, expected);
+  assert_eq!(serde_json::from_value::<Message>(actual).unwrap(), msg);
+  assert_eq!(msg.validate(), Ok(()));
+}
+
+#[test]
+pub fn block_count_is_validated() {
+  let ok = Attachment::builder().blocks((0..50).map(|_| Block::Divider))
+                                .build();
+  let too_many = Attachment::builder().blocks((0..51).map(|_| Block::Divider))
+                                      .build();
+
+  assert_eq!(ok.validate(), Ok(()));
+  assert!(too_many.validate().is_err());
+  assert!(Message::builder().attachment(too_many)
+                            .build()
+                            .validate()
+                            .is_err());
+}
+
+#[test]
+pub fn color_is_validated() {
+  let att =
+    |color: &'static str| Attachment::builder().text("hi").color(color).build();
+
+  assert_eq!(att("good").validate(), Ok(()));
+  assert_eq!(att("#439FE0").validate(), Ok(()));
+  assert!(att("#43").validate().is_err());
+  assert!(att("blue").validate().is_err());
+}
+
+#[test]
+pub fn ts_from_slack_can_be_a_string_or_float() {
+  let expected =
+    Message::builder().text("Deployed")
+                      .attachment(Attachment::builder().text("v1.2.3")
+                                                       .ts(1623956385)
+                                                       .build())
+                      .build();
+
+  for ts in vec![json!("1623956385.000200"),
+                 json!(1623956385.0002),
+                 json!(1623956385)]
+  {
+    let msg: Message =
+      serde_json::from_value(json!({
+                               "text": "Deployed",
+                               "attachments": [{ "text": "v1.2.3", "ts": ts }]
+                             })).unwrap();
+
+    assert_eq!(msg, expected);
+  }
+
+  let bad = json!({ "text": "Deployed", "attachments": [{ "text": "v1.2.3", "ts": "soon" }] });
+  assert!(serde_json::from_value::<Message>(bad).is_err());
+}
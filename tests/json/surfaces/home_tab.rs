@@ -0,0 +1,62 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::Block, blox::*, surfaces::HomeTab};
+
+#[test]
+pub fn all_attrs() {
+  let home =
+    HomeTab::builder().block(blox! { <header_block text="Your tasks" /> })
+                      .block(blox! {
+                               <actions_block>
+                                 <button action_id="refresh">"Refresh"</button>
+                               </actions_block>
+                             })
+                      .private_metadata("user=U123")
+                      .callback_id("home")
+                      .external_id("home_U123")
+                      .build();
+
+  let actual = serde_json::to_value(home).unwrap();
+  let expected = json!({
+    "type": "home",
+    "blocks": [
+      { "type": "header", "text": { "type": "plain_text", "text": "Your tasks" } },
+      {
+        "type": "actions",
+        "elements": [
+          {
+            "type": "button",
+            "action_id": "refresh",
+            "text": { "type": "plain_text", "text": "Refresh" }
+          }
+        ]
+      }
+    ],
+    "private_metadata": "user=U123",
+    "callback_id": "home",
+    "external_id": "home_U123"
+  });
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn roundtrips() {
+  let home = HomeTab::builder().block(Block::Divider).build();
+  let json = serde_json::to_value(&home).unwrap();
+
+  assert_eq!(json,
+             json!({ "type": "home", "blocks": [{ "type": "divider" }] }));
+  assert_eq!(serde_json::from_value::<HomeTab>(json).unwrap(), home);
+}
+
+#[test]
+pub fn over_100_blocks_is_invalid() {
+  let ok = HomeTab::builder().blocks((0..100).map(|_| Block::Divider))
+                             .build();
+  let too_many = HomeTab::builder().blocks((0..101).map(|_| Block::Divider))
+                                   .build();
+
+  assert_eq!(ok.validate(), Ok(()));
+  assert!(too_many.validate().is_err());
+}
@@ -0,0 +1,96 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::Block, blox::*, surfaces::Message};
+
+#[test]
+pub fn text_only() {
+  let msg = Message::builder().text("Hello, world!").build();
+
+  let actual = serde_json::to_value(msg).unwrap();
+  let expected = json!({
+    "text": "Hello, world!"
+  });
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn all_attrs() {
+  let msg = Message::builder().text("Budget Performance")
+                              .block(blox! {<h1>"Budget Performance"</h1>})
+                              .block(Block::Divider)
+                              .thread_ts("1623956385.000200")
+                              .reply_broadcast(true)
+                              .mrkdwn(false)
+                              .unfurl_links(true)
+                              .unfurl_media(false)
+                              .build();
+
+  let actual = serde_json::to_value(msg).unwrap();
+  let expected = json!({
+    "text": "Budget Performance",
+    "blocks": [
+      {
+        "type": "header",
+        "text": {
+          "type": "plain_text",
+          "text": "Budget Performance"
+        }
+      },
+      { "type": "divider" }
+    ],
+    "thread_ts": "1623956385.000200",
+    "reply_broadcast": true,
+    "mrkdwn": false,
+    "unfurl_links": true,
+    "unfurl_media": false
+  });
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn deserializes() {
+  let expected = Message::builder().text("Foo")
+                                   .block(blox! {<h1>"Bar"</h1>})
+                                   .build();
+
+  let actual: Message =
+    serde_json::from_value(json!({
+                             "text": "Foo",
+                             "blocks": [
+                               {
+                                 "type": "header",
+                                 "text": { "type": "plain_text", "text": "Bar" }
+                               }
+                             ]
+                           })).unwrap();
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn input_without_dispatch_action_is_invalid() {
+  let input = blox! {
+    <input_block label="foo">
+      <text_input action_id="input" />
+    </input_block>
+  };
+
+  let msg = Message::builder().block(input).build();
+
+  assert!(msg.validate().is_err());
+}
+
+#[test]
+pub fn input_with_dispatch_action_is_valid() {
+  let input = blox! {
+    <input_block label="foo" dispatch_actions=true>
+      <text_input action_id="input" />
+    </input_block>
+  };
+
+  let msg = Message::builder().block(input).build();
+
+  assert_eq!(msg.validate(), Ok(()));
+}
@@ -0,0 +1,121 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use slack_blocks::{blocks::Block,
+                   compose::value::ValueError,
+                   interaction::{BlockActions, ViewSubmission},
+                   surfaces::{HomeTab, Metadata, Modal}};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Wizard {
+  step: u8,
+  answers: Vec<String>,
+}
+
+fn wizard(answers: usize) -> Wizard {
+  Wizard { step: 1,
+           answers: (0..answers).map(|_| "the same answer".to_string())
+                                .collect() }
+}
+
+#[test]
+pub fn typed_metadata_roundtrips_through_view_submission() {
+  let modal = Modal::builder().title("Wizard")
+                              .block(Block::Divider)
+                              .typed_private_metadata(&Metadata::new(wizard(2)))
+                              .unwrap()
+                              .build();
+
+  assert_eq!(modal.private_metadata(),
+             Some(r#"{"step":1,"answers":["the same answer","the same answer"]}"#));
+
+  let payload: ViewSubmission =
+    serde_json::from_value(json!({
+                             "team": { "id": "T9TK3CUKW" },
+                             "user": { "id": "UA8RXUSPL" },
+                             "view": serde_json::to_value(&modal).unwrap()
+                           })).unwrap();
+
+  assert_eq!(payload.view().typed_private_metadata(), Ok(wizard(2)));
+}
+
+#[test]
+pub fn typed_metadata_roundtrips_through_block_actions() {
+  let home = HomeTab::builder().block(Block::Divider)
+                               .typed_private_metadata(&Metadata::new(&[1, 2]))
+                               .unwrap()
+                               .build();
+
+  let payload: BlockActions =
+    serde_json::from_value(json!({
+                             "team": { "id": "T9TK3CUKW" },
+                             "user": { "id": "UA8RXUSPL" },
+                             "view": serde_json::to_value(&home).unwrap(),
+                             "actions": []
+                           })).unwrap();
+
+  assert_eq!(payload.view().unwrap().typed_private_metadata(),
+             Ok(vec![1, 2]));
+}
+
+#[test]
+pub fn typed_metadata_length_is_checked_when_building() {
+  let err = Modal::builder().title("Wizard")
+                            .block(Block::Divider)
+                            .typed_private_metadata(&Metadata::new(wizard(200)))
+                            .map(|_| ())
+                            .unwrap_err();
+
+  assert!(matches!(err, ValueError::TooLong { max: 3000, .. }));
+}
+
+#[test]
+pub fn compressed_metadata_fits_more() {
+  let metadata = Metadata::new(wizard(200)).compressed();
+  let encoded = metadata.encode().unwrap();
+
+  assert!(encoded.starts_with("~z:"));
+  assert!(encoded.len() < 3000);
+
+  let modal = Modal::builder().title("Wizard")
+                              .block(Block::Divider)
+                              .typed_private_metadata(&metadata)
+                              .unwrap()
+                              .build();
+
+  assert_eq!(modal.typed_private_metadata(), Ok(wizard(200)));
+  assert_eq!(Metadata::<Wizard>::decode(&encoded).unwrap(), metadata);
+}
+
+#[test]
+pub fn missing_or_invalid_metadata() {
+  let modal = Modal::builder().title("Wizard")
+                              .block(Block::Divider)
+                              .build();
+  assert_eq!(modal.typed_private_metadata::<Wizard>(),
+             Err(ValueError::Missing));
+
+  assert!(matches!(Metadata::<Wizard>::decode("~z:not deflate"),
+                   Err(ValueError::Deserialize(_))));
+  assert!(matches!(Metadata::<Wizard>::decode("[1, 2]"),
+                   Err(ValueError::Deserialize(_))));
+}
+
+#[test]
+pub fn compressed_metadata_inflated_size_is_capped() {
+  let fits = Metadata::new("a".repeat(60 * 1024)).compressed();
+  let encoded = fits.encode().unwrap();
+  assert_eq!(Metadata::<String>::decode(&encoded).unwrap(), fits);
+
+  let too_big = Metadata::new("a".repeat(1024 * 1024)).compressed();
+  assert!(matches!(too_big.encode(), Err(ValueError::TooLong { .. })));
+
+  // a forged JSON string of 100,000 `a`s, deflated and base64-encoded
+  let forged =
+    concat!("~z:7cGxAAAAAAIwl8zyl0ijY1sKAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA3MoA");
+
+  assert_eq!(Metadata::<String>::decode(forged),
+             Err(ValueError::Deserialize("inflates to more than 65536 bytes".into())));
+}
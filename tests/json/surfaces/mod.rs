@@ -0,0 +1,23 @@
+pub mod message;
+pub use message::*;
+
+pub mod modal;
+pub use modal::*;
+
+pub mod home_tab;
+pub use home_tab::*;
+
+pub mod surface;
+pub use surface::*;
+
+pub mod attachment;
+pub use attachment::*;
+
+pub mod metadata;
+pub use metadata::*;
+
+pub mod wizard;
+pub use wizard::*;
+
+pub mod stack;
+pub use stack::*;
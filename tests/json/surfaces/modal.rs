@@ -0,0 +1,117 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blox::*, interaction::ViewState, surfaces::Modal};
+
+#[test]
+pub fn all_attrs() {
+  let input = blox! {
+    <input_block label="Name" block_id="name">
+      <text_input action_id="input" />
+    </input_block>
+  };
+
+  let modal = Modal::builder().title("Sign up")
+                              .submit("Submit")
+                              .close("Cancel")
+                              .block(input)
+                              .private_metadata("{\"step\":1}")
+                              .callback_id("sign_up")
+                              .clear_on_close(true)
+                              .notify_on_close(true)
+                              .external_id("sign_up_1")
+                              .submit_disabled(false)
+                              .build();
+
+  let actual = serde_json::to_value(modal).unwrap();
+  let expected = json!({
+    "type": "modal",
+    "title": { "type": "plain_text", "text": "Sign up" },
+    "submit": { "type": "plain_text", "text": "Submit" },
+    "close": { "type": "plain_text", "text": "Cancel" },
+    "blocks": [
+      {
+        "type": "input",
+        "block_id": "name",
+        "label": { "type": "plain_text", "text": "Name" },
+        "element": { "type": "plain_text_input", "action_id": "input" }
+      }
+    ],
+    "private_metadata": "{\"step\":1}",
+    "callback_id": "sign_up",
+    "clear_on_close": true,
+    "notify_on_close": true,
+    "external_id": "sign_up_1",
+    "submit_disabled": false
+  });
+
+  assert_eq!(actual, expected);
+}
+
+#[test]
+pub fn deserializes_view_from_slack() {
+  let view: Modal = serde_json::from_value(json!({
+                      "id": "VMHU10V25",
+                      "team_id": "T8N4K1JN",
+                      "type": "modal",
+                      "title": { "type": "plain_text", "text": "Quite a plain modal" },
+                      "submit": { "type": "plain_text", "text": "Create" },
+                      "blocks": [
+                        {
+                          "type": "input",
+                          "block_id": "a1",
+                          "label": { "type": "plain_text", "text": "Name" },
+                          "element": { "type": "plain_text_input", "action_id": "b1" }
+                        }
+                      ],
+                      "private_metadata": "Shh it is a secret",
+                      "callback_id": "identify_your_modals",
+                      "external_id": "",
+                      "state": { "values": {} },
+                      "hash": "156772938.1827394",
+                      "clear_on_close": false,
+                      "notify_on_close": false,
+                      "root_view_id": "VMHU10V25",
+                      "previous_view_id": null,
+                      "app_id": "AA4928AQ",
+                      "bot_id": "BA13894H"
+                    })).unwrap();
+
+  assert_eq!(view.id(), Some("VMHU10V25"));
+  assert_eq!(view.hash(), Some("156772938.1827394"));
+  assert_eq!(view.root_view_id(), Some("VMHU10V25"));
+  assert_eq!(view.previous_view_id(), None);
+  assert_eq!(view.state(), Some(&ViewState::default()));
+
+  let reserialized = serde_json::to_value(&view).unwrap();
+  assert_eq!(reserialized.get("id"), None);
+  assert_eq!(reserialized.get("hash"), None);
+}
+
+#[test]
+pub fn title_over_24_chars_is_invalid() {
+  let modal = Modal::builder().title("This title is way too long")
+                              .block(blox! {<hr />})
+                              .build();
+
+  assert!(modal.validate().is_err());
+}
+
+#[test]
+pub fn input_requires_submit() {
+  let input = || {
+    blox! {
+      <input_block label="Name">
+        <text_input action_id="input" />
+      </input_block>
+    }
+  };
+
+  let without_submit = Modal::builder().title("Sign up").block(input()).build();
+  let with_submit = Modal::builder().title("Sign up")
+                                    .submit("Go")
+                                    .block(input())
+                                    .build();
+
+  assert!(without_submit.validate().is_err());
+  assert_eq!(with_submit.validate(), Ok(()));
+}
@@ -0,0 +1,96 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::Block,
+                   interaction::ResponseAction,
+                   surfaces::{stack::{ModalStack, StackError},
+                              Modal}};
+
+fn received(id: &str,
+            hash: &str,
+            root: &str,
+            previous: Option<&str>)
+            -> Modal<'static> {
+  serde_json::from_value(json!({
+                           "type": "modal",
+                           "title": { "type": "plain_text", "text": "Order" },
+                           "blocks": [],
+                           "id": id,
+                           "hash": hash,
+                           "root_view_id": root,
+                           "previous_view_id": previous
+                         })).unwrap()
+}
+
+fn modal(title: &str) -> Modal<'static> {
+  Modal::builder().title(title.to_string())
+                  .block(Block::Divider)
+                  .build()
+}
+
+#[test]
+pub fn stack_depth() {
+  let root = ModalStack::from_view(&received("V1", "h1", "V1", None)).unwrap();
+  let second =
+    ModalStack::from_view(&received("V2", "h2", "V1", Some("V1"))).unwrap();
+  let third =
+    ModalStack::from_view(&received("V3", "h3", "V1", Some("V2"))).unwrap();
+
+  assert_eq!((root.depth(), second.depth(), third.depth()), (1, 2, 3));
+  assert_eq!(third.root_view_id(), "V1");
+  assert_eq!(third.previous_view_id(), Some("V2"));
+  assert!(second.can_push());
+  assert!(!third.can_push());
+}
+
+#[test]
+pub fn stack_push() {
+  let second =
+    ModalStack::from_view(&received("V2", "h2", "V1", Some("V1"))).unwrap();
+  let third =
+    ModalStack::from_view(&received("V3", "h3", "V1", Some("V2"))).unwrap();
+
+  let req = second.push("123.456.abc", modal("Confirm")).unwrap();
+
+  assert_eq!(serde_json::to_value(&req).unwrap(),
+             json!({
+               "trigger_id": "123.456.abc",
+               "view": {
+                 "type": "modal",
+                 "title": { "type": "plain_text", "text": "Confirm" },
+                 "blocks": [{ "type": "divider" }]
+               }
+             }));
+  assert_eq!(third.push("123.456.abc", modal("Confirm")),
+             Err(StackError::Full));
+}
+
+#[test]
+pub fn stack_update_carries_hash() {
+  let mut stack =
+    ModalStack::from_view(&received("V2", "h2", "V1", Some("V1"))).unwrap();
+
+  let req = serde_json::to_value(stack.update(modal("Done"))).unwrap();
+  assert_eq!((&req["view_id"], &req["hash"]),
+             (&json!("V2"), &json!("h2")));
+
+  // the response to `views.update` has the view's new hash
+  stack.track(&received("V2", "h2b", "V1", Some("V1")))
+       .unwrap();
+  let req = serde_json::to_value(stack.update(modal("Done"))).unwrap();
+  assert_eq!(req["hash"], json!("h2b"));
+
+  let req = serde_json::to_value(stack.update_root(modal("Order"))).unwrap();
+  assert_eq!((&req["view_id"], req.get("hash")), (&json!("V1"), None));
+}
+
+#[test]
+pub fn stack_not_open() {
+  let mut stack =
+    ModalStack::from_view(&received("V1", "h1", "V1", None)).unwrap();
+
+  assert_eq!(ModalStack::from_view(&modal("Order")),
+             Err(StackError::NotOpen));
+  assert_eq!(stack.track(&modal("Order")), Err(StackError::NotOpen));
+  assert_eq!(stack.view_id(), "V1");
+  assert_eq!(stack.clear(), ResponseAction::Clear);
+}
@@ -0,0 +1,149 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use slack_blocks::{blocks::{Actions, Block, File, Input, Section},
+                   blox::*,
+                   elems::{select, BlockElement, Button, TextInput},
+                   surfaces::{Surface, SurfaceErrorReason, ValidateFor}};
+
+fn input(dispatch: bool) -> Block<'static> {
+  blox! {
+    <input_block label="Name" dispatch_actions=dispatch>
+      <text_input action_id="name" />
+    </input_block>
+  }.into()
+}
+
+#[test]
+pub fn input_needs_dispatch_action_outside_modals() {
+  assert_eq!(input(false).validate_for(Surface::Modal), Ok(()));
+
+  for surface in [Surface::Message, Surface::HomeTab, Surface::Attachment] {
+    let err = input(false).validate_for(surface).unwrap_err();
+
+    assert_eq!(err.surface(), surface);
+    assert_eq!(err.reason(),
+               &SurfaceErrorReason::InputRequiresDispatchAction);
+    assert_eq!(input(true).validate_for(surface), Ok(()));
+  }
+}
+
+#[test]
+pub fn file_only_in_messages() {
+  let file: Block = File::builder().external_id("abc").build().into();
+
+  assert_eq!(file.validate_for(Surface::Message), Ok(()));
+  assert_eq!(file.validate_for(Surface::Attachment), Ok(()));
+
+  for surface in [Surface::Modal, Surface::HomeTab] {
+    assert_eq!(file.validate_for(surface).unwrap_err().reason(),
+               &SurfaceErrorReason::BlockNotSupported { block: "File" });
+  }
+}
+
+#[test]
+pub fn text_input_accessory_is_invalid() {
+  let section: Block =
+    Section::builder().text(blox! { <text kind=plain>"Hi"</text> })
+                      .accessory(TextInput::builder().action_id("a").build())
+                      .build()
+                      .into();
+
+  assert_eq!(section.validate_for(Surface::Modal).unwrap_err().reason(),
+             &SurfaceErrorReason::ElementNotSupportedInBlock { element:
+                                                                 "TextInput",
+                                                               block:
+                                                                 "Section" });
+}
+
+fn multi_users() -> select::multi::User<'static> {
+  select::multi::User::builder().placeholder("Who")
+                                .action_id("who")
+                                .build()
+}
+
+#[test]
+pub fn multi_selects_only_in_modals_and_home_tabs() {
+  let elem: BlockElement = multi_users().into();
+  let not_supported =
+    SurfaceErrorReason::ElementNotSupported { element: "MultiSelectUser" };
+
+  assert_eq!(elem.validate_for(Surface::Modal), Ok(()));
+  assert_eq!(elem.validate_for(Surface::HomeTab), Ok(()));
+
+  for surface in [Surface::Message, Surface::Attachment] {
+    assert_eq!(elem.validate_for(surface).unwrap_err().reason(),
+               &not_supported);
+  }
+}
+
+#[test]
+pub fn block_elements_are_checked_against_surface() {
+  let not_supported =
+    SurfaceErrorReason::ElementNotSupported { element: "MultiSelectUser" };
+
+  let input: Block = Input::builder().label("Who")
+                                     .element(multi_users())
+                                     .dispatch_actions(true)
+                                     .build()
+                                     .into();
+  let section: Block =
+    Section::builder().text(blox! { <text kind=plain>"Who?"</text> })
+                      .accessory(multi_users())
+                      .build()
+                      .into();
+  let actions: Block =
+    Actions::builder().element(Button::builder().text("Go")
+                                                .action_id("go")
+                                                .build())
+                      .build()
+                      .into();
+
+  for block in [&input, &section] {
+    assert_eq!(block.validate_for(Surface::Modal), Ok(()));
+    assert_eq!(block.validate_for(Surface::Message).unwrap_err().reason(),
+               &not_supported);
+  }
+
+  assert_eq!(actions.validate_for(Surface::Message), Ok(()));
+
+  let blocks = [Block::Divider, section];
+  let err = blocks.validate_for(Surface::Attachment).unwrap_err();
+  assert_eq!(err.block_index(), Some(1));
+  assert_eq!(err.reason().code(), "element_not_supported");
+}
+
+#[test]
+pub fn list_error_is_machine_readable() {
+  let blocks = [Block::Divider, input(false)];
+  let err = blocks.validate_for(Surface::HomeTab).unwrap_err();
+
+  assert_eq!(err.block_index(), Some(1));
+  assert_eq!(err.reason().code(), "input_requires_dispatch_action");
+  assert_eq!(serde_json::to_value(err).unwrap(),
+             json!({
+               "surface": "home_tab",
+               "block_index": 1,
+               "reason": { "code": "input_requires_dispatch_action" }
+             }));
+}
+
+#[test]
+pub fn modal_validate_rejects_file_blocks() {
+  use slack_blocks::surfaces::Modal;
+
+  let modal = Modal::builder().title("Files")
+                              .block(File::builder().external_id("abc").build())
+                              .build();
+
+  assert!(modal.validate().is_err());
+}
+
+#[test]
+pub fn unknown_blocks_are_not_supported() {
+  let block: Block =
+    serde_json::from_value(json!({ "type": "video" })).unwrap();
+
+  assert_eq!(block, Block::Unknown);
+  assert_eq!(block.validate_for(Surface::Message).unwrap_err().reason(),
+             &SurfaceErrorReason::BlockNotSupported { block: "Unknown" });
+}
@@ -0,0 +1,223 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use slack_blocks::{blocks::{Block, Input, Section},
+                   compose::{signing::SigningKey, value::ValueError},
+                   elems::TextInput,
+                   interaction::{response_action::InputErrors,
+                                 BlockActions,
+                                 ResponseAction,
+                                 ViewSubmission},
+                   surfaces::{wizard::{WizardError,
+                                       WizardOutcome,
+                                       BACK_ACTION_ID},
+                              Modal,
+                              Wizard},
+                   text::ToSlackPlaintext};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+  name: String,
+  team: String,
+}
+
+fn text_step(id: &'static str) -> Vec<Block<'static>> {
+  vec![Input::builder().label(id)
+                       .block_id(id)
+                       .element(TextInput::builder().action_id(id).build())
+                       .build()
+                       .into()]
+}
+
+fn read(view: &Modal, id: &str) -> Result<String, InputErrors<'static>> {
+  match view.state().unwrap().text(id, id) {
+    | Ok(Some(text)) if !text.is_empty() => Ok(text.to_string()),
+    | _ => Err(InputErrors::for_view(view).error(id.to_string(), "Required")
+                                          .unwrap()),
+  }
+}
+
+fn wizard<'h>() -> Wizard<'h, Answers> {
+  Wizard::<Answers>::new("onboarding", "Onboarding")
+    .step(|_| text_step("name"), |answers, view| {
+      answers.name = read(view, "name")?;
+      Ok(())
+    })
+    .step(|_| text_step("team"), |answers, view| {
+      answers.team = read(view, "team")?;
+      Ok(())
+    })
+    .step(|answers: &Answers| {
+            vec![Section::builder().text(format!("{} joins {}",
+                                                 answers.name,
+                                                 answers.team).plaintext())
+                                   .build()
+                                   .into()]
+          },
+          |_, _| Ok(()))
+}
+
+fn submit(view: &Modal,
+          input: Option<(&str, &str)>)
+          -> ViewSubmission<'static> {
+  let mut view = serde_json::to_value(view).unwrap();
+  view["state"] = match input {
+    | Some((id, value)) => json!({ "values": { id: { id: {
+      "type": "plain_text_input",
+      "value": value
+    } } } }),
+    | None => json!({ "values": {} }),
+  };
+
+  serde_json::from_value(json!({
+                           "team": { "id": "T9TK3CUKW" },
+                           "user": { "id": "UA8RXUSPL" },
+                           "view": view
+                         })).unwrap()
+}
+
+fn next_view(outcome: WizardOutcome<'static, Answers>) -> Modal<'static> {
+  match outcome {
+    | WizardOutcome::Respond(ResponseAction::Update { view }) => view,
+    | other => panic!("expected an update, got {:?}", other),
+  }
+}
+
+#[test]
+pub fn wizard_steps_through_to_finish() {
+  let wizard = wizard();
+  let first = wizard.open(Answers::default()).unwrap();
+
+  let json = serde_json::to_value(&first).unwrap();
+  assert_eq!(json["callback_id"], json!("onboarding"));
+  assert_eq!(json["submit"]["text"], json!("Next"));
+  assert_eq!(json["blocks"].as_array().unwrap().len(), 1);
+
+  let second = next_view(wizard.submit(&submit(&first, Some(("name", "Ada"))))
+                               .unwrap());
+  let json = serde_json::to_value(&second).unwrap();
+  assert_eq!(json["blocks"][1]["elements"][0]["action_id"],
+             json!(BACK_ACTION_ID));
+  assert_eq!(json["submit"]["text"], json!("Next"));
+
+  let third = next_view(wizard.submit(&submit(&second,
+                                              Some(("team", "Platform"))))
+                              .unwrap());
+  let json = serde_json::to_value(&third).unwrap();
+  assert_eq!(json["blocks"][0]["text"]["text"],
+             json!("Ada joins Platform"));
+  assert_eq!(json["submit"]["text"], json!("Submit"));
+
+  assert_eq!(wizard.submit(&submit(&third, None)).unwrap(),
+             WizardOutcome::Finished(Answers { name: "Ada".into(),
+                                               team: "Platform".into() }));
+}
+
+#[test]
+pub fn wizard_rejected_step_responds_with_errors() {
+  let wizard = wizard();
+  let first = wizard.open(Answers::default()).unwrap();
+
+  match wizard.submit(&submit(&first, None)).unwrap() {
+    | WizardOutcome::Respond(ResponseAction::Errors(errors)) => {
+      assert_eq!(serde_json::to_value(&errors).unwrap(),
+                 json!({ "errors": { "name": "Required" } }));
+    },
+    | other => panic!("expected errors, got {:?}", other),
+  }
+}
+
+#[test]
+pub fn wizard_back_renders_previous_step_with_answers() {
+  let wizard = wizard();
+  let first = wizard.open(Answers::default()).unwrap();
+  let second = next_view(wizard.submit(&submit(&first, Some(("name", "Ada"))))
+                               .unwrap());
+
+  let actions = |view: &Modal| -> BlockActions<'static> {
+    serde_json::from_value(json!({
+      "team": { "id": "T9TK3CUKW" },
+      "user": { "id": "UA8RXUSPL" },
+      "view": view,
+      "actions": [{ "type": "button", "action_id": BACK_ACTION_ID, "block_id": "b" }]
+    })).unwrap()
+  };
+
+  let back = wizard.back(&actions(&second)).unwrap();
+  assert_eq!(serde_json::to_value(&back).unwrap()["blocks"],
+             serde_json::to_value(&first).unwrap()["blocks"]);
+  assert_eq!(back.typed_private_metadata::<Value>().unwrap()["answers"]
+               ["name"],
+             json!("Ada"));
+
+  assert_eq!(wizard.back(&actions(&first)),
+             Err(WizardError::UnknownStep { step: 0 }));
+}
+
+#[test]
+pub fn pushed_wizard_uses_close_button_to_go_back() {
+  let wizard = wizard().push().back_text("Previous");
+  let first = wizard.open(Answers::default()).unwrap();
+
+  match wizard.submit(&submit(&first, Some(("name", "Ada"))))
+              .unwrap()
+  {
+    | WizardOutcome::Respond(ResponseAction::Push { view }) => {
+      let json = serde_json::to_value(&view).unwrap();
+      assert_eq!(json["close"]["text"], json!("Previous"));
+      assert_eq!(json["blocks"].as_array().unwrap().len(), 1);
+    },
+    | other => panic!("expected a push, got {:?}", other),
+  }
+
+  let too_long = wizard.step(|_| text_step("extra"), |_, _| Ok(()));
+
+  assert_eq!(too_long.open(Answers::default()),
+             Err(WizardError::StackFull));
+}
+
+#[test]
+pub fn wizard_rejects_foreign_views() {
+  let wizard = wizard();
+  let view = Modal::builder().title("Something else")
+                             .block(Block::Divider)
+                             .callback_id("onboarding")
+                             .build();
+
+  assert!(matches!(wizard.submit(&submit(&view, None)),
+                   Err(WizardError::Metadata(_))));
+
+  let other =
+    Wizard::<Answers>::new("signup", "Sign up").step(|_| text_step("team"),
+                                                     |_, _| Ok(()));
+  let view = other.open(Answers::default()).unwrap();
+
+  assert_eq!(wizard.submit(&submit(&view, None)),
+             Err(WizardError::OtherView { callback_id:
+                                            Some("signup".to_string()) }));
+}
+
+#[test]
+pub fn signed_wizard_rejects_forged_progress() {
+  let key = SigningKey::new("secret");
+  let wizard = wizard().signed(key.clone());
+  let first = wizard.open(Answers::default()).unwrap();
+
+  let second = next_view(wizard.submit(&submit(&first, Some(("name", "Ada"))))
+                               .unwrap());
+  assert_eq!(second.verified_private_metadata::<Value>(&key, "onboarding")
+                   .unwrap()["answers"]["name"],
+             json!("Ada"));
+
+  let forged =
+    json!({ "step": 2, "answers": { "name": "Eve", "team": "Admins" } });
+  let last = Modal::builder().title("Onboarding")
+                             .block(Block::Divider)
+                             .callback_id("onboarding")
+                             .private_metadata(forged.to_string())
+                             .build();
+
+  assert_eq!(wizard.submit(&submit(&last, None)),
+             Err(WizardError::Metadata(ValueError::BadSignature)));
+  assert_eq!(wizard.submit(&submit(&first, None)).map(|_| ()), Ok(()));
+}